@@ -0,0 +1,25 @@
+pub mod vault;
+
+// imports from standard library
+
+use std::any::Any;
+use std::sync::Arc;
+
+// imports from 3rd party crates
+
+use anyhow::{bail, ensure, Context, Result};
+//use log::*;
+use parking_lot::RwLock;
+use serde::{Deserialize, Serialize};
+
+// imports from own crates
+
+use iop_coeus_proto::{DomainName, NoncedBundle, SignedBundle, UserOperation};
+use iop_journal_proto::Nonce;
+use iop_keyvault::{
+    ed25519::{Ed25519, EdExtPrivateKey, EdKeyId, EdPublicKey},
+    multicipher::{MPrivateKey, MPublicKey},
+    Bip32, Bip32Node, Bip43Path, KeyDerivationCrypto, Path as Bip32Path, PrivateKey as _,
+    PublicKey as _, Seed, Subtree,
+};
+use iop_vault::{BoundPlugin, PluginPrivate, PluginPublic, State, Vault, VaultPlugin};
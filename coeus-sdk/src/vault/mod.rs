@@ -0,0 +1,111 @@
+mod plugin;
+mod private;
+mod public;
+mod types;
+
+pub use plugin::*;
+pub use private::*;
+pub use public::*;
+pub use types::*;
+
+use super::*;
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use iop_coeus_proto::Principal;
+    use iop_keyvault::Seed;
+    use iop_vault::Vault;
+
+    fn domain_name() -> DomainName {
+        ".wallet.joe".parse().unwrap()
+    }
+
+    #[test]
+    fn api() -> Result<()> {
+        let unlock_password = "correct horse battery staple";
+        let mut vault = Vault::create(None, Seed::DEMO_PHRASE, "", unlock_password)?;
+        Plugin::init(&mut vault, unlock_password)?;
+
+        let coeus = Plugin::get(&vault)?;
+        let mut coeus_priv = coeus.private(unlock_password)?;
+        let sk0 = coeus_priv.key(0)?;
+        let pk0 = sk0.public_key();
+
+        assert_eq!(&pk0.to_string(), "pezEXddXmorWBUSkPu6FXuC1uTrxin5Su2Kfy736eKpiSRj");
+        assert_eq!(coeus.public()?.key(0)?, pk0);
+        assert!(coeus_priv.key(1).is_err());
+
+        let pk1 = coeus_priv.key_mut(1)?.public_key();
+
+        assert_eq!(coeus.public()?.len()?, 2);
+        assert_eq!(coeus_priv.key_by_pk(&pk1)?.public_key(), pk1);
+
+        let err = Plugin::init(&mut vault, unlock_password).unwrap_err();
+        assert!(err.to_string().contains("was already added"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn sign_bundles() -> Result<()> {
+        let unlock_password = "correct horse battery staple";
+        let mut vault = Vault::create(None, Seed::DEMO_PHRASE, "", unlock_password)?;
+        Plugin::init(&mut vault, unlock_password)?;
+
+        let coeus = Plugin::get(&vault)?;
+        let mut coeus_pub = coeus.public()?;
+        let pk0 = coeus_pub.key(0)?;
+        let op = UserOperation::transfer(domain_name(), Principal::system());
+
+        let bundle = coeus_pub.bundle(&pk0, vec![op.clone()])?;
+        assert_eq!(bundle.nonce, 1);
+
+        let mut coeus_priv = coeus.private(unlock_password)?;
+        let signed = coeus_priv.sign(&pk0, bundle.clone())?;
+
+        assert!(signed.verify());
+        assert_eq!(signed.public_key, pk0);
+        assert_eq!(coeus_pub.last_nonce(&pk0)?, 1);
+        assert_eq!(coeus_pub.next_nonce(&pk0)?, 2);
+
+        let err = coeus_priv.sign(&pk0, bundle).unwrap_err();
+        assert!(err.to_string().contains("already used"));
+
+        coeus_pub.set_last_nonce(&pk0, 41)?;
+        let signed = coeus_priv.sign(&pk0, coeus_pub.bundle(&pk0, vec![op])?)?;
+        assert_eq!(signed.bundle.nonce, 42);
+
+        Ok(())
+    }
+
+    #[test]
+    fn domains() -> Result<()> {
+        let unlock_password = "correct horse battery staple";
+        let mut vault = Vault::create(None, Seed::DEMO_PHRASE, "", unlock_password)?;
+        Plugin::init(&mut vault, unlock_password)?;
+
+        let coeus = Plugin::get(&vault)?;
+        let pk1 = coeus.private(unlock_password)?.key_mut(1)?.public_key();
+        let mut coeus_pub = coeus.public()?;
+        let pk0 = coeus_pub.key(0)?;
+
+        coeus_pub.add_domain(&pk1, &domain_name())?;
+        assert!(coeus_pub.add_domain(&pk0, &domain_name()).is_err());
+        assert_eq!(coeus_pub.domains(&pk1)?, vec![domain_name()]);
+        assert!(coeus_pub.domains(&pk0)?.is_empty());
+        assert_eq!(coeus_pub.owner_of(&domain_name())?, pk1);
+
+        let saved = serde_json::to_string(&vault)?;
+        let loaded: Vault = serde_json::from_str(&saved)?;
+        let mut loaded_pub = Plugin::get(&loaded)?.public()?;
+        assert_eq!(loaded_pub.owner_of(&domain_name())?, pk1);
+
+        loaded_pub.remove_domain(&pk1, &domain_name())?;
+        assert!(loaded_pub.owner_of(&domain_name()).is_err());
+        assert!(loaded_pub.remove_domain(&pk1, &domain_name()).is_err());
+
+        Ok(())
+    }
+}
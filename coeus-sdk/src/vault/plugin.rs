@@ -0,0 +1,72 @@
+use super::*;
+
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Inner {
+    parameters: Parameters,
+    public_state: PublicState,
+}
+
+impl Inner {
+    fn new(public_state: PublicState) -> Self {
+        Self { parameters: Default::default(), public_state }
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Plugin {
+    inner: Arc<RwLock<Inner>>,
+}
+
+#[cfg_attr(target_arch = "wasm32", typetag::serialize(name = "Coeus"))]
+#[cfg_attr(not(target_arch = "wasm32"), typetag::serde(name = "Coeus"))]
+impl VaultPlugin for Plugin {
+    fn name(&self) -> &'static str {
+        "Coeus"
+    }
+
+    fn to_any(&self) -> Box<dyn Any> {
+        Box::new(self.clone())
+    }
+
+    fn eq(&self, other: &dyn VaultPlugin) -> bool {
+        let other: Result<Box<Plugin>, _> = other.to_any().downcast();
+        other.is_ok()
+    }
+}
+
+impl Plugin {
+    pub fn new(public_keys: Vec<String>) -> Self {
+        let keys = public_keys
+            .into_iter()
+            .map(|public_key| KeyState { public_key, last_nonce: 0, domains: vec![] })
+            .collect();
+        let imp = Inner::new(PublicState { keys });
+        let inner = Arc::new(RwLock::new(imp));
+        Self { inner }
+    }
+
+    pub fn create(vault: &mut Vault) -> Result<()> {
+        let plugin = Self::new(vec![]);
+        vault.add(Box::new(plugin))
+    }
+
+    pub fn init(vault: &mut Vault, unlock_password: impl AsRef<str>) -> Result<()> {
+        let seed = vault.unlock(unlock_password.as_ref())?;
+        let owner0 = Coeus.root(&seed)?.derive_hardened(0)?.neuter().public_key();
+        let plugin = Self::new(vec![MPublicKey::from(owner0).to_string()]);
+        vault.add(Box::new(plugin))
+    }
+
+    pub fn get(vault: &Vault) -> Result<BoundPlugin<Plugin, Public, Private>> {
+        let coeus_plugins = vault.plugins_by_type::<Plugin>()?;
+        let plugin: &Plugin =
+            coeus_plugins.iter().by_ref().next().with_context(|| "Could not find Coeus plugin")?;
+        Ok(BoundPlugin::new(vault.to_owned(), plugin.to_owned()))
+    }
+
+    pub(super) fn to_state(&self) -> Box<dyn State<PublicState>> {
+        <dyn State<_>>::map(&self.inner, |s| &s.public_state, |s| &mut s.public_state)
+    }
+}
@@ -0,0 +1,83 @@
+use super::*;
+
+pub struct Private {
+    state: Box<dyn State<PublicState>>,
+    root: Bip32Node<Ed25519>,
+    vault_dirty: Box<dyn State<bool>>,
+}
+
+impl PluginPrivate<Plugin> for Private {
+    fn create(plugin: &Plugin, seed: Seed, vault_dirty: Box<dyn State<bool>>) -> Result<Self> {
+        let root = Coeus.root(&seed)?;
+        let state = plugin.to_state();
+        Ok(Self { state, root, vault_dirty })
+    }
+}
+
+impl Private {
+    pub fn node(&self) -> &Bip32Node<Ed25519> {
+        &self.root
+    }
+
+    pub fn public(&self) -> Public {
+        Public::new(self.state.clone(), self.vault_dirty.clone())
+    }
+
+    pub fn key(&self, idx: i32) -> Result<MPrivateKey> {
+        ensure!(idx >= 0, "Key index cannot be negative");
+        let count = self.state.try_borrow()?.keys.len() as i32;
+        ensure!(idx < count, "Only existing keys can be queried");
+        self.derive(idx)
+    }
+
+    pub fn key_mut(&mut self, idx: i32) -> Result<MPrivateKey> {
+        ensure!(idx >= 0, "Key index cannot be negative");
+        let count = self.state.try_borrow()?.keys.len() as i32;
+        let required = idx + 1;
+        if count < required {
+            let new_keys = (count..required)
+                .map(|i| Ok(KeyState::new(&self.derive(i)?.public_key())))
+                .collect::<Result<Vec<_>>>()?;
+            let mut state = self.state.try_borrow_mut()?;
+            state.keys.extend(new_keys);
+            let mut dirty = self.vault_dirty.try_borrow_mut()?;
+            *dirty = true;
+        }
+        self.derive(idx)
+    }
+
+    pub fn key_by_pk(&self, pk: &MPublicKey) -> Result<MPrivateKey> {
+        let count = self.state.try_borrow()?.keys.len() as i32;
+        for idx in 0..count {
+            let sk = self.derive(idx)?;
+            if sk.public_key() == *pk {
+                return Ok(sk);
+            }
+        }
+        bail!("Could not find {} among Coeus keys", pk)
+    }
+
+    /// Signs the bundle with the private key that belongs to the given public key. The nonce of the
+    /// bundle has to be larger than the last nonce used by that key, and it is remembered as the last
+    /// nonce after signing.
+    pub fn sign(&mut self, pk: &MPublicKey, bundle: NoncedBundle) -> Result<SignedBundle> {
+        let sk = self.key_by_pk(pk)?;
+        let last_nonce = self.public().last_nonce(pk)?;
+        ensure!(
+            bundle.nonce > last_nonce,
+            "Nonce {} is already used by {}, next nonce is {}",
+            bundle.nonce,
+            pk,
+            last_nonce + 1
+        );
+        let nonce = bundle.nonce;
+        let signed = bundle.sign(&sk)?;
+        self.public().set_last_nonce(pk, nonce)?;
+        Ok(signed)
+    }
+
+    fn derive(&self, idx: i32) -> Result<MPrivateKey> {
+        let sk = self.root.derive_hardened(idx)?.private_key();
+        Ok(MPrivateKey::from(sk))
+    }
+}
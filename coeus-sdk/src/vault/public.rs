@@ -0,0 +1,118 @@
+use super::*;
+
+pub struct Public {
+    state: Box<dyn State<PublicState>>,
+    vault_dirty: Box<dyn State<bool>>,
+}
+
+impl PluginPublic<Plugin> for Public {
+    fn create(plugin: &Plugin, vault_dirty: Box<dyn State<bool>>) -> Result<Self> {
+        Ok(Self::new(plugin.to_state(), vault_dirty))
+    }
+}
+
+impl Public {
+    pub(super) fn new(
+        state: Box<dyn State<PublicState>>, vault_dirty: Box<dyn State<bool>>,
+    ) -> Self {
+        Self { state, vault_dirty }
+    }
+
+    pub fn len(&self) -> Result<u32> {
+        let state = self.state.try_borrow()?;
+        Ok(state.keys.len() as u32)
+    }
+
+    pub fn is_empty(&self) -> Result<bool> {
+        let state = self.state.try_borrow()?;
+        Ok(state.keys.is_empty())
+    }
+
+    pub fn key(&self, idx: i32) -> Result<MPublicKey> {
+        ensure!(idx >= 0, "Key index cannot be negative");
+        let idx = idx as usize;
+        let state = self.state.try_borrow()?;
+        ensure!(idx < state.keys.len(), "Only existing keys can be queried via Coeus Public");
+        let key: MPublicKey = state.keys[idx].public_key.parse()?;
+        Ok(key)
+    }
+
+    /// The nonce of the last bundle signed by the given key. Zero if the key has not signed anything yet.
+    pub fn last_nonce(&self, pk: &MPublicKey) -> Result<Nonce> {
+        let state = self.state.try_borrow()?;
+        Ok(state.key_state(pk)?.last_nonce)
+    }
+
+    /// The nonce the next bundle signed by the given key has to use to be accepted by the ledger.
+    pub fn next_nonce(&self, pk: &MPublicKey) -> Result<Nonce> {
+        let last_nonce = self.last_nonce(pk)?;
+        last_nonce.checked_add(1).with_context(|| format!("Nonce of {} overflowed", pk))
+    }
+
+    /// Overwrites the last nonce the vault remembers for the given key. Useful when the nonce stored
+    /// on the ledger differs, e.g. after a signed bundle was rejected or the key was used outside the vault.
+    pub fn set_last_nonce(&mut self, pk: &MPublicKey, nonce: Nonce) -> Result<()> {
+        let mut state = self.state.try_borrow_mut()?;
+        let key_state = state.key_state_mut(pk)?;
+        if key_state.last_nonce != nonce {
+            key_state.last_nonce = nonce;
+            let mut dirty = self.vault_dirty.try_borrow_mut()?;
+            *dirty = true;
+        }
+        Ok(())
+    }
+
+    /// Creates a bundle of the given operations with the next nonce of the given key.
+    pub fn bundle(&self, pk: &MPublicKey, operations: Vec<UserOperation>) -> Result<NoncedBundle> {
+        let nonce = self.next_nonce(pk)?;
+        Ok(NoncedBundle::new(operations, nonce))
+    }
+
+    /// Domain names remembered to be owned by the given key.
+    pub fn domains(&self, pk: &MPublicKey) -> Result<Vec<DomainName>> {
+        let state = self.state.try_borrow()?;
+        state.key_state(pk)?.domains.iter().map(|d| d.parse()).collect()
+    }
+
+    /// Finds the key that is remembered to own the given domain name.
+    pub fn owner_of(&self, name: &DomainName) -> Result<MPublicKey> {
+        let name_str = name.to_string();
+        let state = self.state.try_borrow()?;
+        for key in &state.keys {
+            if key.domains.contains(&name_str) {
+                return key.public_key.parse();
+            }
+        }
+        bail!("Could not find the owner of {} among Coeus keys", name)
+    }
+
+    /// Remembers that the given key owns a domain name, e.g. after its registration or transfer was accepted.
+    pub fn add_domain(&mut self, pk: &MPublicKey, name: &DomainName) -> Result<()> {
+        let name_str = name.to_string();
+        let mut state = self.state.try_borrow_mut()?;
+        ensure!(
+            state.keys.iter().all(|k| !k.domains.contains(&name_str)),
+            "Domain {} is already owned by a Coeus key",
+            name
+        );
+        state.key_state_mut(pk)?.domains.push(name_str);
+        let mut dirty = self.vault_dirty.try_borrow_mut()?;
+        *dirty = true;
+        Ok(())
+    }
+
+    /// Forgets that the given key owns a domain name, e.g. after it was transferred, deleted or expired.
+    pub fn remove_domain(&mut self, pk: &MPublicKey, name: &DomainName) -> Result<()> {
+        let name_str = name.to_string();
+        let mut state = self.state.try_borrow_mut()?;
+        let domains = &mut state.key_state_mut(pk)?.domains;
+        let idx = domains
+            .iter()
+            .position(|d| *d == name_str)
+            .with_context(|| format!("Domain {} is not owned by {}", name, pk))?;
+        domains.remove(idx);
+        let mut dirty = self.vault_dirty.try_borrow_mut()?;
+        *dirty = true;
+        Ok(())
+    }
+}
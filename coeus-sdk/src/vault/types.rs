@@ -0,0 +1,81 @@
+use super::*;
+
+/// Configuration of Bip32 key derivation for Coeus.
+pub struct CoeusSubtree;
+
+impl Subtree for CoeusSubtree {
+    type Suite = Ed25519;
+
+    fn name(&self) -> &'static str {
+        "coeus"
+    }
+    fn master(&self, seed: &Seed) -> EdExtPrivateKey {
+        Ed25519::master(seed)
+    }
+    fn key_id(&self, pk: &EdPublicKey) -> EdKeyId {
+        pk.key_id()
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+/// Starting point for deriving domain owner keys.
+pub struct Coeus;
+
+impl Coeus {
+    /// Unicode code point for globe with meridians. Coeus is not a coin as defined by Bip44, so it
+    /// needs a separate Bip43 purpose to derive its root node.
+    pub const BIP43_PURPOSE: i32 = 0x1F310;
+
+    /// Calculate the root node of the Coeus subtree in the HD wallet.
+    pub fn root(self, seed: &Seed) -> Result<Bip32Node<Ed25519>> {
+        Bip32.master(seed, &CoeusSubtree).derive_hardened(Self::BIP43_PURPOSE)
+    }
+
+    /// The canonical BIP32 derivation path of the root Coeus node.
+    pub fn bip32_path(self) -> Bip32Path {
+        Bip43Path::purpose(Self::BIP43_PURPOSE).bip32_path()
+    }
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub(super) struct Parameters {}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct KeyState {
+    pub(super) public_key: String,
+    #[serde(default)]
+    pub(super) last_nonce: Nonce,
+    #[serde(skip_serializing_if = "Vec::is_empty", default)]
+    pub(super) domains: Vec<String>,
+}
+
+impl KeyState {
+    pub(super) fn new(public_key: &MPublicKey) -> Self {
+        Self { public_key: public_key.to_string(), last_nonce: 0, domains: vec![] }
+    }
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct PublicState {
+    #[serde(skip_serializing_if = "Vec::is_empty", default)]
+    pub(super) keys: Vec<KeyState>,
+}
+
+impl PublicState {
+    pub(super) fn key_state(&self, pk: &MPublicKey) -> Result<&KeyState> {
+        let pk_str = pk.to_string();
+        self.keys
+            .iter()
+            .find(|k| k.public_key == pk_str)
+            .with_context(|| format!("Could not find {} among Coeus keys", pk))
+    }
+
+    pub(super) fn key_state_mut(&mut self, pk: &MPublicKey) -> Result<&mut KeyState> {
+        let pk_str = pk.to_string();
+        self.keys
+            .iter_mut()
+            .find(|k| k.public_key == pk_str)
+            .with_context(|| format!("Could not find {} among Coeus keys", pk))
+    }
+}
@@ -16,8 +16,8 @@ use serde::{Deserialize, Serialize};
 
 use iop_hydra_proto::TransactionData;
 use iop_keyvault::{
-    secp256k1::{Secp256k1, SecpPrivateKey, SecpPublicKey},
+    secp256k1::{Fingerprint, Secp256k1, SecpPrivateKey, SecpPublicKey},
     Bip32Node, Bip32PublicNode, Bip44, Bip44Account, Bip44AccountPath, Bip44Key,
-    Bip44PublicAccount, Bip44PublicKey, Network, Networks, PrivateKey as _, Seed,
+    Bip44PublicAccount, Bip44PublicKey, Chain, Network, Networks, PrivateKey as _, Seed,
 };
 use iop_vault::{BoundPlugin, PluginPrivate, PluginPublic, State};
@@ -16,7 +16,7 @@ use super::*;
 mod test {
     use super::*;
 
-    use iop_keyvault::{secp256k1::hyd, Seed};
+    use iop_keyvault::{secp256k1::hyd, secp256k1::PkhDescriptor, Seed};
     use iop_vault::Vault;
 
     #[test]
@@ -51,6 +51,65 @@ mod test {
         Ok(())
     }
 
+    #[test]
+    fn descriptor() -> Result<()> {
+        let unlock_password = "correct horse battery staple";
+        let mut vault = Vault::create(None, Seed::DEMO_PHRASE, "", unlock_password)?;
+        let parameters = Parameters::new(&hyd::Testnet, 0);
+        Plugin::init(&mut vault, unlock_password, &parameters)?;
+
+        let hyd_priv = Plugin::get(&vault, &parameters)?.private(unlock_password)?;
+        let seed = vault.unlock(unlock_password)?;
+        let fingerprint = Fingerprint::master(&seed);
+        assert_eq!(hyd_priv.master_fingerprint(), fingerprint);
+
+        let xpub = hyd_priv.xpub()?;
+        assert_eq!(hyd_priv.key_origin(), format!("[{}/44'/1'/0']{}", fingerprint, xpub));
+
+        let descriptor = hyd_priv.descriptor(Some(Chain::Receiving));
+        assert!(descriptor.starts_with(&format!("pkh({}/0/*)#", hyd_priv.key_origin())));
+        let parsed = PkhDescriptor::parse(&descriptor, &hyd::Testnet)?;
+        assert_eq!(parsed.master_fingerprint, fingerprint);
+        assert_eq!(parsed.account.to_xpub(), xpub);
+
+        Ok(())
+    }
+
+    #[test]
+    fn watch_only() -> Result<()> {
+        let unlock_password = "correct horse battery staple";
+        let mut vault = Vault::create_watch_only();
+        assert!(vault.is_watch_only()?);
+        let parameters = Parameters::new(&hyd::Testnet, 0);
+        assert!(Plugin::init(&mut vault, unlock_password, &parameters).is_err());
+        let xpub = "hydtVxG6GvapCX2X1YxnwKWGzh8tKy6X56gQUN2KRVpqXkgZQYDE7jNw24ZK23ZXEow4cfJz41fBpRj1wV5mbLBYfdpcRgZuS4mSZ22LsVugPZFK";
+        Plugin::import_xpub(&mut vault, &parameters, xpub)?;
+
+        let hyd = Plugin::get(&vault, &parameters)?;
+        assert!(hyd.plugin().is_watch_only());
+
+        let mut hyd_pub = hyd.public()?;
+        assert_eq!(&hyd_pub.xpub()?, xpub);
+        assert_eq!(&hyd_pub.key(0)?.to_p2pkh_addr(), "tjMvaU79mMJ8fKwoLjFLn7rCTthpY6KxTx");
+        hyd_pub.key_mut(1)?;
+        assert_eq!(hyd_pub.receive_keys()?, 2);
+
+        let err = hyd.private(unlock_password).err().unwrap();
+        assert!(err.to_string().contains("watch-only"));
+
+        let saved = serde_json::to_string(&vault)?;
+        let loaded: Vault = serde_json::from_str(&saved)?;
+        assert!(loaded.is_watch_only()?);
+        let hyd = Plugin::get(&loaded, &parameters)?;
+        assert!(hyd.plugin().is_watch_only());
+
+        let mainnet_params = Parameters::new(&hyd::Mainnet, 0);
+        let err = Plugin::import_xpub(&mut vault, &mainnet_params, xpub).unwrap_err();
+        assert!(err.to_string().contains("Invalid network prefix"));
+
+        Ok(())
+    }
+
     const DEMO_VAULT_DAT: &str = r#"
     {
         "encryptedSeed": "uKOE-HCgv-CUHFuL6jCUHMdXrfgGX-nsUM2FwE-5JY0GhSxOFTQSGB4F_N6VwuDYPQ8-q0Q_eQVCpgOsjRzqJAnr8nhyV32yNtpCsGYimpnEjr_enZDOd4jajLjt7b48J7V5yDKKVyp8",
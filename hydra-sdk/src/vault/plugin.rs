@@ -7,6 +7,8 @@ use iop_vault::{Vault, VaultPlugin};
 pub struct Plugin {
     public_state: Arc<RwLock<PublicState>>,
     parameters: Parameters,
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    watch_only: bool,
 }
 
 #[cfg_attr(target_arch = "wasm32", typetag::serialize(name = "Hydra"))]
@@ -31,9 +33,15 @@ impl VaultPlugin for Plugin {
 
 impl Plugin {
     pub fn new(parameters: Parameters, xpub: String, receive_keys: u32, change_keys: u32) -> Self {
+        Self::new_impl(parameters, xpub, receive_keys, change_keys, false)
+    }
+
+    fn new_impl(
+        parameters: Parameters, xpub: String, receive_keys: u32, change_keys: u32, watch_only: bool,
+    ) -> Self {
         let public_state = PublicState { xpub, receive_keys, change_keys };
         let public_state = Arc::new(RwLock::new(public_state));
-        Self { public_state, parameters }
+        Self { public_state, parameters, watch_only }
     }
 
     fn instantiate(
@@ -60,6 +68,19 @@ impl Plugin {
         Self::instantiate(vault, unlock_password, parameters, 1, 0)
     }
 
+    /// Adds a watch-only account to the vault that can derive public keys and addresses from the
+    /// given xpub, but has no access to the private keys, so the vault seed is not needed.
+    pub fn import_xpub(
+        vault: &mut Vault, parameters: &Parameters, xpub: impl AsRef<str>,
+    ) -> Result<()> {
+        let network = Networks::by_name(&parameters.network)?;
+        ensure!(parameters.account >= 0, "Hydra account number cannot be negative");
+        let pub_account =
+            Bip44PublicAccount::<Secp256k1>::from_xpub(parameters.account, xpub, network)?;
+        let plugin = Self::new_impl(parameters.to_owned(), pub_account.to_xpub(), 1, 0, true);
+        vault.add(Box::new(plugin))
+    }
+
     pub fn get(
         vault: &Vault, parameters: &Parameters,
     ) -> Result<BoundPlugin<Plugin, Public, Private>> {
@@ -84,6 +105,10 @@ impl Plugin {
         self.parameters.account
     }
 
+    pub fn is_watch_only(&self) -> bool {
+        self.watch_only
+    }
+
     pub fn create_account(parameters: &Parameters, seed: &Seed) -> Result<Bip44Account<Secp256k1>> {
         let network = Networks::by_name(&parameters.network)?;
         Bip44.network(seed, network)?.account(parameters.account)
@@ -3,17 +3,25 @@ use super::*;
 pub struct Private {
     state: Box<dyn State<PublicState>>,
     account: Bip44Account<Secp256k1>,
+    master_fingerprint: Fingerprint,
     vault_dirty: Box<dyn State<bool>>,
 }
 
 impl PluginPrivate<Plugin> for Private {
     fn create(plugin: &Plugin, seed: Seed, vault_dirty: Box<dyn State<bool>>) -> Result<Self> {
+        ensure!(
+            !plugin.is_watch_only(),
+            "Hydra account {} on {} is watch-only, it cannot sign",
+            plugin.account(),
+            plugin.network().name()
+        );
         let network = plugin.network();
         let account = plugin.account();
 
         let state = plugin.to_state();
         let account = Bip44.network(&seed, network)?.account(account)?;
-        Ok(Self { state, account, vault_dirty })
+        let master_fingerprint = Fingerprint::master(&seed);
+        Ok(Self { state, account, master_fingerprint, vault_dirty })
     }
 }
 
@@ -71,6 +79,21 @@ impl Private {
         Ok(state.xpub.to_owned())
     }
 
+    /// Fingerprint of the master key of the vault, which other wallets use to identify the seed.
+    pub fn master_fingerprint(&self) -> Fingerprint {
+        self.master_fingerprint
+    }
+
+    /// The xpub of the account with its key origin, e.g. `[d34db33f/44'/4741444'/0']xpub...`
+    pub fn key_origin(&self) -> String {
+        self.account.neuter().to_key_origin(self.master_fingerprint)
+    }
+
+    /// The `pkh(...)` output descriptor of the addresses on the given chain, or on both chains.
+    pub fn descriptor(&self, chain: Option<Chain>) -> String {
+        self.account.neuter().to_descriptor(self.master_fingerprint, chain)
+    }
+
     pub fn receive_keys(&self) -> Result<u32> {
         let state = self.state.try_borrow()?;
         Ok(state.receive_keys)
@@ -72,6 +72,12 @@ impl JsBip32Node {
 
     // Secp specific methods...
 
+    /// The fingerprint of the public key of this node as 8 hex digits.
+    #[wasm_bindgen(getter)]
+    pub fn fingerprint(&self) -> String {
+        self.inner.fingerprint().to_string()
+    }
+
     /// Returns the extended private key in the BIP32 readable format with the version bytes of the network.
     ///
     /// This is a secret that must not be kept unencrypted in transit or in rest!
@@ -154,6 +160,12 @@ impl JsBip32PublicNode {
 
     // Secp specific methods...
 
+    /// The fingerprint of the public key of this node as 8 hex digits.
+    #[wasm_bindgen(getter)]
+    pub fn fingerprint(&self) -> String {
+        self.inner.fingerprint().to_string()
+    }
+
     /// Returns the extended public key in the BIP32 readable format with the version bytes of the network.
     #[wasm_bindgen(js_name = toXpub)]
     pub fn to_xpub(&self, name: &str) -> Result<String, JsValue> {
@@ -23,6 +23,22 @@ impl JsBip39 {
         Ok(JsBip39Phrase::from(phrase))
     }
 
+    /// Creates a new phrase from dice rolls or a card shuffle supplied by the user. By default the user entropy is XOR-ed with
+    /// the CSPRNG available on the platform. Only set `userOnly` if you understand the risks, because then the phrase is exactly
+    /// as strong as the user input, which needs at least 128 bits of entropy in that case.
+    #[wasm_bindgen(js_name = generateWith)]
+    pub fn generate_with(
+        &self, user_entropy: &JsUserEntropy, user_only: Option<bool>,
+    ) -> Result<JsBip39Phrase, JsValue> {
+        let mixing = if user_only.unwrap_or(false) {
+            EntropyMixing::UserOnly
+        } else {
+            EntropyMixing::WithSystem
+        };
+        let phrase = self.inner.generate_with(&user_entropy.inner, mixing).map_err_to_js()?;
+        Ok(JsBip39Phrase::from(phrase))
+    }
+
     /// Creates a new phrase using the 256 bits of entropy provided in a buffer. IOP encourages using 24 word phrases everywhere.
     #[wasm_bindgen(js_name = entropy)]
     pub fn entropy(&self, entropy: &[u8]) -> Result<JsBip39Phrase, JsValue> {
@@ -70,6 +86,35 @@ impl JsBip39 {
         Ok(JsBip39Phrase::from(phrase))
     }
 
+    /// Lists valid phrases the user might have meant when the given 24-word phrase has a single mistake, closest first. A word
+    /// marked with `?` or missing is guessed, a misspelled word is corrected and swapped pairs of words are restored.
+    pub fn recover(&self, phrase: &str) -> Result<Box<[JsValue]>, JsValue> {
+        let candidates = self.inner.recover(phrase).map_err_to_js()?;
+        Ok(Self::candidate_phrases(candidates))
+    }
+
+    /// Same as {@link recover}, but keeps only phrases that generate the given address among the first few addresses of the first
+    /// account on the given network (e.g. 'HYD mainnet').
+    #[wasm_bindgen(js_name = recoverByAddress)]
+    pub fn recover_by_address(
+        &self, phrase: &str, password: &str, network: &str, address: &str,
+    ) -> Result<Box<[JsValue]>, JsValue> {
+        let network = Networks::by_name(network).map_err_to_js()?;
+        let target = RecoveryTarget::HydraAddress { network, address: address.to_owned() };
+        let candidates = self.inner.recover_matching(phrase, password, &target).map_err_to_js()?;
+        Ok(Self::candidate_phrases(candidates))
+    }
+
+    /// Same as {@link recover}, but keeps only phrases that generate the given public key among the first few Morpheus keys.
+    #[wasm_bindgen(js_name = recoverByPublicKey)]
+    pub fn recover_by_public_key(
+        &self, phrase: &str, password: &str, public_key: &JsMPublicKey,
+    ) -> Result<Box<[JsValue]>, JsValue> {
+        let target = RecoveryTarget::MorpheusPublicKey(public_key.inner().to_owned());
+        let candidates = self.inner.recover_matching(phrase, password, &target).map_err_to_js()?;
+        Ok(Self::candidate_phrases(candidates))
+    }
+
     #[wasm_bindgen(js_name = shortPhrase)]
     /// Validates a whole BIP39 mnemonic phrase and returns an intermediate object similar to {@link phrase}. This method is only for
     /// compatibility with other wallets. Check the BIP39 standard for the number of words allowed.
@@ -79,6 +124,68 @@ impl JsBip39 {
     }
 }
 
+/// Entropy supplied by the user from dice rolls or a shuffled deck of cards, validated and ready to be used with
+/// {@link Bip39.generateWith}.
+#[wasm_bindgen(js_name = UserEntropy)]
+pub struct JsUserEntropy {
+    inner: UserEntropy,
+}
+
+#[wasm_bindgen(js_class = UserEntropy)]
+impl JsUserEntropy {
+    /// Validates the rolls of a die (source 'd6', 'd20', etc.) or a card shuffle (source 'cards', e.g. 'AS 10H QD 7C').
+    #[wasm_bindgen(constructor)]
+    pub fn new(source: &str, input: &str) -> Result<JsUserEntropy, JsValue> {
+        let source = EntropySource::from_name(source).map_err_to_js()?;
+        let inner = UserEntropy::parse(source, input).map_err_to_js()?;
+        Ok(Self { inner })
+    }
+
+    /// The number of bits of entropy contributed by the input, assuming a fair die or a well shuffled deck.
+    #[wasm_bindgen(getter)]
+    pub fn bits(&self) -> f64 {
+        self.inner.bits()
+    }
+
+    /// Warnings about patterns in the input that suggest a biased die or a poorly shuffled deck.
+    #[wasm_bindgen(getter)]
+    pub fn warnings(&self) -> Box<[JsValue]> {
+        let warnings = self.inner.warnings().iter().map(|w| JsValue::from_str(w));
+        warnings.collect::<Vec<_>>().into_boxed_slice()
+    }
+
+    /// Whether the input is strong enough to generate a phrase without mixing in system entropy.
+    #[wasm_bindgen(getter = sufficientAlone)]
+    pub fn is_sufficient_alone(&self) -> bool {
+        self.inner.is_sufficient_alone()
+    }
+
+    /// A human readable summary of how many bits were contributed by the input.
+    #[wasm_bindgen(js_name = toString)]
+    pub fn to_string_js(&self) -> String {
+        self.inner.to_string()
+    }
+}
+
+impl JsBip39 {
+    fn candidate_phrases(candidates: Vec<RecoveryCandidate>) -> Box<[JsValue]> {
+        let phrases = candidates.iter().map(|c| JsValue::from_str(&c.phrase));
+        phrases.collect::<Vec<_>>().into_boxed_slice()
+    }
+}
+
+impl From<UserEntropy> for JsUserEntropy {
+    fn from(inner: UserEntropy) -> Self {
+        Self { inner }
+    }
+}
+
+impl Wraps<UserEntropy> for JsUserEntropy {
+    fn inner(&self) -> &UserEntropy {
+        &self.inner
+    }
+}
+
 /// An intermediate object that represents a BIP39 phrase with a known language
 #[wasm_bindgen(js_name = Bip39Phrase)]
 pub struct JsBip39Phrase {
@@ -245,6 +245,33 @@ impl JsBip44PublicAccount {
     pub fn to_xpub(&self) -> String {
         self.inner.to_xpub()
     }
+
+    /// Returns the extended public key prefixed with its key origin, like `[d34db33f/44'/4741444'/0']xpub...`. The master fingerprint
+    /// is 8 hex digits, see {@link Seed.masterFingerprint}.
+    #[wasm_bindgen(js_name = keyOrigin)]
+    pub fn key_origin(&self, master_fingerprint: &str) -> Result<String, JsValue> {
+        let fingerprint = master_fingerprint.parse().map_err_to_js()?;
+        Ok(self.inner.to_key_origin(fingerprint))
+    }
+
+    /// Returns the `pkh(...)` output descriptor of the receiving (`change` is false) or change (`change` is true) addresses. If
+    /// `change` is not given, the descriptor covers both chains.
+    pub fn descriptor(
+        &self, master_fingerprint: &str, change: Option<bool>,
+    ) -> Result<String, JsValue> {
+        let fingerprint = master_fingerprint.parse().map_err_to_js()?;
+        Ok(self.inner.to_descriptor(fingerprint, change.map(Chain::from)))
+    }
+
+    /// Recreates the public API of a BIP44 account from a `pkh(...)` output descriptor. The checksum is verified if present.
+    #[wasm_bindgen(js_name = fromDescriptor)]
+    pub fn from_descriptor(
+        descriptor: &str, network: &str,
+    ) -> Result<JsBip44PublicAccount, JsValue> {
+        let network = Networks::by_name(network).map_err_to_js()?;
+        let descriptor = PkhDescriptor::parse(descriptor, network).map_err_to_js()?;
+        Ok(JsBip44PublicAccount::from(descriptor.account))
+    }
 }
 
 impl From<Bip44PublicAccount<Secp256k1>> for JsBip44PublicAccount {
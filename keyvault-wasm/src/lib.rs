@@ -76,8 +76,9 @@ use wasm_bindgen::prelude::*;
 use iop_keyvault::{
     ed25519::*, encrypt as keyvault_encrypt, multicipher::*, secp256k1::*, Bip32, Bip32Node,
     Bip32PublicNode, Bip39, Bip39Phrase, Bip44, Bip44Account, Bip44Coin, Bip44Key,
-    Bip44PublicAccount, Bip44PublicKey, Bip44PublicSubAccount, Bip44SubAccount, Chain, Networks,
-    PrivateKey as _, PublicKey as _, Seed,
+    Bip44PublicAccount, Bip44PublicKey, Bip44PublicSubAccount, Bip44SubAccount, Chain,
+    EntropyMixing, EntropySource, Networks, PrivateKey as _, PublicKey as _, RecoveryCandidate,
+    RecoveryTarget, Seed, UserEntropy,
 };
 
 // code
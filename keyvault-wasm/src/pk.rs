@@ -29,6 +29,28 @@ impl JsMPublicKey {
         Self { inner }
     }
 
+    /// Parses a `PUBLIC KEY` PEM document into a {@link PublicKey}.
+    #[wasm_bindgen(js_name = fromPem)]
+    pub fn from_pem(pem: &str) -> Result<JsMPublicKey, JsValue> {
+        let inner = MPublicKey::from_pem(pem).map_err_to_js()?;
+        Ok(Self { inner })
+    }
+
+    /// Parses a DER encoded SubjectPublicKeyInfo into a {@link PublicKey}.
+    #[wasm_bindgen(js_name = fromSpkiDer)]
+    pub fn from_spki_der(der: &[u8]) -> Result<JsMPublicKey, JsValue> {
+        let inner = MPublicKey::from_spki_der(der).map_err_to_js()?;
+        Ok(Self { inner })
+    }
+
+    /// Reads a {@link PublicKey} from a JSON Web Key object.
+    #[wasm_bindgen(js_name = fromJwk)]
+    pub fn from_jwk(jwk: JsValue) -> Result<JsMPublicKey, JsValue> {
+        let jwk: Jwk = from_value(jwk)?;
+        let inner = MPublicKey::from_jwk(&jwk).map_err_to_js()?;
+        Ok(Self { inner })
+    }
+
     /// All multicipher public keys start with this prefix
     #[wasm_bindgen]
     pub fn prefix() -> String {
@@ -62,6 +84,30 @@ impl JsMPublicKey {
         self.inner.verify(data, signature.inner())
     }
 
+    /// Encodes the public key as a `PUBLIC KEY` PEM document.
+    #[wasm_bindgen(js_name = toPem)]
+    pub fn to_pem(&self) -> String {
+        self.inner.to_pem()
+    }
+
+    /// Encodes the public key as a DER SubjectPublicKeyInfo.
+    #[wasm_bindgen(js_name = toSpkiDer)]
+    pub fn to_spki_der(&self) -> Vec<u8> {
+        self.inner.to_spki_der()
+    }
+
+    /// Converts the public key into a JSON Web Key object.
+    #[wasm_bindgen(js_name = toJwk)]
+    pub fn to_jwk(&self) -> Result<JsValue, JsValue> {
+        Ok(to_value(&self.inner.to_jwk())?)
+    }
+
+    /// The RFC 7638 thumbprint of the JSON Web Key of this public key, which can be used as `kid`.
+    #[wasm_bindgen(js_name = jwkThumbprint)]
+    pub fn jwk_thumbprint(&self) -> Result<String, JsValue> {
+        self.inner.to_jwk().thumbprint().map_err_to_js()
+    }
+
     /// Converts a {@link PublicKey} into a string.
     // Note that Clippy complains if you call these methods to_string. But implementing Display is not enough to get a toString in JS.
     #[wasm_bindgen(js_name=toString)]
@@ -32,6 +32,12 @@ impl JsSeed {
         Seed::PASSWORD.to_owned()
     }
 
+    /// The fingerprint of the Secp256k1 master key as 8 hex digits, which other wallets use to identify the seed in key origins.
+    #[wasm_bindgen(getter = masterFingerprint)]
+    pub fn master_fingerprint(&self) -> String {
+        Fingerprint::master(&self.inner).to_string()
+    }
+
     /// Returns the 512-bit binary representation of the seed
     #[wasm_bindgen(js_name = toBytes)]
     pub fn to_bytes(&self) -> Vec<u8> {
@@ -24,6 +24,58 @@ impl JsMPrivateKey {
         Self { inner }
     }
 
+    /// Parses an unencrypted `PRIVATE KEY` PEM document into a {@link PrivateKey}.
+    ///
+    /// A PEM private key is a secret that must not be kept unencrypted in transit or in rest!
+    #[wasm_bindgen(js_name = fromPem)]
+    pub fn from_pem(pem: &str) -> Result<JsMPrivateKey, JsValue> {
+        let inner = MPrivateKey::from_pem(pem).map_err_to_js()?;
+        Ok(Self { inner })
+    }
+
+    /// Encodes the private key as a `PRIVATE KEY` PEM document.
+    ///
+    /// This is a secret that must not be kept unencrypted in transit or in rest!
+    #[wasm_bindgen(js_name = toPem)]
+    pub fn to_pem(&self) -> String {
+        self.inner.to_pem()
+    }
+
+    /// Parses a DER encoded PKCS#8 structure into a {@link PrivateKey}.
+    ///
+    /// A PKCS#8 private key is a secret that must not be kept unencrypted in transit or in rest!
+    #[wasm_bindgen(js_name = fromPkcs8Der)]
+    pub fn from_pkcs8_der(der: &[u8]) -> Result<JsMPrivateKey, JsValue> {
+        let inner = MPrivateKey::from_pkcs8_der(der).map_err_to_js()?;
+        Ok(Self { inner })
+    }
+
+    /// Encodes the private key as a DER PKCS#8 structure.
+    ///
+    /// This is a secret that must not be kept unencrypted in transit or in rest!
+    #[wasm_bindgen(js_name = toPkcs8Der)]
+    pub fn to_pkcs8_der(&self) -> Vec<u8> {
+        self.inner.to_pkcs8_der()
+    }
+
+    /// Reads a {@link PrivateKey} from a JSON Web Key object that contains the private part `d`.
+    ///
+    /// A private JWK is a secret that must not be kept unencrypted in transit or in rest!
+    #[wasm_bindgen(js_name = fromJwk)]
+    pub fn from_jwk(jwk: JsValue) -> Result<JsMPrivateKey, JsValue> {
+        let jwk: Jwk = from_value(jwk)?;
+        let inner = MPrivateKey::from_jwk(&jwk).map_err_to_js()?;
+        Ok(Self { inner })
+    }
+
+    /// Converts the key pair into a JSON Web Key object.
+    ///
+    /// This is a secret that must not be kept unencrypted in transit or in rest!
+    #[wasm_bindgen(js_name = toJwk)]
+    pub fn to_jwk(&self) -> Result<JsValue, JsValue> {
+        Ok(to_value(&self.inner.to_jwk())?)
+    }
+
     /// Calculates the public key the belongs to this private key.
     #[wasm_bindgen(js_name = publicKey)]
     pub fn public_key(&self) -> JsMPublicKey {
@@ -21,6 +21,16 @@ impl Bip32 {
         let xsk = subtree.master(seed);
         Bip32Node { path, xsk, subtree }
     }
+
+    /// Calculates the extended private key at the given absolute path in a given subtree.
+    pub fn derive<C: KeyDerivationCrypto>(
+        &self, seed: &Seed, subtree: &'static dyn Subtree<Suite = C>, path: &bip32::Path,
+    ) -> Result<Bip32Node<C>> {
+        path.children().iter().try_fold(self.master(seed, subtree), |node, child| match child {
+            ChildIndex::Normal(idx) => node.derive_normal(*idx),
+            ChildIndex::Hardened(idx) => node.derive_hardened(*idx),
+        })
+    }
 }
 
 #[derive(Clone)]
@@ -222,6 +232,11 @@ impl Path {
         path.push(child);
         Self { path }
     }
+
+    /// The derivation indices in this path starting from the master keypair
+    pub fn children(&self) -> &[ChildIndex] {
+        &self.path
+    }
 }
 
 impl FromStr for Path {
@@ -390,6 +405,26 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn derive_path() -> Result<()> {
+        let phrase = "blast cargo razor option vote shoe stock cruel mansion boy spot never album crop reflect kangaroo blouse slam empty shoot cable vital crane manual";
+        let seed = Bip39::new().phrase(phrase)?.password(Seed::PASSWORD);
+        let net = &secp256k1::hyd::Mainnet;
+        let key = Bip32.derive(&seed, net, &"m/44'/4741444'/0'/0/0".parse()?)?;
+
+        assert_eq!(key.to_xprv(net), "HYDMVzXDVKq5jELcWwpPea9GzS7R8sPmoVo6MjhvGYhenJFBrjzgLw5LPoLzEZY1GyKRqbpPBxZNEvP6TnAq9Qpdi9wo3Bhb9NJNMC79egmr486W");
+        assert_eq!(key.path(), &"m/44'/4741444'/0'/0/0".parse()?);
+        assert_eq!(
+            Bip32.derive(&seed, net, &"m".parse()?)?.to_xprv(net),
+            Bip32.master(&seed, net).to_xprv(net)
+        );
+
+        let morpheus = &ed25519::MorpheusSubtree;
+        assert!(Bip32.derive(&seed, morpheus, &"m/0'/1".parse()?).is_err());
+
+        Ok(())
+    }
+
     #[test]
     fn parsing() -> Result<()> {
         let account_xprv = "HYDMVzUVgP7S8GNPrKWhvoFPivfS25QnhfKi1iydA7jbWRwVuMJTVZzBQvBV86zpNJg83rrtvj6SWsftT3nNg5PQ9kwEdzTSpEDH5KbZsjbKBbhs";
@@ -0,0 +1,176 @@
+//! Deterministic entropy from a BIP32 keychain based on [BIP-0085](https://github.com/bitcoin/bips/blob/master/bip-0085.mediawiki).
+//! Child mnemonics, WIF keys and hex secrets derived this way can be handed to other wallets and
+//! recovered from the master seed, but they do not reveal anything about the master seed.
+
+use super::*;
+use crate::secp256k1::{btc, Secp256k1};
+
+/// Derives child entropy from the master node of a Secp256k1 keychain.
+#[derive(Clone, Debug)]
+pub struct Bip85 {
+    master: Bip32Node<Secp256k1>,
+}
+
+impl Bip85 {
+    /// All BIP85 derivation paths start with this hardened index
+    pub const PURPOSE: i32 = 83_696_968;
+    /// The application number for BIP39 mnemonics
+    pub const APP_BIP39: i32 = 39;
+    /// The application number for private keys in the Wallet Import Format
+    pub const APP_WIF: i32 = 2;
+    /// The application number for hex encoded secrets
+    pub const APP_HEX: i32 = 128_169;
+
+    const HMAC_KEY: &'static [u8] = b"bip-entropy-from-k";
+
+    /// Uses the given node as the master keychain. It has to be at the root of the BIP32 tree.
+    pub fn new(master: Bip32Node<Secp256k1>) -> Result<Self> {
+        ensure!(
+            master.path().children().is_empty(),
+            "BIP85 needs the master node, got {}",
+            master.path()
+        );
+        Ok(Self { master })
+    }
+
+    /// Calculates the master keychain from the seed.
+    pub fn from_seed(seed: &Seed) -> Self {
+        Self { master: Bip32.master(seed, &btc::Mainnet) }
+    }
+
+    /// The 64 bytes of entropy derived at the given hardened indices after the BIP85 purpose.
+    pub fn entropy(&self, indices: &[i32]) -> Result<[u8; 64]> {
+        let mut node = self.master.derive_hardened(Self::PURPOSE)?;
+        for idx in indices {
+            node = node.derive_hardened(*idx)?;
+        }
+        let k = node.private_key().to_bytes();
+
+        let mut hasher = <HmacSha512 as KeyInit>::new_from_slice(Self::HMAC_KEY)?;
+        hasher.update(&k);
+        let mut entropy = [0u8; 64];
+        entropy.copy_from_slice(&hasher.finalize().into_bytes());
+        Ok(entropy)
+    }
+
+    /// A child mnemonic with 12, 15, 18, 21 or 24 words in the given language.
+    pub fn bip39(&self, lang: Bip39Language, words: usize, index: i32) -> Result<Bip39Phrase> {
+        ensure!(
+            [12, 15, 18, 21, 24].contains(&words),
+            "BIP85 mnemonics can have 12, 15, 18, 21 or 24 words, not {}",
+            words
+        );
+        let lang_code = Self::language_index(lang);
+        let entropy = self.entropy(&[Self::APP_BIP39, lang_code, words as i32, index])?;
+        let len = words * 4 / 3;
+        Bip39::language(lang).short_entropy(&entropy[..len])
+    }
+
+    /// A child private key in the Wallet Import Format with the version byte of the network.
+    pub fn wif(
+        &self, network: &'static dyn Network<Suite = Secp256k1>, index: i32,
+    ) -> Result<String> {
+        let entropy = self.entropy(&[Self::APP_WIF, index])?;
+        let sk = secp256k1::SecpPrivateKey::from_bytes(&entropy[..32])?;
+        Ok(sk.to_wif(network.wif(), secp256k1::Bip178::Compressed))
+    }
+
+    /// A child secret of 16 to 64 bytes encoded as lowercase hex.
+    pub fn hex(&self, num_bytes: usize, index: i32) -> Result<String> {
+        ensure!(
+            (16..=64).contains(&num_bytes),
+            "BIP85 hex secrets have 16 to 64 bytes, not {}",
+            num_bytes
+        );
+        let entropy = self.entropy(&[Self::APP_HEX, num_bytes as i32, index])?;
+        Ok(hex::encode(&entropy[..num_bytes]))
+    }
+
+    fn language_index(lang: Bip39Language) -> i32 {
+        match lang {
+            Bip39Language::English => 0,
+            Bip39Language::Japanese => 1,
+            Bip39Language::Korean => 2,
+            Bip39Language::Spanish => 3,
+            Bip39Language::ChineseSimplified => 4,
+            Bip39Language::ChineseTraditional => 5,
+            Bip39Language::French => 6,
+            Bip39Language::Italian => 7,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    // Test vectors from BIP-0085
+    const MASTER_XPRV: &str = "xprv9s21ZrQH143K2LBWUUQRFXhucrQqBpKdRRxNVq2zBqsx8HVqFk2uYo8kmbaLLHRdqtQpUm98uKfu3vca1LqdGhUtyoFnCNkfmXRyPXLjbKb";
+
+    fn bip85() -> Result<Bip85> {
+        let master = Bip32Node::from_xprv(Default::default(), MASTER_XPRV, &btc::Mainnet)?;
+        Bip85::new(master)
+    }
+
+    #[test]
+    fn entropy() -> Result<()> {
+        let bip85 = bip85()?;
+
+        assert_eq!(hex::encode(bip85.entropy(&[0, 0])?), "efecfbccffea313214232d29e71563d941229afb4338c21f9517c41aaa0d16f00b83d2a09ef747e7a64e8e2bd5a14869e693da66ce94ac2da570ab7ee48618f7");
+        assert_eq!(hex::encode(bip85.entropy(&[0, 1])?), "70c6e3e8ebee8dc4c0dbba66076819bb8c09672527c4277ca8729532ad711872218f826919f6b67218adde99018a6df9095ab2b58d803b5b93ec9802085a690e");
+
+        Ok(())
+    }
+
+    #[test]
+    fn bip39() -> Result<()> {
+        let bip85 = bip85()?;
+
+        assert_eq!(
+            bip85.bip39(Bip39Language::English, 12, 0)?.as_phrase(),
+            "girl mad pet galaxy egg matter matrix prison refuse sense ordinary nose"
+        );
+        assert_eq!(
+            bip85.bip39(Bip39Language::English, 18, 0)?.as_phrase(),
+            "near account window bike charge season chef number sketch tomorrow excuse sniff circle vital hockey outdoor supply token"
+        );
+        assert_eq!(
+            bip85.bip39(Bip39Language::English, 24, 0)?.as_phrase(),
+            "puppy ocean match cereal symbol another shed magic wrap hammer bulb intact gadget divorce twin tonight reason outdoor destroy simple truth cigar social volcano"
+        );
+        assert!(bip85.bip39(Bip39Language::English, 13, 0).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn wif() -> Result<()> {
+        let bip85 = bip85()?;
+
+        assert_eq!(
+            bip85.wif(&btc::Mainnet, 0)?,
+            "Kzyv4uF39d4Jrw2W7UryTHwZr1zQVNk4dAFyqE6BuMrMh1Za7uhp"
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn hex() -> Result<()> {
+        let bip85 = bip85()?;
+
+        assert_eq!(bip85.hex(64, 0)?, "492db4698cf3b73a5a24998aa3e9d7fa96275d85724a91e71aa2d645442f878555d078fd1f1f67e368976f04137b1f7a0d19232136ca50c44614af72b5582a5c");
+        assert!(bip85.hex(15, 0).is_err());
+        assert!(bip85.hex(65, 0).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn needs_master() -> Result<()> {
+        let master = Bip32Node::from_xprv(Default::default(), MASTER_XPRV, &btc::Mainnet)?;
+        assert!(Bip85::new(master.derive_hardened(0)?).is_err());
+
+        Ok(())
+    }
+}
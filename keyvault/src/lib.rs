@@ -35,18 +35,21 @@ mod bip39;
 mod bip43;
 mod bip44;
 mod bip44path;
+mod bip85;
 mod cc;
 pub mod ed25519;
 pub mod encrypt;
 pub mod multicipher;
 mod network;
 mod networks;
+mod recovery;
 pub mod secp256k1;
 mod seed;
 #[cfg(test)]
 mod test_crypto;
 #[cfg(test)]
 mod tests;
+mod user_entropy;
 
 pub use ::bip39::ErrorKind as Bip39ErrorKind;
 pub use ::bip39::Language as Bip39Language;
@@ -58,9 +61,12 @@ pub use bip32::*;
 pub use bip43::*;
 pub use bip44::*;
 pub use bip44path::*;
+pub use bip85::*;
 pub use network::*;
 pub use networks::Networks;
+pub use recovery::*;
 pub use seed::*;
+pub use user_entropy::*;
 
 use std::cmp::Ordering;
 use std::fmt;
@@ -0,0 +1,232 @@
+//! JSON Web Key representation of multicipher keys as defined in
+//! [RFC 7517](https://www.rfc-editor.org/rfc/rfc7517), with the `OKP` key type from
+//! [RFC 8037](https://www.rfc-editor.org/rfc/rfc8037) for Ed25519 and the `secp256k1` curve from
+//! [RFC 8812](https://www.rfc-editor.org/rfc/rfc8812).
+
+use super::*;
+
+use sha2::{Digest, Sha256};
+
+const KTY_OKP: &str = "OKP";
+const KTY_EC: &str = "EC";
+const CRV_ED25519: &str = "Ed25519";
+const CRV_SECP256K1: &str = "secp256k1";
+const COORDINATE_SIZE: usize = 32;
+
+fn encode(data: &[u8]) -> String {
+    // The multibase prefix character is dropped
+    multibase::encode(multibase::Base::Base64Url, data)[1..].to_owned()
+}
+
+fn decode(field: &str, value: &str) -> Result<Vec<u8>> {
+    let (_, data) = multibase::decode(format!("{}{}", multibase::Base::Base64Url.code(), value))
+        .map_err(|e| anyhow!("Invalid base64url in JWK field '{}': {}", field, e))?;
+    Ok(data)
+}
+
+/// A JSON Web Key holding a public key, or a key pair if the private part `d` is also present.
+/// Only the members needed to describe the key material are supported, and they serialize with
+/// the names defined in the RFCs.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct Jwk {
+    /// Key type, `OKP` for Ed25519 and `EC` for Secp256k1
+    pub kty: String,
+    /// Curve name, `Ed25519` or `secp256k1`
+    pub crv: String,
+    /// Public key for `OKP`, or the x coordinate of the public point for `EC`
+    pub x: String,
+    /// The y coordinate of the public point for `EC`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub y: Option<String>,
+    /// The private key
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub d: Option<String>,
+}
+
+impl Jwk {
+    /// The cipher suite of the key based on its key type and curve.
+    pub fn suite(&self) -> Result<CipherSuite> {
+        match (self.kty.as_str(), self.crv.as_str()) {
+            (KTY_OKP, CRV_ED25519) => Ok(CipherSuite::Ed25519),
+            (KTY_EC, CRV_SECP256K1) => Ok(CipherSuite::Secp256k1),
+            (kty, crv) => bail!("Unsupported JWK key type '{}' with curve '{}'", kty, crv),
+        }
+    }
+
+    /// Returns a copy without the private part of the key.
+    pub fn to_public(&self) -> Self {
+        Self { d: None, ..self.clone() }
+    }
+
+    /// The [RFC 7638](https://www.rfc-editor.org/rfc/rfc7638) thumbprint of the public key in
+    /// base64url format, which can be used as a `kid`.
+    pub fn thumbprint(&self) -> Result<String> {
+        // Required members in lexicographic order without whitespace
+        let canonical = match self.suite()? {
+            CipherSuite::Ed25519 => {
+                format!(r#"{{"crv":"{}","kty":"{}","x":"{}"}}"#, self.crv, self.kty, self.x)
+            }
+            CipherSuite::Secp256k1 => {
+                let y = self.y.as_ref().ok_or_else(|| anyhow!("JWK of EC key is missing 'y'"))?;
+                format!(
+                    r#"{{"crv":"{}","kty":"{}","x":"{}","y":"{}"}}"#,
+                    self.crv, self.kty, self.x, y
+                )
+            }
+        };
+        Ok(encode(&Sha256::digest(canonical.as_bytes())))
+    }
+
+    fn public_key(&self) -> Result<MPublicKey> {
+        let x = decode("x", &self.x)?;
+        match self.suite()? {
+            CipherSuite::Ed25519 => {
+                ensure!(self.y.is_none(), "JWK of OKP key must not have 'y'");
+                Ok(MPublicKey::Ed25519(EdPublicKey::from_bytes(x)?))
+            }
+            CipherSuite::Secp256k1 => {
+                let y = self.y.as_ref().ok_or_else(|| anyhow!("JWK of EC key is missing 'y'"))?;
+                let y = decode("y", y)?;
+                ensure!(
+                    x.len() == COORDINATE_SIZE && y.len() == COORDINATE_SIZE,
+                    "JWK coordinates must be {} bytes long",
+                    COORDINATE_SIZE
+                );
+                let point = [&[4u8][..], &x, &y].concat();
+                Ok(MPublicKey::Secp256k1(SecpPublicKey::from_uncompressed(point)?))
+            }
+        }
+    }
+}
+
+impl MPublicKey {
+    /// Converts the public key into a JSON Web Key.
+    pub fn to_jwk(&self) -> Jwk {
+        match self {
+            Self::Ed25519(edpk) => Jwk {
+                kty: KTY_OKP.to_owned(),
+                crv: CRV_ED25519.to_owned(),
+                x: encode(&edpk.to_bytes()),
+                y: None,
+                d: None,
+            },
+            Self::Secp256k1(secppk) => {
+                let point = secppk.uncompressed();
+                Jwk {
+                    kty: KTY_EC.to_owned(),
+                    crv: CRV_SECP256K1.to_owned(),
+                    x: encode(&point[1..1 + COORDINATE_SIZE]),
+                    y: Some(encode(&point[1 + COORDINATE_SIZE..])),
+                    d: None,
+                }
+            }
+        }
+    }
+
+    /// Reads the public key from a JSON Web Key. The private part of the key is ignored if
+    /// present.
+    pub fn from_jwk(jwk: &Jwk) -> Result<Self> {
+        jwk.public_key()
+    }
+}
+
+impl MPrivateKey {
+    /// Converts the key pair into a JSON Web Key.
+    ///
+    /// This is a secret that must not be kept unencrypted in transit or in rest!
+    pub fn to_jwk(&self) -> Jwk {
+        let d = match self {
+            Self::Ed25519(edsk) => edsk.to_bytes(),
+            Self::Secp256k1(secpsk) => secpsk.to_bytes(),
+        };
+        Jwk { d: Some(encode(&d)), ..self.public_key().to_jwk() }
+    }
+
+    /// Reads the key pair from a JSON Web Key. The public part of the key has to match the
+    /// private key.
+    pub fn from_jwk(jwk: &Jwk) -> Result<Self> {
+        let d = jwk.d.as_ref().ok_or_else(|| anyhow!("JWK has no private key"))?;
+        let d = decode("d", d)?;
+        let sk = match jwk.suite()? {
+            CipherSuite::Ed25519 => Self::Ed25519(EdPrivateKey::from_bytes(d)?),
+            CipherSuite::Secp256k1 => Self::Secp256k1(SecpPrivateKey::from_bytes(d)?),
+        };
+        ensure!(sk.public_key() == jwk.public_key()?, "Public key does not match private key");
+        Ok(sk)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    // Example from RFC 8037 A.1 and A.3
+    const ED_JWK: &str = r#"{"kty":"OKP","crv":"Ed25519","x":"11qYAYKxCrfVS_7TyWQHOg7hcvPapiMlrwIaaPcHURo","d":"nWGxne_9WmC6hEr0kuwsxERJxWl7MmkZcDusAxyuf2A"}"#;
+    const ED_THUMBPRINT: &str = "kPrK_qmxVWaYVA9wwBF6Iuo3vVzz7TxHCTwXBygrS4k";
+
+    #[test]
+    fn ed25519() -> Result<()> {
+        let jwk: Jwk = serde_json::from_str(ED_JWK)?;
+        assert_eq!(jwk.thumbprint()?, ED_THUMBPRINT);
+
+        let sk = MPrivateKey::from_jwk(&jwk)?;
+        assert_eq!(sk.suite(), CipherSuite::Ed25519);
+        assert_eq!(serde_json::to_string(&sk.to_jwk())?, ED_JWK);
+
+        let pk = MPublicKey::from_jwk(&jwk)?;
+        assert_eq!(sk.public_key(), pk);
+        assert_eq!(pk.to_jwk(), jwk.to_public());
+        assert_eq!(pk.to_jwk().thumbprint()?, ED_THUMBPRINT);
+
+        assert!(MPrivateKey::from_jwk(&jwk.to_public()).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn secp256k1() -> Result<()> {
+        let sk = MPrivateKey::from(SecpPrivateKey::from_bytes(hex::decode(
+            "36e18a3b425fdfa3d011eae0827de4e0ef9694e5b6810c9664999d0d4eed7be8",
+        )?)?);
+        let jwk = sk.to_jwk();
+        assert_eq!(jwk.kty, "EC");
+        assert_eq!(jwk.crv, "secp256k1");
+        assert_eq!(
+            hex::encode(decode("x", &jwk.x)?),
+            "51ed1b9147e857d71da3e83a45fa5b141b55ce4630c86abe838eaaac000c2a64"
+        );
+        assert_eq!(
+            hex::encode(decode("y", jwk.y.as_ref().unwrap())?),
+            "14a6903262e9f72f669f16346414209ba605e4f23d04f14a5fd4a283979051c5"
+        );
+
+        let json = serde_json::to_string(&jwk)?;
+        let parsed: Jwk = serde_json::from_str(&json)?;
+        let sk2 = MPrivateKey::from_jwk(&parsed)?;
+        assert_eq!(sk2.public_key(), sk.public_key());
+        assert_eq!(MPublicKey::from_jwk(&parsed)?, sk.public_key());
+        assert_eq!(parsed.thumbprint()?, parsed.to_public().thumbprint()?);
+
+        Ok(())
+    }
+
+    #[test]
+    fn invalid() -> Result<()> {
+        let mut jwk: Jwk = serde_json::from_str(ED_JWK)?;
+        jwk.crv = "X25519".to_owned();
+        assert!(MPublicKey::from_jwk(&jwk).is_err());
+
+        let other = MPrivateKey::from(SecpPrivateKey::from_bytes([1u8; 32])?);
+        let mut mixed = other.to_jwk();
+        mixed.d = MPrivateKey::from(SecpPrivateKey::from_bytes([2u8; 32])?).to_jwk().d;
+        let err = MPrivateKey::from_jwk(&mixed).err().unwrap();
+        assert_eq!(err.to_string(), "Public key does not match private key");
+
+        let mut no_y = other.to_jwk();
+        no_y.y = None;
+        assert!(MPublicKey::from_jwk(&no_y).is_err());
+        assert!(no_y.thumbprint().is_err());
+
+        Ok(())
+    }
+}
@@ -20,6 +20,9 @@
 //! [`ExtendedPublicKey`]: ../trait.ExtendedPublicKey.html
 
 mod id;
+mod jwk;
+mod multikey;
+mod pem;
 mod pk;
 mod sig;
 mod sk;
@@ -33,6 +36,7 @@ use crate::secp256k1::{SecpKeyId, SecpPrivateKey, SecpPublicKey, SecpSignature};
 use crate::{AsymmetricCrypto, PrivateKey, PublicKey};
 
 pub use id::MKeyId;
+pub use jwk::Jwk;
 pub use pk::MPublicKey;
 pub use sig::MSignature;
 pub use sk::MPrivateKey;
@@ -40,7 +44,8 @@ pub use sk::MPrivateKey;
 /// A suite type that is used to keep the type-safety of the erased types in [`multicipher`]
 ///
 /// [`multicipher`]: index.html
-#[derive(Clone, Copy, Debug, Hash, Eq, PartialEq, Ord, PartialOrd)]
+#[derive(Clone, Copy, Debug, Deserialize, Hash, Eq, PartialEq, Ord, PartialOrd, Serialize)]
+#[serde(rename_all = "lowercase")]
 pub enum CipherSuite {
     /// The object tagged with this variant belongs to the [`ed25519`] module
     ///
@@ -73,6 +78,27 @@ impl CipherSuite {
     }
 }
 
+impl fmt::Display for CipherSuite {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Ed25519 => f.write_str("ed25519"),
+            Self::Secp256k1 => f.write_str("secp256k1"),
+        }
+    }
+}
+
+impl FromStr for CipherSuite {
+    type Err = anyhow::Error;
+
+    fn from_str(src: &str) -> Result<Self> {
+        match src.to_lowercase().as_ref() {
+            "ed25519" => Ok(Self::Ed25519),
+            "secp256k1" => Ok(Self::Secp256k1),
+            _ => bail!("Unknown crypto suite {}", src),
+        }
+    }
+}
+
 #[derive(Clone, Debug)]
 /// See the [module-level description](index.html).
 pub struct MultiCipher;
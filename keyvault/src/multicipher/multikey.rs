@@ -0,0 +1,58 @@
+//! Multikey encoding of public keys used by W3C DID documents and `did:key`: the
+//! [multicodec](https://github.com/multiformats/multicodec) varint of the key type followed by the
+//! raw key, encoded in base58btc multibase.
+
+use super::*;
+
+// Varint encoded multicodec values of 0xed and 0xe7
+const MULTICODEC_ED25519_PUB: &[u8] = &[0xed, 0x01];
+const MULTICODEC_SECP256K1_PUB: &[u8] = &[0xe7, 0x01];
+
+impl MPublicKey {
+    /// Encodes the public key in the multikey format, e.g. `z6Mk...` for Ed25519 or `zQ3s...`
+    /// for compressed Secp256k1 keys.
+    pub fn to_multikey(&self) -> String {
+        let (codec, key) = match self {
+            Self::Ed25519(edpk) => (MULTICODEC_ED25519_PUB, edpk.to_bytes()),
+            Self::Secp256k1(secppk) => (MULTICODEC_SECP256K1_PUB, secppk.to_bytes()),
+        };
+        multibase::encode(multibase::Base::Base58Btc, [codec, &key].concat())
+    }
+
+    /// Decodes a public key from the multikey format. Any multibase encoding is accepted.
+    pub fn from_multikey(multikey: &str) -> Result<Self> {
+        let (_, bytes) = multibase::decode(multikey)?;
+        if let Some(key) = bytes.strip_prefix(MULTICODEC_ED25519_PUB) {
+            Ok(Self::Ed25519(EdPublicKey::from_bytes(key)?))
+        } else if let Some(key) = bytes.strip_prefix(MULTICODEC_SECP256K1_PUB) {
+            Ok(Self::Secp256k1(SecpPublicKey::from_bytes(key)?))
+        } else {
+            bail!("Unsupported multicodec key type in '{}'", multikey)
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn roundtrip() -> Result<()> {
+        let edpk: MPublicKey = "pezDj6ea4tVfNRUTMyssVDepAAzPW67Fe3yHtuHL6ZNtcfJ".parse()?;
+        let multikey = edpk.to_multikey();
+        assert!(multikey.starts_with("z6Mk"));
+        assert_eq!(MPublicKey::from_multikey(&multikey)?, edpk);
+
+        let sk = SecpPrivateKey::from_ark_passphrase("multikey")?;
+        let secppk = MPublicKey::from(sk.public_key());
+        let multikey = secppk.to_multikey();
+        assert!(multikey.starts_with("zQ3s"));
+        assert_eq!(MPublicKey::from_multikey(&multikey)?, secppk);
+
+        assert!(
+            MPublicKey::from_multikey("z6LSbysY2xFMRpGMhb7tFTLMpeuPRaqaWM1yECx2AtzE3KCc").is_err()
+        );
+
+        Ok(())
+    }
+}
@@ -15,6 +15,16 @@ pub enum MPrivateKey {
     Secp256k1(SecpPrivateKey),
 }
 
+impl MPrivateKey {
+    /// The ciphersuite that this private key belongs to
+    pub fn suite(&self) -> CipherSuite {
+        match self {
+            Self::Ed25519(_) => CipherSuite::Ed25519,
+            Self::Secp256k1(_) => CipherSuite::Secp256k1,
+        }
+    }
+}
+
 impl PrivateKey<MultiCipher> for MPrivateKey {
     fn public_key(&self) -> MPublicKey {
         match self {
@@ -0,0 +1,322 @@
+//! Helps users who mistyped or lost a single word of their BIP39 phrase. Because the last word of
+//! a phrase contains a checksum, most guesses can be ruled out without deriving any keys.
+
+use super::*;
+use crate::ed25519::{DidKind, Morpheus};
+use crate::multicipher::MPublicKey;
+use crate::secp256k1::Secp256k1;
+
+/// The kind of mistake a recovered phrase corrects.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum RecoveryKind {
+    /// The word at the given index was unknown, marked with `?` or missing from the phrase.
+    UnknownWord(usize),
+    /// The words at the given indices were swapped.
+    SwappedWords(usize, usize),
+    /// The word at the given index was misspelled.
+    Misspelled(usize),
+}
+
+/// A valid phrase that might be the one the user tried to enter.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RecoveryCandidate {
+    /// The candidate phrase with a valid checksum
+    pub phrase: String,
+    /// The correction applied to the phrase entered by the user
+    pub kind: RecoveryKind,
+    /// The number of characters that had to be inserted, deleted or replaced in the phrase entered
+    /// by the user to get this candidate
+    pub distance: usize,
+}
+
+/// Something known about the original phrase to tell the right candidate from the rest.
+#[derive(Clone)]
+pub enum RecoveryTarget {
+    /// An address of the first account on a Hydra or other Secp256k1 network. The first
+    /// [`RecoveryTarget::GAP`] receiving and change addresses are checked.
+    HydraAddress {
+        /// The network the address belongs to
+        network: &'static dyn Network<Suite = Secp256k1>,
+        /// The P2PKH address
+        address: String,
+    },
+    /// A Morpheus public key of any DID kind among the first [`RecoveryTarget::GAP`] keys.
+    MorpheusPublicKey(MPublicKey),
+}
+
+impl RecoveryTarget {
+    /// The number of keys checked on each chain or DID kind
+    pub const GAP: i32 = 20;
+
+    /// Whether the seed derives the target.
+    pub fn matches(&self, seed: &Seed) -> Result<bool> {
+        match self {
+            Self::HydraAddress { network, address } => {
+                let account = Bip44.network(seed, *network)?.account(0)?;
+                for chain in [Chain::Receiving, Chain::Change] {
+                    let sub_account = account.chain(chain)?;
+                    for idx in 0..Self::GAP {
+                        if sub_account.key(idx)?.neuter().to_p2pkh_addr() == *address {
+                            return Ok(true);
+                        }
+                    }
+                }
+                Ok(false)
+            }
+            Self::MorpheusPublicKey(pk) => {
+                let root = Morpheus.root(seed)?;
+                for kind in DidKind::all() {
+                    let keys = root.kind(*kind)?;
+                    for idx in 0..Self::GAP {
+                        if keys.key(idx)?.neuter().public_key() == *pk {
+                            return Ok(true);
+                        }
+                    }
+                }
+                Ok(false)
+            }
+        }
+    }
+}
+
+impl Bip39 {
+    /// The maximum number of typos corrected in a single word
+    pub const MAX_TYPO_DISTANCE: usize = 2;
+
+    /// Lists valid phrases the user might have meant, closest first. Only a single mistake is
+    /// corrected:
+    /// - a word marked with `?` or missing from a phrase of 23 words is guessed,
+    /// - a word missing from the dictionary is replaced by words spelled similarly,
+    /// - when all words are in the dictionary, but the checksum is wrong, any pair of words is
+    ///   swapped and any word is replaced by words spelled similarly.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use iop_keyvault::{Bip39, RecoveryKind, Seed};
+    /// let typo = Seed::DEMO_PHRASE.replace("include", "inclued");
+    /// let candidates = Bip39::new().recover(&typo).unwrap();
+    /// assert_eq!(candidates[0].phrase, Seed::DEMO_PHRASE);
+    /// assert_eq!(candidates[0].kind, RecoveryKind::Misspelled(0));
+    /// ```
+    pub fn recover(self, phrase: impl AsRef<str>) -> Result<Vec<RecoveryCandidate>> {
+        let words = phrase.as_ref().split_whitespace().collect::<Vec<_>>();
+        let typed = words.join(" ");
+        let unknown = words.iter().enumerate().filter(|(_, w)| !self.check_word(w));
+        let unknown = unknown.map(|(i, _)| i).collect::<Vec<_>>();
+
+        let mut candidates = Vec::new();
+        if words.len() == Self::MNEMONIC_WORDS - 1 {
+            ensure!(unknown.is_empty(), "Only a single word can be recovered");
+            for idx in 0..Self::MNEMONIC_WORDS {
+                let mut guess = words.clone();
+                guess.insert(idx, "?");
+                self.guess_word(&guess, idx, &mut candidates);
+            }
+        } else {
+            ensure!(
+                words.len() == Self::MNEMONIC_WORDS,
+                "Only {}-word mnemonics are supported",
+                Self::MNEMONIC_WORDS
+            );
+            match unknown.as_slice() {
+                [] if self.validate(&typed).is_ok() => {}
+                [] => {
+                    self.swap_words(&words, &mut candidates);
+                    for idx in 0..words.len() {
+                        self.fix_typo(&words, idx, &mut candidates);
+                    }
+                }
+                [idx] if words[*idx] == "?" => self.guess_word(&words, *idx, &mut candidates),
+                [idx] => self.fix_typo(&words, *idx, &mut candidates),
+                _ => bail!("Only a single word can be recovered"),
+            }
+        }
+
+        candidates.iter_mut().for_each(|c| c.distance = levenshtein(&typed, &c.phrase));
+        candidates.sort_by_key(|c| c.distance);
+        Ok(candidates)
+    }
+
+    /// Same as [`Self::recover`], but keeps only the candidates that derive the given target with
+    /// the given BIP39 password.
+    pub fn recover_matching(
+        self, phrase: impl AsRef<str>, password: impl AsRef<str>, target: &RecoveryTarget,
+    ) -> Result<Vec<RecoveryCandidate>> {
+        let mut matching = Vec::new();
+        for candidate in self.recover(phrase)? {
+            let seed = self.phrase(&candidate.phrase)?.password(password.as_ref());
+            if target.matches(&seed)? {
+                matching.push(candidate);
+            }
+        }
+        Ok(matching)
+    }
+
+    fn guess_word(self, words: &[&str], idx: usize, candidates: &mut Vec<RecoveryCandidate>) {
+        for word in self.list_words("") {
+            self.try_candidate(words, idx, word, RecoveryKind::UnknownWord(idx), candidates);
+        }
+    }
+
+    fn fix_typo(self, words: &[&str], idx: usize, candidates: &mut Vec<RecoveryCandidate>) {
+        for word in self.list_words("") {
+            let distance = levenshtein(words[idx], word);
+            if distance > 0 && distance <= Self::MAX_TYPO_DISTANCE {
+                self.try_candidate(words, idx, word, RecoveryKind::Misspelled(idx), candidates);
+            }
+        }
+    }
+
+    fn swap_words(self, words: &[&str], candidates: &mut Vec<RecoveryCandidate>) {
+        for i in 0..words.len() {
+            for j in (i + 1)..words.len() {
+                if words[i] == words[j] {
+                    continue;
+                }
+                let mut swapped = words.to_vec();
+                swapped.swap(i, j);
+                let phrase = swapped.join(" ");
+                if self.validate(&phrase).is_ok() {
+                    let kind = RecoveryKind::SwappedWords(i, j);
+                    candidates.push(RecoveryCandidate { phrase, kind, distance: 0 });
+                }
+            }
+        }
+    }
+
+    fn try_candidate(
+        self, words: &[&str], idx: usize, word: &str, kind: RecoveryKind,
+        candidates: &mut Vec<RecoveryCandidate>,
+    ) {
+        let mut replaced = words.to_vec();
+        replaced[idx] = word;
+        let phrase = replaced.join(" ");
+        if self.validate(&phrase).is_ok() {
+            candidates.push(RecoveryCandidate { phrase, kind, distance: 0 });
+        }
+    }
+}
+
+fn levenshtein(a: &str, b: &str) -> usize {
+    let b = b.chars().collect::<Vec<_>>();
+    let mut prev = (0..=b.len()).collect::<Vec<_>>();
+    for (i, ca) in a.chars().enumerate() {
+        let mut curr = vec![i + 1; b.len() + 1];
+        for (j, cb) in b.iter().enumerate() {
+            let substitution = prev[j] + usize::from(ca != *cb);
+            curr[j + 1] = substitution.min(prev[j + 1] + 1).min(curr[j] + 1);
+        }
+        prev = curr;
+    }
+    prev[b.len()]
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::secp256k1::hyd;
+
+    fn replace_word(idx: usize, word: &str) -> String {
+        let mut words = Seed::DEMO_PHRASE.split(' ').collect::<Vec<_>>();
+        words[idx] = word;
+        words.join(" ")
+    }
+
+    fn position(candidates: &[RecoveryCandidate]) -> Option<usize> {
+        candidates.iter().position(|c| c.phrase == Seed::DEMO_PHRASE)
+    }
+
+    #[test]
+    fn edit_distance() {
+        assert_eq!(levenshtein("", ""), 0);
+        assert_eq!(levenshtein("abandon", "abandon"), 0);
+        assert_eq!(levenshtein("abandon", "abandn"), 1);
+        assert_eq!(levenshtein("kitten", "sitting"), 3);
+        assert_eq!(levenshtein("", "zoo"), 3);
+    }
+
+    #[test]
+    fn unknown_word() -> Result<()> {
+        let candidates = Bip39::new().recover(replace_word(5, "?"))?;
+        assert!(position(&candidates).is_some());
+        assert!(candidates.len() < 32);
+        assert!(candidates.iter().all(|c| c.kind == RecoveryKind::UnknownWord(5)));
+        assert!(candidates.windows(2).all(|w| w[0].distance <= w[1].distance));
+        candidates.iter().try_for_each(|c| Bip39::new().validate(&c.phrase))?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn missing_word() -> Result<()> {
+        let mut words = Seed::DEMO_PHRASE.split(' ').collect::<Vec<_>>();
+        words.remove(10);
+        let candidates = Bip39::new().recover(words.join(" "))?;
+        let idx = position(&candidates).unwrap();
+        assert_eq!(candidates[idx].kind, RecoveryKind::UnknownWord(10));
+
+        Ok(())
+    }
+
+    #[test]
+    fn misspelled() -> Result<()> {
+        let candidates = Bip39::new().recover(replace_word(3, "sakl"))?;
+        assert_eq!(position(&candidates), Some(0));
+        assert_eq!(candidates[0].kind, RecoveryKind::Misspelled(3));
+        assert_eq!(candidates[0].distance, 1);
+
+        Ok(())
+    }
+
+    #[test]
+    fn swapped() -> Result<()> {
+        let mut words = Seed::DEMO_PHRASE.split(' ').collect::<Vec<_>>();
+        words.swap(2, 7);
+        let candidates = Bip39::new().recover(words.join(" "))?;
+        let idx = position(&candidates).unwrap();
+        assert_eq!(candidates[idx].kind, RecoveryKind::SwappedWords(2, 7));
+
+        Ok(())
+    }
+
+    #[test]
+    fn nothing_to_recover() -> Result<()> {
+        assert!(Bip39::new().recover(Seed::DEMO_PHRASE)?.is_empty());
+
+        let two_unknown = replace_word(3, "?").replace("include", "?");
+        assert!(Bip39::new().recover(two_unknown).is_err());
+        assert!(Bip39::new().recover("include pear ?").is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn matching_target() -> Result<()> {
+        let seed = Bip39::new().phrase(Seed::DEMO_PHRASE)?.password(Seed::PASSWORD);
+        let typo = replace_word(20, "?");
+
+        let address = Bip44
+            .network(&seed, &hyd::Testnet)?
+            .account(0)?
+            .chain(Chain::Change)?
+            .key(3)?
+            .neuter()
+            .to_p2pkh_addr();
+        let target = RecoveryTarget::HydraAddress { network: &hyd::Testnet, address };
+        let candidates = Bip39::new().recover_matching(&typo, Seed::PASSWORD, &target)?;
+        assert_eq!(candidates.len(), 1);
+        assert_eq!(candidates[0].phrase, Seed::DEMO_PHRASE);
+
+        let pk = Morpheus.root(&seed)?.devices()?.key(7)?.neuter().public_key();
+        let target = RecoveryTarget::MorpheusPublicKey(pk);
+        let candidates = Bip39::new().recover_matching(&typo, Seed::PASSWORD, &target)?;
+        assert_eq!(candidates.len(), 1);
+        assert_eq!(candidates[0].phrase, Seed::DEMO_PHRASE);
+
+        let candidates = Bip39::new().recover_matching(&typo, "wrong password", &target)?;
+        assert!(candidates.is_empty());
+
+        Ok(())
+    }
+}
@@ -6,6 +6,7 @@ mod ext_pk;
 mod ext_sk;
 mod id;
 mod networks;
+mod origin;
 mod pk;
 mod sig;
 mod sk;
@@ -91,6 +92,7 @@ pub use ext_pk::SecpExtPublicKey;
 pub use ext_sk::SecpExtPrivateKey;
 pub use id::{SecpKeyId, KEY_ID_SIZE, KEY_ID_VERSION1};
 pub use networks::{ark, btc, hyd, iop};
+pub use origin::*;
 pub use pk::{SecpPublicKey, PUBLIC_KEY_SIZE, PUBLIC_KEY_UNCOMPRESSED_SIZE};
 pub use sig::{SecpSignature, SIGNATURE_SIZE, SIGNATURE_VERSION1};
 pub use sk::{SecpPrivateKey, PRIVATE_KEY_SIZE};
@@ -0,0 +1,354 @@
+use super::*;
+use crate::bip44::*;
+
+/// The first 4 bytes of the HASH160 of a compressed public key. BIP32 uses it to identify the
+/// parent of an extended key, and wallets use the fingerprint of the master key to tell which
+/// seed a key was derived from.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Fingerprint([u8; 4]);
+
+impl Fingerprint {
+    /// Calculates the fingerprint of a public key.
+    pub fn of(pk: &SecpPublicKey) -> Self {
+        let hash = hash160(pk.to_bytes());
+        let mut bytes = [0u8; 4];
+        bytes.copy_from_slice(&hash[..4]);
+        Self(bytes)
+    }
+
+    /// The fingerprint of the Secp256k1 master key derived from the seed. It does not depend on
+    /// the network, so the same seed has the same fingerprint in all wallets.
+    pub fn master(seed: &Seed) -> Self {
+        Bip32.master(seed, &btc::Mainnet).fingerprint()
+    }
+
+    /// Creates a fingerprint from its 4 bytes.
+    pub fn from_bytes(bytes: impl AsRef<[u8]>) -> Result<Self> {
+        let bytes = bytes.as_ref();
+        ensure!(bytes.len() == 4, "Fingerprint must be 4 bytes, not {}", bytes.len());
+        let mut fp = [0u8; 4];
+        fp.copy_from_slice(bytes);
+        Ok(Self(fp))
+    }
+
+    /// The 4 bytes of the fingerprint.
+    pub fn to_bytes(&self) -> [u8; 4] {
+        self.0
+    }
+}
+
+impl fmt::Display for Fingerprint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", hex::encode(self.0))
+    }
+}
+
+impl FromStr for Fingerprint {
+    type Err = anyhow::Error;
+    fn from_str(src: &str) -> Result<Self> {
+        ensure!(src.len() == 8, "Fingerprint must be 8 hex digits, got '{}'", src);
+        Self::from_bytes(hex::decode(src)?)
+    }
+}
+
+/// Where a key was derived from: the fingerprint of the master key and the derivation path. Its
+/// textual format is the one used in output descriptors, e.g. `[d34db33f/44'/4741444'/0']`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct KeyOrigin {
+    /// Fingerprint of the master key
+    pub fingerprint: Fingerprint,
+    /// Derivation path from the master key
+    pub path: crate::Path,
+}
+
+impl fmt::Display for KeyOrigin {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "[{}", self.fingerprint)?;
+        for child in self.path.children() {
+            write!(f, "/{}", child)?;
+        }
+        write!(f, "]")
+    }
+}
+
+impl FromStr for KeyOrigin {
+    type Err = anyhow::Error;
+    fn from_str(src: &str) -> Result<Self> {
+        let inner = src
+            .strip_prefix('[')
+            .and_then(|s| s.strip_suffix(']'))
+            .ok_or_else(|| anyhow!("Key origin must be enclosed in brackets, got '{}'", src))?;
+        let (fingerprint, path) = inner.split_once('/').unwrap_or((inner, ""));
+        let fingerprint = fingerprint.parse()?;
+        let path = if path.is_empty() { "m".parse()? } else { format!("m/{}", path).parse()? };
+        Ok(Self { fingerprint, path })
+    }
+}
+
+/// A `pkh(...)` output descriptor of the addresses in a BIP44 account.
+#[derive(Clone, Debug)]
+pub struct PkhDescriptor {
+    /// Fingerprint of the master key the account was derived from
+    pub master_fingerprint: Fingerprint,
+    /// The chain of the addresses, or `None` if the descriptor covers both the receiving and change
+    /// addresses with the `/<0;1>/*` multipath notation
+    pub chain: Option<Chain>,
+    /// The public API of the account
+    pub account: Bip44PublicAccount<Secp256k1>,
+}
+
+impl PkhDescriptor {
+    /// Parses a descriptor like `pkh([d34db33f/44'/4741444'/0']xpub.../0/*)#checksum`. The
+    /// checksum is optional, but it is verified if present.
+    pub fn parse(src: &str, network: &'static dyn Network<Suite = Secp256k1>) -> Result<Self> {
+        let desc = match src.split_once('#') {
+            Some((desc, checksum)) => {
+                let expected = descriptor_checksum(desc)?;
+                ensure!(checksum == expected, "Descriptor checksum should be {}", expected);
+                desc
+            }
+            None => src,
+        };
+        let key = desc
+            .strip_prefix("pkh(")
+            .and_then(|s| s.strip_suffix(')'))
+            .ok_or_else(|| anyhow!("Only pkh(...) descriptors are supported"))?;
+
+        let (key, chain) = if let Some(key) = key.strip_suffix("/<0;1>/*") {
+            (key, None)
+        } else if let Some(key) = key.strip_suffix("/0/*") {
+            (key, Some(Chain::Receiving))
+        } else if let Some(key) = key.strip_suffix("/1/*") {
+            (key, Some(Chain::Change))
+        } else {
+            bail!("Descriptor must end with /0/*, /1/* or /<0;1>/*")
+        };
+
+        let (master_fingerprint, account) = Bip44PublicAccount::from_key_origin(key, network)?;
+        Ok(Self { master_fingerprint, chain, account })
+    }
+}
+
+impl fmt::Display for PkhDescriptor {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let chain = match self.chain {
+            None => "<0;1>",
+            Some(Chain::Receiving) => "0",
+            Some(Chain::Change) => "1",
+        };
+        let desc =
+            format!("pkh({}/{}/*)", self.account.to_key_origin(self.master_fingerprint), chain);
+        // The descriptor only contains characters the checksum is defined for
+        let checksum = descriptor_checksum(&desc).map_err(|_| fmt::Error)?;
+        write!(f, "{}#{}", desc, checksum)
+    }
+}
+
+impl SecpExtPublicKey {
+    /// The fingerprint of this key.
+    pub fn fingerprint(&self) -> Fingerprint {
+        Fingerprint::of(&self.pk)
+    }
+
+    /// The fingerprint of the key this key was derived from. It is all zeros for a master key.
+    pub fn parent_fingerprint(&self) -> Fingerprint {
+        Fingerprint::from_bytes(&self.parent_fingerprint).unwrap_or(Fingerprint([0; 4]))
+    }
+
+    /// The number of derivations from the master key.
+    pub fn depth(&self) -> u8 {
+        self.depth
+    }
+}
+
+impl Bip32Node<Secp256k1> {
+    /// The fingerprint of the public key of this node.
+    pub fn fingerprint(&self) -> Fingerprint {
+        self.neuter().fingerprint()
+    }
+}
+
+impl Bip32PublicNode<Secp256k1> {
+    /// The fingerprint of the public key of this node.
+    pub fn fingerprint(&self) -> Fingerprint {
+        self.xpk().fingerprint()
+    }
+}
+
+impl Bip44PublicAccount<Secp256k1> {
+    /// The extended public key with its key origin, e.g. `[d34db33f/44'/4741444'/0']xpub...`
+    pub fn to_key_origin(&self, master_fingerprint: Fingerprint) -> String {
+        let origin = KeyOrigin { fingerprint: master_fingerprint, path: self.bip32_path().clone() };
+        format!("{}{}", origin, self.to_xpub())
+    }
+
+    /// Parses an extended public key with its key origin. The origin must be a BIP44 account
+    /// path of the network.
+    pub fn from_key_origin(
+        src: &str, network: &'static dyn Network<Suite = Secp256k1>,
+    ) -> Result<(Fingerprint, Self)> {
+        let end = src.find(']').ok_or_else(|| anyhow!("Key origin is missing from '{}'", src))?;
+        let (origin, xpub) = src.split_at(end + 1);
+        let origin: KeyOrigin = origin.parse()?;
+
+        let account = match origin.path.children() {
+            [ChildIndex::Hardened(44), ChildIndex::Hardened(slip44), ChildIndex::Hardened(account)]
+                if *slip44 == network.slip44() =>
+            {
+                *account
+            }
+            _ => bail!(
+                "Key origin {} is not a BIP44 account on {}",
+                origin,
+                network.subtree().name()
+            ),
+        };
+        let public_account = Self::from_xpub(account, xpub, network)?;
+        ensure!(
+            public_account.node().xpk().depth() == 3,
+            "Extended public key is not at the depth of a BIP44 account"
+        );
+        Ok((origin.fingerprint, public_account))
+    }
+
+    /// The output descriptor of the addresses on the given chain, or both chains if `None`.
+    pub fn to_descriptor(&self, master_fingerprint: Fingerprint, chain: Option<Chain>) -> String {
+        let descriptor = PkhDescriptor { master_fingerprint, chain, account: self.clone() };
+        descriptor.to_string()
+    }
+}
+
+const INPUT_CHARSET: &str =
+    "0123456789()[],'/*abcdefgh@:$%{}IJKLMNOPQRSTUVWXYZ&+-.;<=>?!^_|~ijklmnopqrstuvwxyzABCDEFGH`#\"\\ ";
+const CHECKSUM_CHARSET: &[u8] = b"qpzry9x8gf2tvdw0s3jn54khce6mua7l";
+
+fn polymod(c: u64, val: u64) -> u64 {
+    const GENERATOR: [u64; 5] =
+        [0xf5_dee5_1989, 0xa9_fdca_3312, 0x1b_ab10_e32d, 0x37_06b1_677a, 0x64_4d62_6ffd];
+    let c0 = c >> 35;
+    let mut c = ((c & 0x7_ffff_ffff) << 5) ^ val;
+    for (i, generator) in GENERATOR.iter().enumerate() {
+        if c0 & (1 << i) != 0 {
+            c ^= generator;
+        }
+    }
+    c
+}
+
+/// The 8-character checksum of an output descriptor defined in
+/// [BIP-0380](https://github.com/bitcoin/bips/blob/master/bip-0380.mediawiki#checksum).
+pub fn descriptor_checksum(descriptor: &str) -> Result<String> {
+    let mut c = 1u64;
+    let mut cls = 0u64;
+    let mut cls_count = 0;
+    for ch in descriptor.chars() {
+        let pos = INPUT_CHARSET
+            .find(ch)
+            .ok_or_else(|| anyhow!("Invalid character '{}' in descriptor", ch))?
+            as u64;
+        c = polymod(c, pos & 31);
+        cls = cls * 3 + (pos >> 5);
+        cls_count += 1;
+        if cls_count == 3 {
+            c = polymod(c, cls);
+            cls = 0;
+            cls_count = 0;
+        }
+    }
+    if cls_count > 0 {
+        c = polymod(c, cls);
+    }
+    for _ in 0..8 {
+        c = polymod(c, 0);
+    }
+    c ^= 1;
+
+    let checksum = (0..8).map(|j| CHECKSUM_CHARSET[((c >> (5 * (7 - j))) & 31) as usize] as char);
+    Ok(checksum.collect())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn master_fingerprint() -> Result<()> {
+        // Test vector 2 from BIP-0032
+        let seed = Seed::from_bytes(&hex::decode("fffcf9f6f3f0edeae7e4e1dedbd8d5d2cfccc9c6c3c0bdbab7b4b1aeaba8a5a29f9c999693908d8a8784817e7b7875726f6c696663605d5a5754514e4b484542")?)?;
+        let master = Bip32.master(&seed, &btc::Mainnet);
+        assert_eq!(Fingerprint::master(&seed).to_string(), "bd16bee5");
+        assert_eq!(master.fingerprint(), master.neuter().fingerprint());
+
+        let child = master.derive_normal(0)?.neuter();
+        assert_eq!(child.xpk().parent_fingerprint().to_string(), "bd16bee5");
+        assert_eq!(child.xpk().depth(), 1);
+        assert_eq!(master.neuter().xpk().parent_fingerprint().to_bytes(), [0; 4]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn key_origin() -> Result<()> {
+        let origin: KeyOrigin = "[d34db33f/44'/4741444h/0']".parse()?;
+        assert_eq!(origin.fingerprint.to_bytes(), [0xd3, 0x4d, 0xb3, 0x3f]);
+        assert_eq!(origin.path.to_string(), "m/44'/4741444'/0'");
+        assert_eq!(origin.to_string(), "[d34db33f/44'/4741444'/0']");
+
+        let master: KeyOrigin = "[d34db33f]".parse()?;
+        assert!(master.path.children().is_empty());
+        assert_eq!(master.to_string(), "[d34db33f]");
+
+        assert!("d34db33f/44'".parse::<KeyOrigin>().is_err());
+        assert!("[d34db3/44']".parse::<KeyOrigin>().is_err());
+        assert!("[d34db33f/x]".parse::<KeyOrigin>().is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn checksum() -> Result<()> {
+        // Test vectors from BIP-0380 and BIP-0381
+        assert_eq!(descriptor_checksum("raw(deadbeef)")?, "89f8spxm");
+        assert_eq!(descriptor_checksum("pkh([d34db33f/44'/0'/0']xpub6ERApfZwUNrhLCkDtcHTcxd75RbzS1ed54G1LkBUHQVHQKqhMkhgbmJbZRkrgZw4koxb5JaHWkY4ALHY2grBGRjaDMzQLcgJvLJuZZvRcEL/1/*)")?, "ml40v0wf");
+        assert!(descriptor_checksum("pkh(€)").is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn account_descriptor() -> Result<()> {
+        let seed = Bip39::new().phrase(Seed::DEMO_PHRASE)?.password(Seed::PASSWORD);
+        let fingerprint = Fingerprint::master(&seed);
+        let account = Bip44.network(&seed, &hyd::Testnet)?.account(0)?.neuter();
+
+        let key = account.to_key_origin(fingerprint);
+        assert_eq!(key, format!("[{}/44'/1'/0']{}", fingerprint, account.to_xpub()));
+        let (parsed_fp, parsed) = Bip44PublicAccount::from_key_origin(&key, &hyd::Testnet)?;
+        assert_eq!(parsed_fp, fingerprint);
+        assert_eq!(parsed.to_xpub(), account.to_xpub());
+        assert!(Bip44PublicAccount::from_key_origin(&key, &hyd::Mainnet).is_err());
+
+        let descriptor = account.to_descriptor(fingerprint, Some(Chain::Change));
+        let (desc, checksum) = descriptor.split_once('#').unwrap();
+        assert_eq!(desc, format!("pkh({}/1/*)", key));
+        assert_eq!(checksum, descriptor_checksum(desc)?);
+
+        let parsed = PkhDescriptor::parse(&descriptor, &hyd::Testnet)?;
+        assert_eq!(parsed.master_fingerprint, fingerprint);
+        assert_eq!(parsed.chain, Some(Chain::Change));
+        assert_eq!(parsed.account.bip44_path().account(), 0);
+        assert_eq!(parsed.to_string(), descriptor);
+
+        let both = account.to_descriptor(fingerprint, None);
+        assert!(both.contains("/<0;1>/*)#"));
+        assert_eq!(PkhDescriptor::parse(&both, &hyd::Testnet)?.chain, None);
+        assert!(PkhDescriptor::parse(desc, &hyd::Testnet).is_ok());
+
+        let tampered = descriptor.replace("/1/*", "/0/*");
+        assert!(PkhDescriptor::parse(&tampered, &hyd::Testnet).is_err());
+        assert!(PkhDescriptor::parse(&format!("wpkh({}/0/*)", key), &hyd::Testnet).is_err());
+        assert!(PkhDescriptor::parse(&format!("pkh({})", key), &hyd::Testnet).is_err());
+
+        Ok(())
+    }
+}
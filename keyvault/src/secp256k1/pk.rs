@@ -47,6 +47,15 @@ impl SecpPublicKey {
         self.0.serialize()
     }
 
+    /// Creates a public key from the uncompressed format returned by the [`uncompressed`] method.
+    ///
+    /// [`uncompressed`]: #method.uncompressed
+    pub fn from_uncompressed<D: AsRef<[u8]>>(bytes: D) -> Result<Self> {
+        let format = Some(secp::PublicKeyFormat::Full);
+        let pk = secp::PublicKey::parse_slice(bytes.as_ref(), format)?;
+        Ok(Self(pk))
+    }
+
     /// ARK uses a non-standards hashing of the compressed public key.
     pub fn ark_key_id(&self) -> SecpKeyId {
         SecpKeyId::from_ark_pk(self)
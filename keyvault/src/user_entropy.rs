@@ -0,0 +1,413 @@
+//! Entropy collected by hand, like dice rolls or a shuffled deck of cards, for users who do not want
+//! to trust the random generator of their device alone.
+
+use sha2::{Digest, Sha256};
+
+use super::*;
+
+/// Physical sources of randomness a user can read out by hand.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum EntropySource {
+    /// Rolls of fair dice with the given number of sides, e.g. 6 for casino dice or 20 for a d20.
+    /// Each roll is a number from 1 to the number of sides. Dice with at most 9 sides can also be
+    /// written without separators, like `2651334`.
+    Dice(u8),
+    /// The order of cards in a shuffled deck of 52 French playing cards, written like `AS 10H QD 7C`.
+    /// A partial deck is accepted, but each card can appear only once.
+    Cards,
+}
+
+impl EntropySource {
+    /// Parses the name of the source, like `d6`, `d20` or `cards`.
+    pub fn from_name(name: &str) -> Result<Self> {
+        let name = name.trim().to_lowercase();
+        if name == "cards" {
+            return Ok(Self::Cards);
+        }
+        let sides = name
+            .strip_prefix('d')
+            .and_then(|sides| sides.parse::<u8>().ok())
+            .ok_or_else(|| anyhow!("Unknown entropy source {}, use e.g. d6, d20 or cards", name))?;
+        ensure!(sides >= 2, "Dice need at least 2 sides, not {}", sides);
+        Ok(Self::Dice(sides))
+    }
+}
+
+impl fmt::Display for EntropySource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Dice(sides) => write!(f, "d{}", sides),
+            Self::Cards => write!(f, "cards"),
+        }
+    }
+}
+
+/// How user supplied entropy is turned into the entropy of a new mnemonic.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum EntropyMixing {
+    /// XOR the user supplied entropy with the CSPRNG of the platform. The result is at least as
+    /// strong as the stronger of the two, so this is the recommended option.
+    WithSystem,
+    /// Use only the user supplied entropy, e.g. on an air-gapped device with a questionable random
+    /// generator. This needs at least [`UserEntropy::MIN_BITS_ALONE`] bits from the user.
+    UserOnly,
+}
+
+/// Validated entropy supplied by the user with an estimate of its strength and warnings about
+/// patterns that suggest the source was not fair.
+#[derive(Clone, Debug)]
+pub struct UserEntropy {
+    source: EntropySource,
+    digest: [u8; 32],
+    bits: f64,
+    warnings: Vec<String>,
+}
+
+impl UserEntropy {
+    /// The number of bits the user has to contribute for [`EntropyMixing::UserOnly`]. This matches
+    /// the 128-bit security level of the curves the keys are used with, and it is reachable with 50
+    /// rolls of a d6 or a fully shuffled deck of cards (225.6 bits at most).
+    pub const MIN_BITS_ALONE: f64 = 128.0;
+
+    const DECK: usize = 52;
+    const RANKS: [&'static str; 13] =
+        ["A", "2", "3", "4", "5", "6", "7", "8", "9", "10", "J", "Q", "K"];
+    const SUITS: [char; 4] = ['S', 'H', 'D', 'C'];
+
+    /// Validates the input read from the given source.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use iop_keyvault::{EntropySource, UserEntropy};
+    /// let entropy = UserEntropy::parse(EntropySource::Dice(6), "4 2 6 1 3 5 2 2 6 4").unwrap();
+    /// assert_eq!(entropy.bits().floor(), 25.0);
+    /// assert!(UserEntropy::parse(EntropySource::Dice(6), "1 2 7").is_err());
+    /// ```
+    pub fn parse(source: EntropySource, input: &str) -> Result<Self> {
+        let (values, bits, warnings) = match source {
+            EntropySource::Dice(sides) => Self::parse_dice(sides, input)?,
+            EntropySource::Cards => Self::parse_cards(input)?,
+        };
+
+        let canonical = values.join(" ");
+        let mut hasher = Sha256::new();
+        hasher.update(format!("{}:{}", source, canonical).as_bytes());
+        let mut digest = [0u8; 32];
+        digest.copy_from_slice(&hasher.finalize());
+
+        Ok(Self { source, digest, bits, warnings })
+    }
+
+    /// The source the entropy was read from.
+    pub fn source(&self) -> EntropySource {
+        self.source
+    }
+
+    /// The number of bits of entropy the input contributes, assuming the source was fair.
+    pub fn bits(&self) -> f64 {
+        self.bits
+    }
+
+    /// Human readable warnings about patterns in the input that are unlikely from a fair source.
+    /// The input is still accepted, but the user should consider rolling or shuffling again.
+    pub fn warnings(&self) -> &[String] {
+        &self.warnings
+    }
+
+    /// Whether the input is strong enough to be used with [`EntropyMixing::UserOnly`].
+    pub fn is_sufficient_alone(&self) -> bool {
+        self.bits >= Self::MIN_BITS_ALONE
+    }
+
+    /// The 256 bits of entropy to create a mnemonic from with [`Bip39::entropy`].
+    pub fn entropy(&self, mixing: EntropyMixing) -> Result<[u8; 32]> {
+        match mixing {
+            EntropyMixing::WithSystem => {
+                let mut entropy = Bip39::generate_entropy()?;
+                entropy.iter_mut().zip(self.digest.iter()).for_each(|(e, d)| *e ^= d);
+                Ok(entropy)
+            }
+            EntropyMixing::UserOnly => {
+                ensure!(
+                    self.is_sufficient_alone(),
+                    "The input contributed only {:.1} bits of entropy, at least {} bits are needed without system entropy",
+                    self.bits,
+                    Self::MIN_BITS_ALONE
+                );
+                Ok(self.digest)
+            }
+        }
+    }
+
+    fn parse_dice(sides: u8, input: &str) -> Result<(Vec<String>, f64, Vec<String>)> {
+        ensure!(sides >= 2, "Dice need at least 2 sides, not {}", sides);
+        let tokens = Self::tokens(input);
+        let rolls = if sides <= 9 {
+            let digits = tokens.iter().flat_map(|t| t.chars());
+            digits.map(|c| Self::roll(sides, &c.to_string())).collect::<Result<Vec<_>>>()?
+        } else {
+            tokens.iter().map(|t| Self::roll(sides, t)).collect::<Result<Vec<_>>>()?
+        };
+        ensure!(!rolls.is_empty(), "No dice rolls were given");
+
+        let bits = rolls.len() as f64 * f64::from(sides).log2();
+        let mut warnings = Vec::new();
+        Self::check_frequencies(sides, &rolls, &mut warnings);
+        Self::check_runs(sides, &rolls, &mut warnings);
+        let rolls = rolls.iter().map(|r| r.to_string()).collect();
+        Ok((rolls, bits, warnings))
+    }
+
+    fn roll(sides: u8, token: &str) -> Result<u8> {
+        let roll = token.parse::<u8>().ok().filter(|r| (1..=sides).contains(r));
+        roll.ok_or_else(|| anyhow!("Invalid roll '{}' for a {}-sided die", token, sides))
+    }
+
+    // Pearson's chi-squared test against a uniform distribution at a 0.1% significance level. The
+    // critical value is approximated with the Wilson-Hilferty transformation.
+    fn check_frequencies(sides: u8, rolls: &[u8], warnings: &mut Vec<String>) {
+        let sides_count = usize::from(sides);
+        if rolls.len() < 5 * sides_count {
+            return;
+        }
+        let mut counts = vec![0usize; sides_count];
+        rolls.iter().for_each(|r| counts[usize::from(*r) - 1] += 1);
+
+        let expected = rolls.len() as f64 / f64::from(sides);
+        let chi2: f64 = counts.iter().map(|c| (*c as f64 - expected).powi(2) / expected).sum();
+        let df = f64::from(sides) - 1.0;
+        let a = 2.0 / (9.0 * df);
+        let critical = df * (1.0 - a + 3.09 * a.sqrt()).powi(3);
+        if chi2 > critical {
+            let (face, count) =
+                counts.iter().enumerate().max_by_key(|(_, c)| **c).unwrap_or((0, &0));
+            warnings.push(format!(
+                "The rolls are unevenly distributed, e.g. {} was rolled {} times instead of about {:.0}. The die might be biased.",
+                face + 1,
+                count,
+                expected
+            ));
+        }
+    }
+
+    fn check_runs(sides: u8, rolls: &[u8], warnings: &mut Vec<String>) {
+        let mut longest = 1;
+        let mut current = 1;
+        for pair in rolls.windows(2) {
+            current = if pair[0] == pair[1] { current + 1 } else { 1 };
+            longest = longest.max(current);
+        }
+        // Expected number of such runs in a fair sequence of this length
+        let expected = rolls.len() as f64 * f64::from(sides).recip().powi(longest - 1);
+        if longest > 2 && expected < 0.001 {
+            warnings.push(format!(
+                "The same value was rolled {} times in a row, which is very unlikely with a fair die.",
+                longest
+            ));
+        }
+    }
+
+    fn parse_cards(input: &str) -> Result<(Vec<String>, f64, Vec<String>)> {
+        let cards =
+            Self::tokens(input).iter().map(|t| Self::card(t)).collect::<Result<Vec<_>>>()?;
+        ensure!(!cards.is_empty(), "No cards were given");
+        ensure!(cards.len() <= Self::DECK, "A deck has only {} cards", Self::DECK);
+        for (i, card) in cards.iter().enumerate() {
+            ensure!(
+                !cards[..i].contains(card),
+                "Card {} appears more than once",
+                Self::card_name(*card)
+            );
+        }
+
+        // log2(52! / (52 - n)!)
+        let bits = (0..cards.len()).map(|i| ((Self::DECK - i) as f64).log2()).sum();
+
+        let mut warnings = Vec::new();
+        let in_sequence = cards
+            .windows(2)
+            .filter(|pair| {
+                pair[0] / 13 == pair[1] / 13 && (pair[0] % 13).abs_diff(pair[1] % 13) == 1
+            })
+            .count();
+        // Less than 1 neighbour of the same suit and adjacent rank is expected in a full shuffled deck
+        if cards.len() >= 13 && in_sequence > 4 + cards.len() / 13 {
+            warnings.push(format!(
+                "{} cards follow a card of the same suit and adjacent rank. The deck might not have been shuffled well.",
+                in_sequence
+            ));
+        }
+
+        let names = cards.into_iter().map(Self::card_name).collect();
+        Ok((names, bits, warnings))
+    }
+
+    fn card(token: &str) -> Result<usize> {
+        let upper = token.to_uppercase();
+        let parsed = upper.char_indices().last().and_then(|(idx, suit)| {
+            let suit = Self::SUITS.iter().position(|s| *s == suit)?;
+            let rank = match &upper[..idx] {
+                "T" => "10",
+                rank => rank,
+            };
+            let rank = Self::RANKS.iter().position(|r| *r == rank)?;
+            Some(suit * 13 + rank)
+        });
+        parsed.ok_or_else(|| anyhow!("Invalid card '{}', use e.g. AS, 10H, QD or 7C", token))
+    }
+
+    fn card_name(card: usize) -> String {
+        format!("{}{}", Self::RANKS[card % 13], Self::SUITS[card / 13])
+    }
+
+    fn tokens(input: &str) -> Vec<&str> {
+        input.split(|c: char| c.is_whitespace() || c == ',').filter(|t| !t.is_empty()).collect()
+    }
+}
+
+impl fmt::Display for UserEntropy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} contributed {:.1} bits of entropy", self.source, self.bits)
+    }
+}
+
+impl Bip39 {
+    /// Creates a new phrase from entropy supplied by the user, optionally mixed with the CSPRNG of
+    /// the platform. Check [`UserEntropy::warnings`] before calling this.
+    pub fn generate_with(self, user: &UserEntropy, mixing: EntropyMixing) -> Result<Bip39Phrase> {
+        self.entropy(user.entropy(mixing)?)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    // 100 rolls of a d6, twice the 50 rolls needed to be used alone
+    const FAIR_D6: &str = "3625145326 1425361524 3615243162 5431625341 2653142635 \
+                           4152634251 6345216354 1263541632 4521365142 6354126315";
+
+    #[test]
+    fn dice() -> Result<()> {
+        let entropy = UserEntropy::parse(EntropySource::Dice(6), FAIR_D6)?;
+        assert!(entropy.warnings().is_empty());
+        assert_eq!(format!("{:.3}", entropy.bits()), "258.496");
+        assert!(entropy.is_sufficient_alone());
+        assert_eq!(entropy.to_string(), "d6 contributed 258.5 bits of entropy");
+
+        let compact = UserEntropy::parse(EntropySource::Dice(6), &FAIR_D6.replace(' ', ""))?;
+        assert_eq!(
+            compact.entropy(EntropyMixing::UserOnly)?,
+            entropy.entropy(EntropyMixing::UserOnly)?
+        );
+
+        let d20 = UserEntropy::parse(EntropySource::Dice(20), "20, 1, 13, 7")?;
+        assert_eq!(format!("{:.3}", d20.bits()), "17.288");
+
+        assert!(UserEntropy::parse(EntropySource::Dice(6), "").is_err());
+        assert!(UserEntropy::parse(EntropySource::Dice(6), "1 2 0").is_err());
+        assert!(UserEntropy::parse(EntropySource::Dice(6), "12x").is_err());
+        assert!(UserEntropy::parse(EntropySource::Dice(1), "1").is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn dice_bias() -> Result<()> {
+        let loaded = "6".repeat(40) + &"123456".repeat(10);
+        let entropy = UserEntropy::parse(EntropySource::Dice(6), &loaded)?;
+        let warnings = entropy.warnings();
+        assert_eq!(warnings.len(), 2);
+        assert!(warnings[0].contains("6 was rolled 50 times"));
+        assert!(warnings[1].contains("40 times in a row"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn cards() -> Result<()> {
+        let entropy = UserEntropy::parse(EntropySource::Cards, "as 10h Td, QD 7c")?;
+        assert!(entropy.warnings().is_empty());
+        assert_eq!(format!("{:.3}", entropy.bits()), "28.216");
+
+        let err = UserEntropy::parse(EntropySource::Cards, "AS 10H 10H").unwrap_err();
+        assert_eq!(err.to_string(), "Card 10H appears more than once");
+        assert!(UserEntropy::parse(EntropySource::Cards, "1S").is_err());
+        assert!(UserEntropy::parse(EntropySource::Cards, "AX").is_err());
+
+        let fresh_deck = ["S", "H", "D", "C"]
+            .iter()
+            .flat_map(|suit| UserEntropy::RANKS.iter().map(move |rank| format!("{}{}", rank, suit)))
+            .collect::<Vec<_>>()
+            .join(" ");
+        let entropy = UserEntropy::parse(EntropySource::Cards, &fresh_deck)?;
+        assert_eq!(format!("{:.2}", entropy.bits()), "225.58");
+        assert!(entropy.is_sufficient_alone());
+        assert_eq!(entropy.warnings().len(), 1);
+        assert!(entropy.warnings()[0].contains("48 cards follow"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn sufficient_alone() -> Result<()> {
+        let rolls = &FAIR_D6.replace(' ', "");
+        let short = UserEntropy::parse(EntropySource::Dice(6), &rolls[..49])?;
+        assert_eq!(format!("{:.1}", short.bits()), "126.7");
+        assert!(!short.is_sufficient_alone());
+        let enough = UserEntropy::parse(EntropySource::Dice(6), &rolls[..50])?;
+        assert!(enough.is_sufficient_alone());
+
+        let deck = "AS 7H QC 2D 10S 5C KH 9D 3S JC 6H 8D 4C QS 2H 10C 7D KS 5H 9C AD 3H JS 6C \
+                    8H 4D QH 2C 10D 7S KC 5D 9S AH 3C JD 6S 8C 4H QD 2S 10H 7C KD 5S 9H AC 3D JH 6D 8S 4S";
+        let shuffled = UserEntropy::parse(EntropySource::Cards, deck)?;
+        assert!(shuffled.warnings().is_empty());
+        assert!(shuffled.is_sufficient_alone());
+        let phrase = Bip39::new().generate_with(&shuffled, EntropyMixing::UserOnly)?;
+        Bip39::new().validate(phrase.as_phrase())?;
+
+        let partial = deck.split(' ').take(20).collect::<Vec<_>>().join(" ");
+        let partial = UserEntropy::parse(EntropySource::Cards, &partial)?;
+        assert!(!partial.is_sufficient_alone());
+        assert!(partial.entropy(EntropyMixing::UserOnly).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn mixing() -> Result<()> {
+        let dice = UserEntropy::parse(EntropySource::Dice(6), FAIR_D6)?;
+        let alone = dice.entropy(EntropyMixing::UserOnly)?;
+        assert_eq!(alone, dice.entropy(EntropyMixing::UserOnly)?);
+        assert_ne!(alone, dice.entropy(EntropyMixing::WithSystem)?);
+        assert_ne!(
+            dice.entropy(EntropyMixing::WithSystem)?,
+            dice.entropy(EntropyMixing::WithSystem)?
+        );
+
+        let phrase = Bip39::new().generate_with(&dice, EntropyMixing::UserOnly)?;
+        assert_eq!(phrase.as_phrase().split(' ').count(), Bip39::MNEMONIC_WORDS);
+        Bip39::new().validate(phrase.as_phrase())?;
+
+        let few = UserEntropy::parse(EntropySource::Dice(6), "123456")?;
+        assert!(Bip39::new().generate_with(&few, EntropyMixing::UserOnly).is_err());
+        let err = few.entropy(EntropyMixing::UserOnly).unwrap_err();
+        assert!(err.to_string().contains("only 15.5 bits"));
+        Bip39::new().generate_with(&few, EntropyMixing::WithSystem)?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn source_names() -> Result<()> {
+        assert_eq!(EntropySource::from_name("d6")?, EntropySource::Dice(6));
+        assert_eq!(EntropySource::from_name("D20")?, EntropySource::Dice(20));
+        assert_eq!(EntropySource::from_name("cards")?, EntropySource::Cards);
+        assert!(EntropySource::from_name("d1").is_err());
+        assert!(EntropySource::from_name("coins").is_err());
+        assert_eq!(EntropySource::Dice(8).to_string(), "d8");
+
+        Ok(())
+    }
+}
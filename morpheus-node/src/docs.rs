@@ -5,24 +5,54 @@ struct KeyEntry {
     auth: Authentication,
     added_at_height: Option<BlockHeight>,
     expires_at_height: Option<BlockHeight>,
+    expires_at: Option<DateTime<Utc>>,
     revoked_at: Option<BlockHeight>,
     rights: HashMap<Right, TimeSeries<bool>>,
 }
 
+#[derive(Debug, Clone)]
+struct ServiceEntry {
+    service: Service,
+    added_at_height: BlockHeight,
+    revoked_at: Option<BlockHeight>,
+}
+
+impl ServiceEntry {
+    pub fn is_valid_at(
+        &self, tombstoned_at_height: Option<BlockHeight>, height: BlockHeight,
+    ) -> bool {
+        let valid_until = min_of_somes([self.revoked_at, tombstoned_at_height].iter()).cloned();
+        is_height_in_range_exc_until(height, Some(self.added_at_height), valid_until)
+    }
+}
+
 fn min_of_somes<'a, T: Ord>(i: impl Iterator<Item = &'a Option<T>>) -> Option<&'a T> {
     i.filter(|n| n.is_some()).min().and_then(|a| a.as_ref())
 }
 
 impl KeyEntry {
-    pub fn valid_until(&self, tombstoned_at_height: Option<BlockHeight>) -> Option<BlockHeight> {
-        min_of_somes([self.expires_at_height, self.revoked_at, tombstoned_at_height].iter())
-            .cloned()
+    pub fn valid_until(
+        &self, tombstoned_at_height: Option<BlockHeight>, times: &BlockTimestamps,
+    ) -> Option<BlockHeight> {
+        let expires_at_time = self.expires_at.and_then(|time| times.first_height_at(time));
+        let heights =
+            [self.expires_at_height, expires_at_time, self.revoked_at, tombstoned_at_height];
+        min_of_somes(heights.iter()).cloned()
+    }
+
+    pub fn valid_until_time(
+        &self, tombstoned_at_height: Option<BlockHeight>, times: &BlockTimestamps,
+    ) -> Option<DateTime<Utc>> {
+        let valid_until = self.valid_until(tombstoned_at_height, times);
+        let timestamp = valid_until.and_then(|height| times.get(height));
+        min_of_somes([self.expires_at, timestamp].iter()).cloned()
     }
 
     pub fn is_valid_at(
         &self, tombstoned_at_height: Option<BlockHeight>, height: BlockHeight,
+        times: &BlockTimestamps,
     ) -> bool {
-        let valid_until = self.valid_until(tombstoned_at_height);
+        let valid_until = self.valid_until(tombstoned_at_height, times);
         is_height_in_range_exc_until(height, self.added_at_height, valid_until)
     }
 }
@@ -36,7 +66,9 @@ fn system_rights(valid: bool) -> HashMap<Right, TimeSeries<bool>> {
 pub(super) struct DidDocumentState {
     // last entry inserted at the end, because reading performance is unaffected, but writing gets faster
     key_entries: Vec<KeyEntry>,
+    service_entries: Vec<ServiceEntry>,
     tombstoned_at_height: Option<BlockHeight>,
+    update_threshold: TimeSeries<u32>,
 }
 
 impl DidDocumentState {
@@ -47,13 +79,18 @@ impl DidDocumentState {
                 rights: system_rights(true),
                 added_at_height: None,
                 expires_at_height: None,
+                expires_at: None,
                 revoked_at: None,
             }],
+            service_entries: vec![],
             tombstoned_at_height: None,
+            update_threshold: TimeSeries::new(1),
         }
     }
 
-    pub fn at_height(&self, did: &Did, height: BlockHeight) -> Result<DidDocument> {
+    pub fn at_height(
+        &self, did: &Did, height: BlockHeight, times: &BlockTimestamps,
+    ) -> Result<DidDocument> {
         let keys_at_height: Vec<&KeyEntry> = self
             .key_entries
             .iter()
@@ -63,25 +100,45 @@ impl DidDocumentState {
         let keys: Vec<KeyData> = keys_at_height
             .iter()
             .enumerate()
-            .map(|(i, k)| self.key_entry_to_data(*k, i, height))
+            .map(|(i, k)| self.key_entry_to_data(*k, i, height, times))
             .collect();
 
-        let rights: HashMap<Right, Vec<KeyRightHistory>> = Right::map_all(|r| {
-            keys_at_height
-                .iter()
-                .enumerate()
-                .map(|(i, k)| self.key_entry_to_right_history(*k, i, height, r))
-                .collect()
-        });
+        let mut all_rights = Right::system().to_vec();
+        for right in keys_at_height.iter().flat_map(|k| k.rights.keys()) {
+            if !all_rights.contains(right) {
+                all_rights.push(right.to_owned());
+            }
+        }
+        let rights: HashMap<Right, Vec<KeyRightHistory>> = all_rights
+            .into_iter()
+            .map(|r| {
+                let history = keys_at_height
+                    .iter()
+                    .enumerate()
+                    .map(|(i, k)| self.key_entry_to_right_history(*k, i, height, &r, times))
+                    .collect();
+                (r, history)
+            })
+            .collect();
+
+        let services = self
+            .service_entries
+            .iter()
+            .filter(|s| s.is_valid_at(self.tombstoned_at_height, height))
+            .map(|s| s.service.to_owned())
+            .collect();
 
         let doc = DidDocument {
             did: did.to_owned(),
             keys,
             rights,
-            services: Default::default(),
+            services,
             queried_at_height: height,
             tombstoned: self.tombstoned_at_height.is_some(),
             tombstoned_at_height: self.tombstoned_at_height,
+            tombstoned_at_time: self.tombstoned_at_height.and_then(|h| times.get(h)),
+            queried_at_time: times.get(height),
+            update_threshold: *self.update_threshold.get(height),
         };
 
         Ok(doc)
@@ -89,12 +146,17 @@ impl DidDocumentState {
 
     fn key_entry_to_right_history(
         &self, key_entry: &KeyEntry, idx: usize, height: BlockHeight, right: &Right,
+        times: &BlockTimestamps,
     ) -> KeyRightHistory {
         let (items, valid) = if let Some(history) = key_entry.rights.get(right) {
             (
                 history
                     .iter()
-                    .map(|(height, valid)| KeyRightHistoryItem { height, valid: *valid })
+                    .map(|(height, valid)| KeyRightHistoryItem {
+                        height,
+                        valid: *valid,
+                        timestamp: height.and_then(|h| times.get(h)),
+                    })
                     .collect(),
                 *history.get(height),
             )
@@ -109,16 +171,18 @@ impl DidDocumentState {
     }
 
     fn key_entry_to_data(
-        &self, key_entry: &KeyEntry, index: usize, height: BlockHeight,
+        &self, key_entry: &KeyEntry, index: usize, height: BlockHeight, times: &BlockTimestamps,
     ) -> KeyData {
         let state = KeyState {
             authentication: key_entry.auth.to_owned(),
             valid_from_block: key_entry.added_at_height,
-            valid_until_block: key_entry.valid_until(self.tombstoned_at_height),
+            valid_until_block: key_entry.valid_until(self.tombstoned_at_height, times),
+            valid_from_time: key_entry.added_at_height.and_then(|h| times.get(h)),
+            valid_until_time: key_entry.valid_until_time(self.tombstoned_at_height, times),
         };
         let derived = KeyDataDerived {
             index,
-            valid: key_entry.is_valid_at(self.tombstoned_at_height, height),
+            valid: key_entry.is_valid_at(self.tombstoned_at_height, height, times),
         };
         KeyData { state, derived }
     }
@@ -127,35 +191,75 @@ impl DidDocumentState {
         self.key_entries.iter_mut().rev().find(|i| &i.auth == auth)
     }
 
+    fn last_service_by_name(&mut self, name: &str) -> Option<&mut ServiceEntry> {
+        self.service_entries.iter_mut().rev().find(|s| s.service.name == name)
+    }
+
     fn right_history(
         &mut self, did: &Did, height: BlockHeight, auth: &Authentication, right: &str,
+        times: &BlockTimestamps,
     ) -> Result<&mut TimeSeries<bool>> {
         let right: Right = right.parse()?;
         let tombstoned_at_height = self.tombstoned_at_height;
         if let Some(entry) = self.last_by_auth(auth) {
             ensure!(
-                entry.is_valid_at(tombstoned_at_height, height),
+                entry.is_valid_at(tombstoned_at_height, height, times),
                 "Key matching {} of DID {} is invalid at height {}",
                 auth,
                 did,
                 height
             );
 
-            if let Some(history) = entry.rights.get_mut(&right) {
-                Ok(history)
-            } else {
-                bail!(
+            // Custom rights get a history when first granted
+            match entry.rights.entry(right) {
+                Entry::Occupied(history) => Ok(history.into_mut()),
+                Entry::Vacant(history) if !history.key().is_system() => {
+                    Ok(history.insert(TimeSeries::new(false)))
+                }
+                Entry::Vacant(history) => bail!(
                     "Key matching {} of DID {} has no right history of right {}",
                     auth,
                     did,
-                    right
-                );
+                    history.key()
+                ),
             }
         } else {
             bail!("DID {} has no key matching {}", did, auth)
         }
     }
 
+    fn forget_ungranted_right(&mut self, auth: &Authentication, right: &str) -> Result<()> {
+        let right: Right = right.parse()?;
+        if let Some(entry) = self.last_by_auth(auth) {
+            let ungranted = entry.rights.get(&right).map(|h| h.is_empty()).unwrap_or_default();
+            if ungranted && !right.is_system() {
+                entry.rights.remove(&right);
+            }
+        }
+        Ok(())
+    }
+
+    fn ensure_update_threshold_reachable(
+        &self, did: &Did, height: BlockHeight, times: &BlockTimestamps,
+    ) -> Result<()> {
+        let threshold = *self.update_threshold.get(height);
+        let updaters = self
+            .key_entries
+            .iter()
+            .filter(|k| k.is_valid_at(self.tombstoned_at_height, height, times))
+            .filter(|k| k.rights.get(&Right::Update).map(|h| *h.get(height)).unwrap_or_default())
+            .count();
+        ensure!(
+            threshold as usize <= updaters,
+            "DID {} would have only {} keys with update right at height {}, but its update threshold is {}",
+            did,
+            updaters,
+            height,
+            threshold
+        );
+        Ok(())
+    }
+
     fn ensure_min_height(&self, height: BlockHeight) -> Result<()> {
         ensure!(height > 1, "Keys cannot be added before height 2");
         Ok(())
@@ -170,23 +274,43 @@ impl DidDocumentState {
         Ok(())
     }
 
-    fn ensure_different_auth(&self, signer: &Authentication, auth: &Authentication) -> Result<()> {
-        ensure!(signer != auth, "{} cannot modify its own authorization (as {})", signer, auth);
+    fn ensure_different_auth(
+        &self, signers: &[Authentication], auth: &Authentication,
+    ) -> Result<()> {
+        for signer in signers {
+            ensure!(signer != auth, "{} cannot modify its own authorization (as {})", signer, auth);
+        }
         Ok(())
     }
 
     pub fn apply(
-        &mut self, did: &Did, height: BlockHeight, signer: &Authentication,
-        op: &SignableOperationDetails,
+        &mut self, did: &Did, height: BlockHeight, signers: &[Authentication],
+        op: &SignableOperationDetails, times: &BlockTimestamps,
     ) -> Result<()> {
         use SignableOperationDetails::*;
         match op {
-            AddKey { auth, expires_at_height } => {
+            AddKey { auth, expires_at_height, expires_at } => {
                 self.ensure_min_height(height)?;
                 self.ensure_not_tombstoned()?;
+                ensure!(
+                    auth != &Authentication::Did(did.to_owned()),
+                    "DID {} cannot delegate rights to itself",
+                    did
+                );
+                if let (Some(expires_at), Some(now)) = (expires_at, times.get(height)) {
+                    ensure!(
+                        now < *expires_at,
+                        "Key matching {} of DID {} would expire at {}, before block {} at {}",
+                        auth,
+                        did,
+                        expires_at,
+                        height,
+                        now
+                    );
+                }
                 if let Some(existing_entry) = self.last_by_auth(auth) {
                     ensure!(
-                        !existing_entry.is_valid_at(None, height),
+                        !existing_entry.is_valid_at(None, height, times),
                         "DID {} already has a still valid key matching {}",
                         did,
                         auth
@@ -197,16 +321,17 @@ impl DidDocumentState {
                     rights: system_rights(false),
                     added_at_height: Some(height),
                     expires_at_height: *expires_at_height,
+                    expires_at: *expires_at,
                     revoked_at: None,
                 })
             }
             RevokeKey { auth } => {
-                self.ensure_different_auth(signer, auth)?;
+                self.ensure_different_auth(signers, auth)?;
                 self.ensure_min_height(height)?;
                 self.ensure_not_tombstoned()?;
                 if let Some(existing_entry) = self.last_by_auth(auth) {
                     ensure!(
-                        existing_entry.is_valid_at(None, height),
+                        existing_entry.is_valid_at(None, height, times),
                         "DID {} has a key matching {}, but it's already invalidated",
                         did,
                         auth
@@ -221,36 +346,132 @@ impl DidDocumentState {
                 } else {
                     bail!("DID {} does not have a key matching {}", did, auth)
                 }
+                self.ensure_update_threshold_reachable(did, height, times)?;
+            }
+            RotateKey { old, new } => {
+                self.ensure_min_height(height)?;
+                self.ensure_not_tombstoned()?;
+                ensure!(
+                    old != new,
+                    "Key matching {} of DID {} cannot be rotated to itself",
+                    old,
+                    did
+                );
+                ensure!(
+                    new != &Authentication::Did(did.to_owned()),
+                    "DID {} cannot delegate rights to itself",
+                    did
+                );
+                if let Some(existing_entry) = self.last_by_auth(new) {
+                    ensure!(
+                        !existing_entry.is_valid_at(None, height, times),
+                        "DID {} already has a still valid key matching {}",
+                        did,
+                        new
+                    )
+                }
+                let new_entry = if let Some(old_entry) = self.last_by_auth(old) {
+                    ensure!(
+                        old_entry.is_valid_at(None, height, times),
+                        "DID {} has a key matching {}, but it's already invalidated",
+                        did,
+                        old
+                    );
+                    let mut rights = system_rights(false);
+                    for (right, history) in &old_entry.rights {
+                        if *history.get(height) {
+                            rights
+                                .entry(right.to_owned())
+                                .or_insert_with(|| TimeSeries::new(false))
+                                .apply(height, true, || {
+                                    format!("Validity of key {} in DID {}", new, did)
+                                })?;
+                        }
+                    }
+                    old_entry.revoked_at = Some(height);
+                    KeyEntry {
+                        auth: new.clone(),
+                        rights,
+                        added_at_height: Some(height),
+                        expires_at_height: old_entry.expires_at_height,
+                        expires_at: old_entry.expires_at,
+                        revoked_at: None,
+                    }
+                } else {
+                    bail!("DID {} does not have a key matching {}", did, old)
+                };
+                self.key_entries.push(new_entry);
             }
             AddRight { auth, right } => {
-                self.ensure_different_auth(signer, auth)?;
+                self.ensure_different_auth(signers, auth)?;
                 self.ensure_not_tombstoned()?;
-                let history = self.right_history(did, height, auth, right)?;
+                let history = self.right_history(did, height, auth, right, times)?;
                 history
                     .apply(height, true, || format!("Validity of key {} in DID {}", auth, did))?;
             }
             RevokeRight { auth, right } => {
-                self.ensure_different_auth(signer, auth)?;
+                self.ensure_different_auth(signers, auth)?;
                 self.ensure_not_tombstoned()?;
-                let history = self.right_history(did, height, auth, right)?;
+                let history = self.right_history(did, height, auth, right, times)?;
                 history
                     .apply(height, false, || format!("Validity of key {} in DID {}", auth, did))?;
+                self.ensure_update_threshold_reachable(did, height, times)?;
             }
             TombstoneDid {} => {
                 self.ensure_not_tombstoned()?;
                 self.tombstoned_at_height = Some(height);
             }
+            SetUpdateThreshold { threshold } => {
+                self.ensure_not_tombstoned()?;
+                ensure!(*threshold >= 1, "Update threshold of DID {} must be at least 1", did);
+                self.update_threshold
+                    .apply(height, *threshold, || format!("Update threshold of DID {}", did))?;
+                self.ensure_update_threshold_reachable(did, height, times)?;
+            }
+            AddService { service } => {
+                self.ensure_min_height(height)?;
+                self.ensure_not_tombstoned()?;
+                service.validate()?;
+                if let Some(existing_entry) = self.last_service_by_name(&service.name) {
+                    ensure!(
+                        !existing_entry.is_valid_at(None, height),
+                        "DID {} already has a still valid service named {}",
+                        did,
+                        service.name
+                    )
+                }
+                self.service_entries.push(ServiceEntry {
+                    service: service.to_owned(),
+                    added_at_height: height,
+                    revoked_at: None,
+                })
+            }
+            RevokeService { name } => {
+                self.ensure_min_height(height)?;
+                self.ensure_not_tombstoned()?;
+                if let Some(existing_entry) = self.last_service_by_name(name) {
+                    ensure!(
+                        existing_entry.is_valid_at(None, height),
+                        "DID {} has a service named {}, but it's already revoked",
+                        did,
+                        name
+                    );
+                    existing_entry.revoked_at = Some(height);
+                } else {
+                    bail!("DID {} does not have a service named {}", did, name)
+                }
+            }
         }
         Ok(())
     }
 
     pub fn revert(
-        &mut self, did: &Did, height: BlockHeight, signer: &Authentication,
-        op: &SignableOperationDetails,
+        &mut self, did: &Did, height: BlockHeight, signers: &[Authentication],
+        op: &SignableOperationDetails, times: &BlockTimestamps,
     ) -> Result<()> {
         use SignableOperationDetails::*;
         match op {
-            AddKey { auth, expires_at_height } => {
+            AddKey { auth, expires_at_height, expires_at } => {
                 self.ensure_min_height(height)?;
                 self.ensure_not_tombstoned()?;
                 if let Some(last_entry) = self.key_entries.pop() {
@@ -265,7 +486,8 @@ impl DidDocumentState {
                         did
                     );
                     ensure!(
-                        &last_entry.expires_at_height == expires_at_height,
+                        &last_entry.expires_at_height == expires_at_height
+                            && &last_entry.expires_at == expires_at,
                         "Cannot revert addKey in DID {}, because it was not added with the same expiration.",
                         did
                     )
@@ -274,7 +496,7 @@ impl DidDocumentState {
                 }
             }
             RevokeKey { auth } => {
-                self.ensure_different_auth(signer, auth)?;
+                self.ensure_different_auth(signers, auth)?;
                 self.ensure_min_height(height)?;
                 self.ensure_not_tombstoned()?;
                 if let Some(existing_entry) = self.last_by_auth(auth) {
@@ -286,7 +508,7 @@ impl DidDocumentState {
                     );
                     existing_entry.revoked_at = None;
                     ensure!(
-                        existing_entry.is_valid_at(None, height),
+                        existing_entry.is_valid_at(None, height, times),
                         "Failed to revert revokeKey in DID {} for key matching {}. It's still invalid after reverted revoking.",
                         did,
                         auth
@@ -295,17 +517,48 @@ impl DidDocumentState {
                     bail!("Cannot revert revokeKey in DID {} because it does not have a key matching {}", did, auth)
                 }
             }
+            RotateKey { old, new } => {
+                self.ensure_min_height(height)?;
+                self.ensure_not_tombstoned()?;
+                if let Some(last_entry) = self.key_entries.pop() {
+                    ensure!(
+                        &last_entry.auth == new,
+                        "Cannot revert rotateKey in DID {}, because the new key does not match the last added one.",
+                        did
+                    );
+                    ensure!(
+                        last_entry.added_at_height == Some(height),
+                        "Cannot revert rotateKey in DID {}, because the new key was not added at the specified height.",
+                        did
+                    );
+                } else {
+                    bail!("Cannot revert rotateKey in DID {}, because there are no keys", did);
+                }
+                if let Some(existing_entry) = self.last_by_auth(old) {
+                    ensure!(
+                        existing_entry.revoked_at == Some(height),
+                        "Cannot revert rotateKey in DID {} because key matching {} was not revoked at height {}",
+                        did,
+                        old,
+                        height
+                    );
+                    existing_entry.revoked_at = None;
+                } else {
+                    bail!("Cannot revert rotateKey in DID {} because it does not have a key matching {}", did, old)
+                }
+            }
             AddRight { auth, right } => {
-                self.ensure_different_auth(signer, auth)?;
+                self.ensure_different_auth(signers, auth)?;
                 self.ensure_not_tombstoned()?;
-                let history = self.right_history(did, height, auth, right)?;
+                let history = self.right_history(did, height, auth, right, times)?;
                 history
                     .revert(height, true, || format!("Validity of key {} in DID {}", auth, did))?;
+                self.forget_ungranted_right(auth, right)?;
             }
             RevokeRight { auth, right } => {
-                self.ensure_different_auth(signer, auth)?;
+                self.ensure_different_auth(signers, auth)?;
                 self.ensure_not_tombstoned()?;
-                let history = self.right_history(did, height, auth, right)?;
+                let history = self.right_history(did, height, auth, right, times)?;
                 history
                     .revert(height, false, || format!("Validity of key {} in DID {}", auth, did))?;
             }
@@ -317,6 +570,45 @@ impl DidDocumentState {
                 );
                 self.tombstoned_at_height = None;
             }
+            SetUpdateThreshold { threshold } => {
+                self.ensure_not_tombstoned()?;
+                self.update_threshold
+                    .revert(height, *threshold, || format!("Update threshold of DID {}", did))?;
+            }
+            AddService { service } => {
+                self.ensure_min_height(height)?;
+                self.ensure_not_tombstoned()?;
+                if let Some(last_entry) = self.service_entries.pop() {
+                    ensure!(
+                        &last_entry.service == service,
+                        "Cannot revert addService in DID {}, because the service does not match the last added one.",
+                        did
+                    );
+                    ensure!(
+                        last_entry.added_at_height == height,
+                        "Cannot revert addService in DID {}, because it was not added at the specified height.",
+                        did
+                    );
+                } else {
+                    bail!("Cannot revert addService in DID {}, because there are no services", did);
+                }
+            }
+            RevokeService { name } => {
+                self.ensure_min_height(height)?;
+                self.ensure_not_tombstoned()?;
+                if let Some(existing_entry) = self.last_service_by_name(name) {
+                    ensure!(
+                        existing_entry.revoked_at == Some(height),
+                        "Cannot revert revokeService in DID {} because service {} was not revoked at height {}",
+                        did,
+                        name,
+                        height
+                    );
+                    existing_entry.revoked_at = None;
+                } else {
+                    bail!("Cannot revert revokeService in DID {} because it does not have a service named {}", did, name)
+                }
+            }
         }
         Ok(())
     }
@@ -324,8 +616,12 @@ impl DidDocumentState {
 
 #[cfg(test)]
 mod test {
+    use super::*;
+
     use std::cmp::Ordering;
 
+    use iop_morpheus_proto::data::ServiceType;
+
     #[test]
     fn std_option_ordering() {
         assert_eq!(None.cmp(&Some(0u32)), Ordering::Less);
@@ -335,4 +631,193 @@ mod test {
         assert_eq!(Option::<u32>::None.cmp(&None), Ordering::Equal);
         assert_eq!(Option::<u32>::None.partial_cmp(&None), Some(Ordering::Equal));
     }
+
+    #[test]
+    fn custom_rights() -> Result<()> {
+        use SignableOperationDetails::*;
+
+        let did: Did = "did:morpheus:ezqztJ6XX6GDxdSgdiySiT3J".parse()?;
+        let signer = Authentication::KeyId(did.default_key_id());
+        let signers = [signer.clone()];
+        let device: Authentication = "iez25N5WZ1Q6TQpgpyYgiu9gTX".parse()?;
+        let mut state = DidDocumentState::new(&did);
+        let times = BlockTimestamps::default();
+
+        let add_key = AddKey { auth: device.clone(), expires_at_height: None, expires_at: None };
+        state.apply(&did, 5, &signers, &add_key, &times)?;
+        let invalid = AddRight { auth: device.clone(), right: "sign-contracts".to_owned() };
+        assert!(state.apply(&did, 6, &signers, &invalid, &times).is_err());
+        let sign = Right::custom("acme", "sign-contracts")?;
+        let revoke = RevokeRight { auth: device.clone(), right: sign.to_string() };
+        assert!(state.apply(&did, 6, &signers, &revoke, &times).is_err());
+
+        let add = AddRight { auth: device.clone(), right: sign.to_string() };
+        state.apply(&did, 6, &signers, &add, &times)?;
+        assert!(state.apply(&did, 7, &signers, &add, &times).is_err());
+
+        let doc = state.at_height(&did, 7, &times)?;
+        assert_eq!(doc.rights.len(), 3);
+        // The default key never had the custom right
+        assert!(doc.rights[&sign][0].state.history.is_empty());
+        assert!(doc.has_right_at(&device, sign.clone(), 6)?);
+        assert!(!doc.has_right_at(&device, sign.clone(), 5)?);
+        assert!(!doc.has_right_at(&device, Right::Update, 6)?);
+        assert!(!doc.has_right_at(&signer, sign.clone(), 6)?);
+
+        state.apply(&did, 8, &signers, &revoke, &times)?;
+        assert!(!state.at_height(&did, 8, &times)?.has_right_at(&device, sign.clone(), 8)?);
+        state.revert(&did, 8, &signers, &revoke, &times)?;
+
+        state.revert(&did, 6, &signers, &add, &times)?;
+        let doc = state.at_height(&did, 7, &times)?;
+        assert_eq!(doc.rights.len(), 2);
+        assert!(!doc.has_right_at(&device, sign, 6)?);
+
+        Ok(())
+    }
+
+    #[test]
+    fn rotate_key() -> Result<()> {
+        use SignableOperationDetails::*;
+
+        let did: Did = "did:morpheus:ezqztJ6XX6GDxdSgdiySiT3J".parse()?;
+        let signer = Authentication::KeyId(did.default_key_id());
+        let signers = [signer.clone()];
+        let phone: Authentication = "iez25N5WZ1Q6TQpgpyYgiu9gTX".parse()?;
+        let laptop: Authentication = "iez21JXEtMzXjbCK6BAYFU9ewX".parse()?;
+        let sign: Right = "myapp:sign-contracts".parse()?;
+        let mut state = DidDocumentState::new(&did);
+        let times = BlockTimestamps::default();
+
+        let add_key =
+            AddKey { auth: phone.clone(), expires_at_height: Some(100), expires_at: None };
+        state.apply(&did, 5, &signers, &add_key, &times)?;
+        let add_update = AddRight { auth: phone.clone(), right: Right::Update.to_string() };
+        state.apply(&did, 5, &signers, &add_update, &times)?;
+        let add_sign = AddRight { auth: phone.clone(), right: sign.to_string() };
+        state.apply(&did, 5, &signers, &add_sign, &times)?;
+
+        let itself = RotateKey { old: phone.clone(), new: phone.clone() };
+        assert!(state.apply(&did, 7, &signers, &itself, &times).is_err());
+
+        let rotate = RotateKey { old: phone.clone(), new: laptop.clone() };
+        state.apply(&did, 7, &signers, &rotate, &times)?;
+        assert!(state.apply(&did, 8, &signers, &rotate, &times).is_err());
+
+        let doc = state.at_height(&did, 7, &times)?;
+        assert!(doc.has_right_at(&laptop, Right::Update, 7)?);
+        assert!(doc.has_right_at(&laptop, sign.clone(), 7)?);
+        assert!(!doc.has_right_at(&laptop, Right::Impersonation, 7)?);
+        assert!(!doc.has_right_at(&phone, Right::Update, 7)?);
+        assert!(doc.has_right_at(&phone, sign.clone(), 6)?);
+        assert_eq!(doc.keys[2].state.valid_until_block, Some(100));
+
+        state.revert(&did, 7, &signers, &rotate, &times)?;
+        let doc = state.at_height(&did, 7, &times)?;
+        assert_eq!(doc.keys.len(), 2);
+        assert!(doc.has_right_at(&phone, sign, 7)?);
+
+        // A DID with only its default key can rotate that key with its own signature
+        let mut fresh = DidDocumentState::new(&did);
+        let own = RotateKey { old: signer.clone(), new: laptop.clone() };
+        fresh.apply(&did, 5, &signers, &own, &times)?;
+        let doc = fresh.at_height(&did, 5, &times)?;
+        assert!(doc.has_right_at(&laptop, Right::Update, 5)?);
+        assert!(doc.has_right_at(&laptop, Right::Impersonation, 5)?);
+        assert!(!doc.has_right_at(&signer, Right::Update, 5)?);
+        fresh.revert(&did, 5, &signers, &own, &times)?;
+        assert!(fresh.at_height(&did, 5, &times)?.has_right_at(&signer, Right::Update, 5)?);
+
+        Ok(())
+    }
+
+    #[test]
+    fn services() -> Result<()> {
+        use SignableOperationDetails::*;
+
+        let did: Did = "did:morpheus:ezqztJ6XX6GDxdSgdiySiT3J".parse()?;
+        let signer = Authentication::KeyId(did.default_key_id());
+        let signers = [signer.clone()];
+        let hub = Service::new(ServiceType::Storage, "hub", "https://hub.example.com");
+        let mut state = DidDocumentState::new(&did);
+        let times = BlockTimestamps::default();
+
+        let add = AddService { service: hub.clone() };
+        state.apply(&did, 5, &signers, &add, &times)?;
+        assert!(state.apply(&did, 6, &signers, &add, &times).is_err());
+        let invalid = Service::new(ServiceType::Storage, "my hub", "https://hub.example.com");
+        assert!(state.apply(&did, 6, &signers, &AddService { service: invalid }, &times).is_err());
+
+        let revoke = RevokeService { name: "hub".to_owned() };
+        state.apply(&did, 8, &signers, &revoke, &times)?;
+        assert!(state.apply(&did, 9, &signers, &revoke, &times).is_err());
+
+        assert!(state.at_height(&did, 4, &times)?.services.is_empty());
+        assert_eq!(state.at_height(&did, 5, &times)?.services, vec![hub.clone()]);
+        assert_eq!(state.at_height(&did, 7, &times)?.services, vec![hub.clone()]);
+        assert!(state.at_height(&did, 8, &times)?.services.is_empty());
+
+        // Re-adding after revocation is allowed
+        state.apply(&did, 10, &signers, &add, &times)?;
+        assert_eq!(state.at_height(&did, 10, &times)?.services, vec![hub.clone()]);
+        state.revert(&did, 10, &signers, &add, &times)?;
+        assert!(state.at_height(&did, 10, &times)?.services.is_empty());
+
+        assert!(state.revert(&did, 9, &signers, &revoke, &times).is_err());
+        state.revert(&did, 8, &signers, &revoke, &times)?;
+        assert_eq!(state.at_height(&did, 8, &times)?.services, vec![hub]);
+
+        state.apply(&did, 11, &signers, &TombstoneDid {}, &times)?;
+        assert!(state.at_height(&did, 11, &times)?.services.is_empty());
+
+        Ok(())
+    }
+
+    #[test]
+    fn duplicate_service_name() -> Result<()> {
+        use SignableOperationDetails::*;
+
+        let did: Did = "did:morpheus:ezqztJ6XX6GDxdSgdiySiT3J".parse()?;
+        let signer = Authentication::KeyId(did.default_key_id());
+        let signers = [signer.clone()];
+        let mut state = DidDocumentState::new(&did);
+        let times = BlockTimestamps::default();
+
+        let hub = Service::new(ServiceType::Storage, "hub", "https://hub.example.com");
+        state.apply(&did, 5, &signers, &AddService { service: hub }, &times)?;
+        let other = Service::new(ServiceType::LinkedDomains, "hub", "https://example.com");
+        let err =
+            state.apply(&did, 6, &signers, &AddService { service: other }, &times).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            format!("DID {} already has a still valid service named hub", did)
+        );
+        assert_eq!(state.at_height(&did, 6, &times)?.services.len(), 1);
+
+        Ok(())
+    }
+
+    #[test]
+    fn revoke_unknown_service() -> Result<()> {
+        use SignableOperationDetails::*;
+
+        let did: Did = "did:morpheus:ezqztJ6XX6GDxdSgdiySiT3J".parse()?;
+        let signer = Authentication::KeyId(did.default_key_id());
+        let signers = [signer.clone()];
+        let mut state = DidDocumentState::new(&did);
+        let times = BlockTimestamps::default();
+
+        let revoke = RevokeService { name: "hub".to_owned() };
+        let err = state.apply(&did, 5, &signers, &revoke, &times).unwrap_err();
+        assert_eq!(err.to_string(), format!("DID {} does not have a service named hub", did));
+
+        let hub = Service::new(ServiceType::Storage, "hub", "https://hub.example.com");
+        state.apply(&did, 6, &signers, &AddService { service: hub }, &times)?;
+        let unknown = RevokeService { name: "inbox".to_owned() };
+        let err = state.apply(&did, 7, &signers, &unknown, &times).unwrap_err();
+        assert_eq!(err.to_string(), format!("DID {} does not have a service named inbox", did));
+        assert_eq!(state.at_height(&did, 7, &times)?.services.len(), 1);
+
+        Ok(())
+    }
 }
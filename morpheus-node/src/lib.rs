@@ -4,6 +4,7 @@ mod state_holder;
 #[cfg(test)]
 mod test;
 mod time_series;
+mod timestamps;
 mod txns;
 mod util;
 
@@ -14,24 +15,28 @@ use util::*;
 pub use state::*;
 pub use state_holder::*;
 pub use time_series::*;
+pub use timestamps::*;
 
 // imports from standard library
 
-use std::collections::HashMap;
+use std::collections::{hash_map::Entry, HashMap};
 use std::fmt;
 
 // imports from 3rd party crates
 
 use anyhow::{bail, ensure, Result};
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
 // imports from own crates
 
 use iop_journal_proto::BlockHeight;
 use iop_morpheus_proto::{
+    crypto::sign::BeforeProofOracle,
     data::{
-        Authentication, Did, DidDocument, KeyData, KeyDataDerived, KeyRightDerived,
-        KeyRightHistory, KeyRightHistoryItem, KeyRightState, KeyState, OperationError, Right,
+        Authentication, Did, DidDocument, DidDocumentResolver, KeyData, KeyDataDerived,
+        KeyRightDerived, KeyRightHistory, KeyRightHistoryItem, KeyRightState, KeyState,
+        OperationError, Right, Service,
     },
     txtype::{MorpheusAsset, OperationAttempt, SignableOperationDetails, SignedOperation},
 };
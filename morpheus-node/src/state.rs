@@ -3,11 +3,28 @@ use iop_morpheus_proto::data::BeforeProofHistory;
 use super::*;
 
 pub(super) enum Mutation<'a> {
-    SetBlockHeight { height: BlockHeight },
-    RegisterAttempt { txid: &'a str, op: &'a OperationAttempt },
-    DoAttempt { txid: &'a str, op: &'a OperationAttempt },
-    ConfirmTxn { txid: &'a str },
-    RejectTxn { txid: &'a str },
+    SetBlockHeight {
+        height: BlockHeight,
+        timestamp: DateTime<Utc>,
+    },
+    /// Reverting a block only needs its height, the timestamp recorded at applying it is dropped
+    RevertBlockHeight {
+        height: BlockHeight,
+    },
+    RegisterAttempt {
+        txid: &'a str,
+        op: &'a OperationAttempt,
+    },
+    DoAttempt {
+        txid: &'a str,
+        op: &'a OperationAttempt,
+    },
+    ConfirmTxn {
+        txid: &'a str,
+    },
+    RejectTxn {
+        txid: &'a str,
+    },
 }
 
 #[derive(Debug, Default, Clone)]
@@ -19,6 +36,7 @@ pub struct BeforeProofState {
 #[derive(Debug, Default, Clone)]
 pub struct State {
     last_seen_height: BlockHeight,
+    block_timestamps: BlockTimestamps,
     txn_status: HashMap<String, bool>,
     before_proofs: HashMap<String, BeforeProofState>,
     did_states: HashMap<String, DidDocumentState>,
@@ -30,6 +48,10 @@ impl State {
         self.last_seen_height
     }
 
+    pub fn block_timestamp(&self, height: BlockHeight) -> Option<DateTime<Utc>> {
+        self.block_timestamps.get(height)
+    }
+
     pub fn is_confirmed(&self, txid: &str) -> Option<bool> {
         self.txn_status.get(txid).cloned()
     }
@@ -73,13 +95,11 @@ impl State {
         let did: Did = did_data.parse()?;
         let default_state = DidDocumentState::new(&did);
         let state = self.did_states.get(did_data).unwrap_or(&default_state);
-        let doc = state.at_height(&did, height)?;
+        let doc = state.at_height(&did, height, &self.block_timestamps)?;
         Ok(doc)
     }
 
-    fn did_state_mut(
-        &mut self, did: &Did, last_tx_id: &Option<String>,
-    ) -> Result<&mut DidDocumentState> {
+    fn ensure_last_tx_id(&self, did: &Did, last_tx_id: &Option<String>) -> Result<()> {
         let height = self.last_seen_height;
         let did_data = did.to_string();
 
@@ -106,11 +126,18 @@ impl State {
                 chain_state
             )
         }
+        Ok(())
+    }
 
+    fn did_state_mut(
+        &mut self, did: &Did, last_tx_id: &Option<String>,
+    ) -> Result<(&mut DidDocumentState, &BlockTimestamps)> {
+        self.ensure_last_tx_id(did, last_tx_id)?;
+        let did_data = did.to_string();
         let state =
             self.did_states.entry(did_data.clone()).or_insert_with(|| DidDocumentState::new(did));
 
-        Ok(state)
+        Ok((state, &self.block_timestamps))
     }
 
     pub(super) fn apply(&mut self, mutation: Mutation) -> Result<()> {
@@ -152,54 +179,82 @@ impl State {
         }
 
         fn check_state(
-            state: &mut DidDocumentState, did: &Did, height: u32, signer: &Authentication,
+            this: &State, did: &Did, height: u32, signers: &[Authentication],
         ) -> Result<()> {
             let did_data = did.to_string();
 
-            let doc = state.at_height(did, height)?;
+            let doc = this.get_doc_at(&did_data, Some(height))?;
             let tombstoned = doc.is_tombstoned_at(height)?;
-            let can_update = doc.has_right_at(signer, Right::Update, height)?;
 
             ensure!(
                 !tombstoned,
                 "{} cannot update {} at height {}. The DID is tombstoned",
-                signer,
+                signers[0],
                 &did_data,
                 height
             );
 
+            for signer in signers {
+                let can_update = doc.has_delegated_right_at(signer, Right::Update, height, this)?;
+                ensure!(
+                    can_update,
+                    "{} has no right to update {} at height {}",
+                    signer,
+                    &did_data,
+                    height
+                );
+            }
+
+            // Keys with update right may expire, which must not lock the DID for good
+            let mut live_updaters = Vec::new();
+            for key in &doc.keys {
+                let auth = &key.state.authentication;
+                if !live_updaters.contains(&auth)
+                    && doc.has_right_at(auth, Right::Update, height)?
+                {
+                    live_updaters.push(auth);
+                }
+            }
+            let threshold = (doc.update_threshold as usize).min(live_updaters.len());
             ensure!(
-                can_update,
-                "{} has no right to update {} at height {}",
-                signer,
+                threshold <= signers.len(),
+                "{} has an update threshold of {} at height {}, but only {} keys signed",
                 &did_data,
-                height
+                threshold,
+                height,
+                signers.len()
             );
 
             Ok(())
         }
 
         fn apply_signed_op(this: &mut State, op: &SignedOperation) -> Result<()> {
-            op.attempts()?.try_for_each(|a| -> Result<()> {
-                let signer = Authentication::PublicKey(op.signer_public_key.parse()?);
+            let signers: Vec<_> =
+                op.signers()?.into_iter().map(Authentication::PublicKey).collect();
+            op.attempts_unsafe_without_signature_checking().try_for_each(|a| -> Result<()> {
                 let height = this.last_seen_height;
-                let state = this.did_state_mut(&a.did, &a.last_tx_id)?;
-                check_state(state, &a.did, height, &signer)?;
-                state.apply(&a.did, height, &signer, &a.operation)
+                this.ensure_last_tx_id(&a.did, &a.last_tx_id)?;
+                check_state(this, &a.did, height, &signers)?;
+                let (state, times) = this.did_state_mut(&a.did, &a.last_tx_id)?;
+                state.apply(&a.did, height, &signers, &a.operation, times)
             })
         }
 
         use Mutation::*;
         match mutation {
-            SetBlockHeight { height } => {
+            SetBlockHeight { height, timestamp } => {
                 ensure!(
                     self.last_seen_height <= height,
                     "The applied height ({}) is < last seen height ({})",
                     height,
                     self.last_seen_height
                 );
+                self.block_timestamps.apply(height, timestamp)?;
                 self.last_seen_height = height;
             }
+            RevertBlockHeight { height } => {
+                bail!("Reverting block {} cannot be applied", height)
+            }
             RegisterAttempt { txid, op } => {
                 if let OperationAttempt::Signed(signed_op) = op {
                     insert_did_txns(self, txid, signed_op);
@@ -277,23 +332,28 @@ impl State {
         }
 
         fn revert_signed_op(this: &mut State, op: &SignedOperation) -> Result<()> {
+            let signers: Vec<_> =
+                op.signers()?.into_iter().map(Authentication::PublicKey).collect();
             op.attempts()?.rev().try_for_each(|a| -> Result<()> {
-                let signer = Authentication::PublicKey(op.signer_public_key.parse()?);
                 let height = this.last_seen_height;
-                let state = this.did_state_mut(&a.did, &a.last_tx_id)?;
-                state.revert(&a.did, height, &signer, &a.operation)
+                let (state, times) = this.did_state_mut(&a.did, &a.last_tx_id)?;
+                state.revert(&a.did, height, &signers, &a.operation, times)
             })
         }
 
         use Mutation::*;
         match mutation {
-            SetBlockHeight { height } => {
+            SetBlockHeight { height, timestamp: _ } => {
+                bail!("Block {} has to be reverted without its timestamp", height)
+            }
+            RevertBlockHeight { height } => {
                 ensure!(
                     self.last_seen_height >= height,
                     "The reverted height ({}) is > last seen height ({})",
                     height,
                     self.last_seen_height
                 );
+                self.block_timestamps.revert(height);
                 self.last_seen_height = height;
             }
             RegisterAttempt { txid, op } => {
@@ -313,3 +373,15 @@ impl State {
         Ok(())
     }
 }
+
+impl DidDocumentResolver for State {
+    fn resolve_at(&self, did: &Did, height: BlockHeight) -> Result<DidDocument> {
+        self.get_doc_at(&did.to_string(), Some(height))
+    }
+}
+
+impl BeforeProofOracle for State {
+    fn before_proof_height(&self, content_id: &str) -> Result<Option<BlockHeight>> {
+        Ok(self.before_proofs.get(content_id).map(|state| state.height))
+    }
+}
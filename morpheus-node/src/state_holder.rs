@@ -59,9 +59,12 @@ impl StateHolder {
         }
     }
 
-    pub fn block_applying(&mut self, height: BlockHeight) -> Result<()> {
+    /// Every block has to be applied with its timestamp, so all nodes invalidate keys expiring at a
+    /// given time in the same block.
+    pub fn block_applying(&mut self, height: BlockHeight, timestamp: DateTime<Utc>) -> Result<()> {
         self.ensure_not_corrupted()?;
-        self.may_corrupt_state(|inner| inner.apply(Mutation::SetBlockHeight { height }))
+        let mutation = Mutation::SetBlockHeight { height, timestamp };
+        self.may_corrupt_state(|inner| inner.apply(mutation))
     }
 
     pub fn apply_transaction(&mut self, txid: &str, asset: &MorpheusAsset) -> Result<()> {
@@ -92,7 +95,8 @@ impl StateHolder {
 
     pub fn block_reverting(&mut self, height: BlockHeight) -> Result<()> {
         self.ensure_not_corrupted()?;
-        self.may_corrupt_state(|inner| inner.revert(Mutation::SetBlockHeight { height }))
+        let mutation = Mutation::RevertBlockHeight { height };
+        self.may_corrupt_state(|inner| inner.revert(mutation))
     }
 
     pub fn revert_transaction(&mut self, txid: &str, asset: &MorpheusAsset) -> Result<()> {
@@ -1,4 +1,13 @@
-// use super::*;
+use super::*;
+
+use chrono::{Duration, TimeZone};
+use iop_keyvault::{
+    multicipher::MPrivateKey, secp256k1::SecpPrivateKey, PrivateKey as _, PublicKey as _,
+};
+use iop_morpheus_proto::{
+    crypto::sign::PrivateKeySigner,
+    txtype::{SignableOperation, SignableOperationAttempt},
+};
 
 #[test]
 pub fn did_doc_format() {
@@ -7,3 +16,219 @@ pub fn did_doc_format() {
     // let op_attempts1 = vec![OperationAttempt::Signed()]
     // state.apply_transaction("tx1", MorpheusAsset::new(op_attempts1) )
 }
+
+fn signer(phrase: &str) -> (Did, PrivateKeySigner) {
+    let sk = MPrivateKey::from(SecpPrivateKey::from_ark_passphrase(phrase).unwrap());
+    let did = Did::from(sk.public_key().key_id());
+    (did, PrivateKeySigner::new(sk))
+}
+
+fn block_time(height: BlockHeight) -> DateTime<Utc> {
+    Utc.with_ymd_and_hms(2022, 7, 1, 0, 0, 0).unwrap() + Duration::seconds(8 * height as i64)
+}
+
+fn asset(
+    signer: &PrivateKeySigner, did: &Did, last_tx_id: Option<&str>,
+    operation: SignableOperationDetails,
+) -> Result<MorpheusAsset> {
+    let attempt = SignableOperationAttempt {
+        did: did.to_owned(),
+        last_tx_id: last_tx_id.map(|txid| txid.to_owned()),
+        operation,
+    };
+    let signed = SignableOperation::new(vec![attempt]).sign(signer)?;
+    Ok(MorpheusAsset::new(vec![OperationAttempt::Signed(signed)]))
+}
+
+#[test]
+fn delegated_update() -> Result<()> {
+    let (org, org_signer) = signer("organization");
+    let (admin, admin_signer) = signer("admin");
+    let (_, stranger_signer) = signer("stranger");
+    let tombstone = SignableOperationDetails::TombstoneDid {};
+
+    let mut holder = StateHolder::default();
+    holder.block_applying(5, block_time(5))?;
+    let delegate = SignableOperationDetails::AddKey {
+        auth: Authentication::Did(admin.clone()),
+        expires_at_height: None,
+        expires_at: None,
+    };
+    holder.apply_transaction("tx1", &asset(&org_signer, &org, None, delegate)?)?;
+    assert_eq!(holder.state()?.is_confirmed("tx1"), Some(true));
+
+    let itself = SignableOperationDetails::AddKey {
+        auth: Authentication::Did(org.clone()),
+        expires_at_height: None,
+        expires_at: None,
+    };
+    let err = holder.apply_transaction("tx2", &asset(&org_signer, &org, Some("tx1"), itself)?);
+    assert!(err.unwrap_err().to_string().contains("to itself"));
+    assert_eq!(holder.state()?.is_confirmed("tx2"), Some(false));
+
+    // The delegate DID has no update right yet
+    holder.block_applying(6, block_time(6))?;
+    let stranger_op = asset(&stranger_signer, &org, Some("tx1"), tombstone.clone())?;
+    assert_eq!(holder.dry_run(&stranger_op).len(), 1);
+    let admin_op = asset(&admin_signer, &org, Some("tx1"), tombstone.clone())?;
+    assert_eq!(holder.dry_run(&admin_op).len(), 1);
+
+    let add_right = SignableOperationDetails::AddRight {
+        auth: Authentication::Did(admin.clone()),
+        right: Right::Update.to_string(),
+    };
+    holder.apply_transaction("tx3", &asset(&org_signer, &org, Some("tx1"), add_right)?)?;
+    assert_eq!(holder.state()?.is_confirmed("tx3"), Some(true));
+
+    holder.block_applying(7, block_time(7))?;
+    let admin_op = asset(&admin_signer, &org, Some("tx3"), tombstone)?;
+    assert!(holder.dry_run(&admin_op).is_empty());
+    holder.apply_transaction("tx4", &admin_op)?;
+    assert!(holder.state()?.get_doc_at(&org.to_string(), None)?.tombstoned);
+
+    Ok(())
+}
+
+#[test]
+fn key_expiring_at_time() -> Result<()> {
+    let time = |hour| Utc.with_ymd_and_hms(2022, 7, 1, hour, 0, 0).unwrap();
+    let (did, did_signer) = signer("expiring");
+    let key_sk = MPrivateKey::from(SecpPrivateKey::from_ark_passphrase("expiring key").unwrap());
+    let key = Authentication::PublicKey(key_sk.public_key());
+    let add_key = |expires_at| SignableOperationDetails::AddKey {
+        auth: key.clone(),
+        expires_at_height: None,
+        expires_at: Some(expires_at),
+    };
+
+    let mut holder = StateHolder::default();
+    holder.block_applying(5, time(10))?;
+    let expired = add_key(time(10));
+    let err = holder.apply_transaction("tx1", &asset(&did_signer, &did, None, expired)?);
+    assert!(err.unwrap_err().to_string().contains("would expire"));
+
+    holder.apply_transaction("tx2", &asset(&did_signer, &did, None, add_key(time(12)))?)?;
+    assert_eq!(holder.state()?.is_confirmed("tx2"), Some(true));
+    holder.block_applying(6, time(11))?;
+    assert!(holder.block_applying(7, time(9)).is_err());
+    assert!(holder.is_corrupted());
+
+    let mut holder = StateHolder::default();
+    holder.block_applying(5, time(10))?;
+    holder.apply_transaction("tx2", &asset(&did_signer, &did, None, add_key(time(12)))?)?;
+    holder.block_applying(6, time(11))?;
+    let doc = holder.state()?.get_doc_at(&did.to_string(), None)?;
+    assert!(doc.keys[1].derived.valid);
+    assert_eq!(doc.keys[1].state.valid_until_block, None);
+    assert_eq!(doc.keys[1].state.valid_until_time, Some(time(12)));
+    assert_eq!(doc.queried_at_time, Some(time(11)));
+
+    holder.block_applying(7, time(12))?;
+    let doc = holder.state()?.get_doc_at(&did.to_string(), None)?;
+    assert!(!doc.keys[1].derived.valid);
+    assert_eq!(doc.keys[1].state.valid_until_block, Some(7));
+    assert_eq!(doc.keys[1].state.valid_from_time, Some(time(10)));
+
+    holder.block_reverting(7)?;
+    assert_eq!(holder.state()?.block_timestamp(7), None);
+    let doc = holder.state()?.get_doc_at(&did.to_string(), Some(6))?;
+    assert!(doc.keys[1].derived.valid);
+    assert_eq!(doc.keys[1].state.valid_until_block, None);
+
+    Ok(())
+}
+
+#[test]
+fn update_threshold() -> Result<()> {
+    let (did, did_signer) = signer("group");
+    let key_sk = MPrivateKey::from(SecpPrivateKey::from_ark_passphrase("group member").unwrap());
+    let key = Authentication::PublicKey(key_sk.public_key());
+    let key_signer = PrivateKeySigner::new(key_sk);
+    let tombstone = SignableOperationDetails::TombstoneDid {};
+
+    let mut holder = StateHolder::default();
+    holder.block_applying(5, block_time(5))?;
+    let add_key = SignableOperationDetails::AddKey {
+        auth: key.clone(),
+        expires_at_height: None,
+        expires_at: None,
+    };
+    holder.apply_transaction("tx1", &asset(&did_signer, &did, None, add_key)?)?;
+
+    // Only a single key has update right yet
+    let threshold = SignableOperationDetails::SetUpdateThreshold { threshold: 2 };
+    let err = holder.apply_transaction("tx2", &asset(&did_signer, &did, Some("tx1"), threshold)?);
+    assert!(err.unwrap_err().to_string().contains("update threshold is 2"));
+
+    let add_right =
+        SignableOperationDetails::AddRight { auth: key.clone(), right: Right::Update.to_string() };
+    holder.apply_transaction("tx3", &asset(&did_signer, &did, Some("tx1"), add_right)?)?;
+    let threshold = SignableOperationDetails::SetUpdateThreshold { threshold: 2 };
+    holder.apply_transaction("tx4", &asset(&did_signer, &did, Some("tx3"), threshold)?)?;
+    assert_eq!(holder.state()?.get_doc_at(&did.to_string(), None)?.update_threshold, 2);
+
+    holder.block_applying(6, block_time(6))?;
+    let single_op = asset(&did_signer, &did, Some("tx4"), tombstone.clone())?;
+    assert_eq!(holder.dry_run(&single_op).len(), 1);
+
+    // A cosigner cannot revoke its own key either
+    let revoke = SignableOperationAttempt {
+        did: did.clone(),
+        last_tx_id: Some("tx4".to_owned()),
+        operation: SignableOperationDetails::RevokeKey { auth: key.clone() },
+    };
+    let mut signed = SignableOperation::new(vec![revoke]).sign(&did_signer)?;
+    signed.cosign(&key_signer)?;
+    let self_revoke = MorpheusAsset::new(vec![OperationAttempt::Signed(signed)]);
+    let errors = holder.dry_run(&self_revoke);
+    assert_eq!(errors.len(), 1);
+    assert!(errors[0].message.contains("cannot modify its own authorization"));
+
+    let attempt = SignableOperationAttempt {
+        did: did.clone(),
+        last_tx_id: Some("tx4".to_owned()),
+        operation: tombstone,
+    };
+    let mut signed = SignableOperation::new(vec![attempt]).sign(&did_signer)?;
+    signed.cosign(&key_signer)?;
+    let cosigned_op = MorpheusAsset::new(vec![OperationAttempt::Signed(signed)]);
+    assert!(holder.dry_run(&cosigned_op).is_empty());
+    holder.apply_transaction("tx5", &cosigned_op)?;
+    assert!(holder.state()?.get_doc_at(&did.to_string(), None)?.tombstoned);
+
+    Ok(())
+}
+
+#[test]
+fn update_threshold_with_expiring_key() -> Result<()> {
+    let (did, did_signer) = signer("expiring group");
+    let key_sk = MPrivateKey::from(SecpPrivateKey::from_ark_passphrase("temporary").unwrap());
+    let key = Authentication::PublicKey(key_sk.public_key());
+    let tombstone = SignableOperationDetails::TombstoneDid {};
+
+    let mut holder = StateHolder::default();
+    holder.block_applying(5, block_time(5))?;
+    let add_key = SignableOperationDetails::AddKey {
+        auth: key.clone(),
+        expires_at_height: Some(10),
+        expires_at: None,
+    };
+    holder.apply_transaction("tx1", &asset(&did_signer, &did, None, add_key)?)?;
+    let add_right =
+        SignableOperationDetails::AddRight { auth: key.clone(), right: Right::Update.to_string() };
+    holder.apply_transaction("tx2", &asset(&did_signer, &did, Some("tx1"), add_right)?)?;
+    let threshold = SignableOperationDetails::SetUpdateThreshold { threshold: 2 };
+    holder.apply_transaction("tx3", &asset(&did_signer, &did, Some("tx2"), threshold)?)?;
+
+    holder.block_applying(9, block_time(9))?;
+    let single_op = asset(&did_signer, &did, Some("tx3"), tombstone)?;
+    assert_eq!(holder.dry_run(&single_op).len(), 1);
+
+    // After the other updater expired, the threshold is capped at the single key left
+    holder.block_applying(10, block_time(10))?;
+    assert!(holder.dry_run(&single_op).is_empty());
+    holder.apply_transaction("tx4", &single_op)?;
+    assert!(holder.state()?.get_doc_at(&did.to_string(), None)?.tombstoned);
+
+    Ok(())
+}
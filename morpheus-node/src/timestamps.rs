@@ -0,0 +1,64 @@
+use super::*;
+
+#[derive(Debug, Clone)]
+struct BlockTimestamp {
+    height: BlockHeight,
+    timestamp: DateTime<Utc>,
+}
+
+/// Timestamps of applied blocks, so keys can expire at a given time instead of a block height.
+#[derive(Debug, Default, Clone)]
+pub struct BlockTimestamps {
+    points: Vec<BlockTimestamp>,
+}
+
+impl BlockTimestamps {
+    pub fn get(&self, height: BlockHeight) -> Option<DateTime<Utc>> {
+        self.points
+            .binary_search_by_key(&height, |p| p.height)
+            .ok()
+            .map(|idx| self.points[idx].timestamp)
+    }
+
+    /// The first known block having a timestamp not earlier than the given time
+    pub fn first_height_at(&self, time: DateTime<Utc>) -> Option<BlockHeight> {
+        let idx = self.points.partition_point(|p| p.timestamp < time);
+        self.points.get(idx).map(|p| p.height)
+    }
+
+    pub fn apply(&mut self, height: BlockHeight, timestamp: DateTime<Utc>) -> Result<()> {
+        if let Some(last) = self.points.last() {
+            if last.height == height {
+                ensure!(
+                    last.timestamp == timestamp,
+                    "Block {} was already applied with timestamp {}",
+                    height,
+                    last.timestamp
+                );
+                return Ok(());
+            }
+            ensure!(
+                last.height < height,
+                "The applied height ({}) is < last timestamped height ({})",
+                height,
+                last.height
+            );
+            ensure!(
+                last.timestamp <= timestamp,
+                "Timestamp {} of block {} is earlier than {} of block {}",
+                timestamp,
+                height,
+                last.timestamp,
+                last.height
+            );
+        }
+        self.points.push(BlockTimestamp { height, timestamp });
+        Ok(())
+    }
+
+    /// Forgets the timestamps of the reverted block and all blocks after it
+    pub fn revert(&mut self, height: BlockHeight) {
+        let idx = self.points.partition_point(|p| p.height < height);
+        self.points.truncate(idx);
+    }
+}
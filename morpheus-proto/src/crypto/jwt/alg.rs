@@ -1,6 +1,6 @@
 use std::borrow::Cow;
 
-use anyhow::anyhow;
+use anyhow::{anyhow, bail};
 
 use super::*;
 
@@ -26,7 +26,7 @@ impl Algorithm for JwtMultiCipher {
     type Signature = JwtSignature;
 
     fn name(&self) -> Cow<'static, str> {
-        Cow::Borrowed("Multicipher")
+        Cow::Borrowed(JwtAlgorithm::MultiCipher.name())
     }
 
     fn sign(&self, signing_key: &MPrivateKey, message: &[u8]) -> JwtSignature {
@@ -39,3 +39,157 @@ impl Algorithm for JwtMultiCipher {
         verifying_key.verify(message, &signature.0)
     }
 }
+
+/// JOSE uses the raw signature without our version byte prefix
+pub struct JwtEdSignature(EdSignature);
+
+impl AlgorithmSignature for JwtEdSignature {
+    fn as_bytes(&self) -> Cow<'_, [u8]> {
+        Cow::Owned(self.0.to_bytes()[1..].to_vec())
+    }
+
+    fn try_from_slice(bytes: &[u8]) -> anyhow::Result<Self> {
+        let versioned = [&[ed25519::SIGNATURE_VERSION1][..], bytes].concat();
+        let inner = EdSignature::from_bytes(versioned).map_err(|e| anyhow!("{}", e))?;
+        Ok(JwtEdSignature(inner))
+    }
+}
+
+/// The `EdDSA` algorithm of [RFC 8037](https://www.rfc-editor.org/rfc/rfc8037) with Ed25519 keys
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct JwtEdDsa;
+
+impl Algorithm for JwtEdDsa {
+    type SigningKey = EdPrivateKey;
+    type VerifyingKey = EdPublicKey;
+    type Signature = JwtEdSignature;
+
+    fn name(&self) -> Cow<'static, str> {
+        Cow::Borrowed(JwtAlgorithm::EdDsa.name())
+    }
+
+    fn sign(&self, signing_key: &EdPrivateKey, message: &[u8]) -> JwtEdSignature {
+        JwtEdSignature(signing_key.sign(message))
+    }
+
+    fn verify_signature(
+        &self, signature: &JwtEdSignature, verifying_key: &EdPublicKey, message: &[u8],
+    ) -> bool {
+        verifying_key.verify(message, &signature.0)
+    }
+}
+
+/// JOSE uses the raw `r || s` signature without our version byte prefix
+pub struct JwtSecpSignature(SecpSignature);
+
+impl AlgorithmSignature for JwtSecpSignature {
+    fn as_bytes(&self) -> Cow<'_, [u8]> {
+        Cow::Owned(self.0.to_bytes()[1..].to_vec())
+    }
+
+    fn try_from_slice(bytes: &[u8]) -> anyhow::Result<Self> {
+        let versioned = [&[secp256k1::SIGNATURE_VERSION1][..], bytes].concat();
+        let inner = SecpSignature::from_bytes(versioned).map_err(|e| anyhow!("{}", e))?;
+        Ok(JwtSecpSignature(inner))
+    }
+}
+
+/// The `ES256K` algorithm of [RFC 8812](https://www.rfc-editor.org/rfc/rfc8812), which is ECDSA
+/// over the SHA-256 digest with Secp256k1 keys
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct JwtEs256k;
+
+impl Algorithm for JwtEs256k {
+    type SigningKey = SecpPrivateKey;
+    type VerifyingKey = SecpPublicKey;
+    type Signature = JwtSecpSignature;
+
+    fn name(&self) -> Cow<'static, str> {
+        Cow::Borrowed(JwtAlgorithm::Es256k.name())
+    }
+
+    fn sign(&self, signing_key: &SecpPrivateKey, message: &[u8]) -> JwtSecpSignature {
+        JwtSecpSignature(signing_key.sign(message))
+    }
+
+    fn verify_signature(
+        &self, signature: &JwtSecpSignature, verifying_key: &SecpPublicKey, message: &[u8],
+    ) -> bool {
+        verifying_key.verify(message, &signature.0)
+    }
+}
+
+/// The `alg` header values a Morpheus JWT can be signed with
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum JwtAlgorithm {
+    /// Our own algorithm accepting any multicipher key. Standard JWT libraries cannot verify it,
+    /// it is kept for backward compatibility.
+    MultiCipher,
+    /// `EdDSA` for Ed25519 keys
+    EdDsa,
+    /// `ES256K` for Secp256k1 keys
+    Es256k,
+}
+
+impl JwtAlgorithm {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::MultiCipher => "Multicipher",
+            Self::EdDsa => "EdDSA",
+            Self::Es256k => "ES256K",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Result<Self> {
+        match name {
+            "Multicipher" => Ok(Self::MultiCipher),
+            "EdDSA" => Ok(Self::EdDsa),
+            "ES256K" => Ok(Self::Es256k),
+            _ => bail!("Unsupported JWT algorithm '{}'", name),
+        }
+    }
+
+    /// The standard JOSE algorithm for keys of the given cipher suite
+    pub fn standard_for(suite: CipherSuite) -> Self {
+        match suite {
+            CipherSuite::Ed25519 => Self::EdDsa,
+            CipherSuite::Secp256k1 => Self::Es256k,
+        }
+    }
+
+    pub(super) fn token<T: Serialize>(
+        &self, header: Header, claims: &Claims<T>, sk: &MPrivateKey,
+    ) -> Result<String> {
+        let token = match (self, sk) {
+            (Self::MultiCipher, _) => JwtMultiCipher.token(header, claims, sk)?,
+            (Self::EdDsa, MPrivateKey::Ed25519(edsk)) => JwtEdDsa.token(header, claims, edsk)?,
+            (Self::Es256k, MPrivateKey::Secp256k1(secpsk)) => {
+                JwtEs256k.token(header, claims, secpsk)?
+            }
+            _ => bail!("{} cannot sign with a {} key", self.name(), sk.suite()),
+        };
+        Ok(token)
+    }
+
+    pub(super) fn validate_integrity<T: DeserializeOwned>(
+        &self, untrusted: &UntrustedToken, pk: &MPublicKey,
+    ) -> Result<Token<T>> {
+        let token = match (self, pk) {
+            (Self::MultiCipher, _) => JwtMultiCipher.validate_integrity(untrusted, pk)?,
+            (Self::EdDsa, MPublicKey::Ed25519(edpk)) => {
+                JwtEdDsa.validate_integrity(untrusted, edpk)?
+            }
+            (Self::Es256k, MPublicKey::Secp256k1(secppk)) => {
+                JwtEs256k.validate_integrity(untrusted, secppk)?
+            }
+            _ => bail!("{} cannot verify with a {} key", self.name(), pk.suite()),
+        };
+        Ok(token)
+    }
+}
+
+impl fmt::Display for JwtAlgorithm {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
@@ -1,16 +1,23 @@
 mod alg;
 mod token;
 
-pub use alg::JwtMultiCipher;
-pub use token::{JwtBuilder, JwtParser};
+pub use alg::{
+    JwtAlgorithm, JwtEdDsa, JwtEdSignature, JwtEs256k, JwtMultiCipher, JwtSecpSignature,
+    JwtSignature,
+};
+pub use token::{JwtBuilder, JwtParser, JwtValidation};
 
 use std::convert::TryFrom;
+use std::fmt;
 
 use chrono::{DateTime, Duration, Utc};
 use jwt_compact::{prelude::*, Algorithm, AlgorithmSignature, Token};
+use serde::de::DeserializeOwned;
 
 use iop_keyvault::{
-    multicipher::{MPrivateKey, MPublicKey, MSignature},
+    ed25519::{self, EdPrivateKey, EdPublicKey, EdSignature},
+    multicipher::{CipherSuite, MPrivateKey, MPublicKey, MSignature},
+    secp256k1::{self, SecpPrivateKey, SecpPublicKey, SecpSignature},
     PrivateKey as _, PublicKey as _,
 };
 
@@ -1,39 +1,129 @@
 use anyhow::Context;
 use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
 
 use super::hash::ContentId;
 use super::*;
+use crate::data::Did;
+
+/// Claim names that have their dedicated fields and cannot be set as custom claims
+const RESERVED_CLAIMS: &[&str] = &["jti", "iss", "sub", "aud", "nonce", "exp", "nbf", "iat"];
 
 #[derive(Debug, Default, PartialEq, Serialize, Deserialize)]
 struct JwtClaim {
     #[serde(rename = "jti", skip_serializing_if = "Option::is_none", default)]
     content_id: Option<ContentId>,
+    /// Tokens issued by other JWT libraries might have an URL or any string here, not a DID
+    #[serde(rename = "iss", skip_serializing_if = "Option::is_none", default)]
+    issuer: Option<String>,
+    #[serde(rename = "sub", skip_serializing_if = "Option::is_none", default)]
+    subject: Option<String>,
+    #[serde(rename = "aud", with = "audience", skip_serializing_if = "Vec::is_empty", default)]
+    audience: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    nonce: Option<String>,
+    #[serde(flatten)]
+    custom: Map<String, Value>,
+}
+
+/// RFC 7519 allows `aud` to be a single string or an array of strings
+mod audience {
+    use super::*;
+    use serde::{Deserializer, Serializer};
+
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum OneOrMany {
+        One(String),
+        Many(Vec<String>),
+    }
+
+    pub fn serialize<S: Serializer>(audience: &[String], serializer: S) -> Result<S::Ok, S::Error> {
+        match audience {
+            [single] => single.serialize(serializer),
+            many => many.serialize(serializer),
+        }
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Vec<String>, D::Error> {
+        match OneOrMany::deserialize(deserializer)? {
+            OneOrMany::One(single) => Ok(vec![single]),
+            OneOrMany::Many(many) => Ok(many),
+        }
+    }
 }
 
 pub struct JwtBuilder {
     pub content_id: Option<ContentId>,
     pub time_to_live: Duration,
     pub created_at: DateTime<Utc>,
+    /// When not set, the standard algorithm is picked based on the suite of the signing key
+    pub algorithm: Option<JwtAlgorithm>,
+    pub issuer: Option<Did>,
+    pub subject: Option<Did>,
+    pub audience: Vec<String>,
+    pub nonce: Option<String>,
+    custom: Map<String, Value>,
 }
 
 impl JwtBuilder {
     pub fn new() -> Self {
-        JwtBuilder { content_id: None, time_to_live: Duration::minutes(5), created_at: Utc::now() }
+        JwtBuilder {
+            content_id: None,
+            time_to_live: Duration::minutes(5),
+            created_at: Utc::now(),
+            algorithm: None,
+            issuer: None,
+            subject: None,
+            audience: Vec::new(),
+            nonce: None,
+            custom: Map::new(),
+        }
     }
 
     pub fn with_content_id(content_id: ContentId) -> Self {
         JwtBuilder { content_id: Some(content_id), ..Self::new() }
     }
 
+    /// Adds an application specific claim to the token. Registered claim names and the ones
+    /// having their own fields in the builder are rejected.
+    pub fn set_custom_claim<T: Serialize>(&mut self, name: &str, value: &T) -> Result<()> {
+        ensure!(
+            !RESERVED_CLAIMS.contains(&name),
+            "Claim '{}' cannot be set as a custom claim",
+            name
+        );
+        self.custom.insert(name.to_owned(), serde_json::to_value(value)?);
+        Ok(())
+    }
+
+    pub fn remove_custom_claim(&mut self, name: &str) -> Option<Value> {
+        self.custom.remove(name)
+    }
+
+    pub fn custom_claims(&self) -> &Map<String, Value> {
+        &self.custom
+    }
+
     pub fn sign(&self, sk: &MPrivateKey) -> Result<String> {
         let pk = sk.public_key();
+        let algorithm = self.algorithm.unwrap_or_else(|| JwtAlgorithm::standard_for(sk.suite()));
         let header = Header::default().with_key_id(pk.to_string());
         let options = TimeOptions::new(Duration::seconds(0), || self.created_at);
-        let claims = Claims::new(JwtClaim { content_id: self.content_id.clone() })
+        let claim = JwtClaim {
+            content_id: self.content_id.clone(),
+            issuer: self.issuer.as_ref().map(|did| did.to_string()),
+            subject: self.subject.as_ref().map(|did| did.to_string()),
+            audience: self.audience.clone(),
+            nonce: self.nonce.clone(),
+            custom: self.custom.clone(),
+        };
+        let claims = Claims::new(claim)
             .set_duration(&options, self.time_to_live)
             .set_not_before(self.created_at);
-        let token = JwtMultiCipher.token(header, &claims, sk)?;
-        Ok(token)
+        algorithm.token(header, &claims, sk)
     }
 }
 
@@ -43,44 +133,345 @@ impl Default for JwtBuilder {
     }
 }
 
-pub struct JwtParser(Token<JwtClaim>);
+/// Checks on the claims of a token done by [`JwtParser::validate_with`] in addition to the
+/// signature and the time window
+#[derive(Clone, Debug, Default)]
+pub struct JwtValidation {
+    /// The token has to be issued for this audience, e.g. the identifier of an API
+    pub audience: Option<String>,
+    /// The token has to be issued by this DID. Note that the issuer is not checked against the
+    /// keys of the DID document here, as that needs the state of the ledger.
+    pub issuer: Option<Did>,
+    /// Verify with this key instead of the multicipher public key in the `kid` header
+    pub public_key: Option<MPublicKey>,
+}
+
+impl JwtValidation {
+    fn validate(&self, claims: &JwtClaim) -> Result<()> {
+        if let Some(audience) = &self.audience {
+            ensure!(
+                claims.audience.contains(audience),
+                "JWT is not issued for audience '{}'",
+                audience
+            );
+        }
+        if let Some(issuer) = &self.issuer {
+            let claimed = did_claim(&claims.issuer, "iss")?;
+            ensure!(claimed.as_ref() == Some(issuer), "JWT is not issued by {}", issuer);
+        }
+        Ok(())
+    }
+}
+
+fn did_claim(claim: &Option<String>, name: &str) -> Result<Option<Did>> {
+    claim
+        .as_deref()
+        .map(|did| did.parse().with_context(|| format!("JWT claim '{}' is not a DID", name)))
+        .transpose()
+}
+
+pub struct JwtParser {
+    token: Token<JwtClaim>,
+    public_key: MPublicKey,
+    algorithm: JwtAlgorithm,
+}
 
 impl JwtParser {
     pub fn new(token: impl AsRef<str>, current_time: Option<DateTime<Utc>>) -> Result<Self> {
+        Self::validate_with(token, &JwtValidation::default(), current_time)
+    }
+
+    /// Parses tokens that do not carry the multicipher public key in their `kid` header, like the
+    /// ones issued by standard JWT libraries
+    pub fn with_public_key(
+        token: impl AsRef<str>, public_key: &MPublicKey, current_time: Option<DateTime<Utc>>,
+    ) -> Result<Self> {
+        let validation =
+            JwtValidation { public_key: Some(public_key.to_owned()), ..Default::default() };
+        Self::validate_with(token, &validation, current_time)
+    }
+
+    /// Parses the token and checks its claims against the expectations of the verifier
+    pub fn validate_with(
+        token: impl AsRef<str>, validation: &JwtValidation, current_time: Option<DateTime<Utc>>,
+    ) -> Result<Self> {
         let untrusted = UntrustedToken::try_from(token.as_ref())?;
-        let pk_str = untrusted
-            .header()
-            .key_id
-            .as_ref()
-            .with_context(|| "Publickey is missing from JWT kid header")?;
-        let pk: MPublicKey = pk_str.parse()?;
-        let token = JwtMultiCipher.validate_integrity::<JwtClaim>(&untrusted, &pk)?;
+        let public_key = match &validation.public_key {
+            Some(pk) => pk.to_owned(),
+            None => {
+                let pk_str = untrusted
+                    .header()
+                    .key_id
+                    .as_ref()
+                    .with_context(|| "Publickey is missing from JWT kid header")?;
+                pk_str.parse()?
+            }
+        };
+        let algorithm = JwtAlgorithm::from_name(untrusted.algorithm())?;
+        let token = algorithm.validate_integrity::<JwtClaim>(&untrusted, &public_key)?;
         let options =
             TimeOptions::new(Duration::seconds(0), || current_time.unwrap_or_else(Utc::now));
         token.claims().validate_expiration(&options)?.validate_maturity(&options)?;
+        validation.validate(&token.claims().custom)?;
 
-        Ok(Self(token))
+        Ok(Self { token, public_key, algorithm })
     }
 
-    // new would fail if key_id would be missing or unparsable
     pub fn public_key(&self) -> MPublicKey {
-        let pk_str = self.0.header().key_id.as_ref().unwrap();
-        let pk: MPublicKey = pk_str.parse().unwrap();
-        pk
+        self.public_key.clone()
+    }
+
+    pub fn algorithm(&self) -> JwtAlgorithm {
+        self.algorithm
     }
 
     // new would fail on validate_maturity or validate_expiration if either not_before or expiration_date were missing
     pub fn time_to_live(&self) -> Duration {
-        let claims = self.0.claims();
+        let claims = self.token.claims();
         *claims.expiration.as_ref().unwrap() - *claims.not_before.as_ref().unwrap()
     }
 
     // new would fail on validate_maturity if not_before was missing
     pub fn created_at(&self) -> &DateTime<Utc> {
-        self.0.claims().not_before.as_ref().unwrap()
+        self.token.claims().not_before.as_ref().unwrap()
     }
 
     pub fn content_id(&self) -> Option<&ContentId> {
-        self.0.claims().custom.content_id.as_ref()
+        self.token.claims().custom.content_id.as_ref()
+    }
+
+    pub fn issuer(&self) -> Option<&str> {
+        self.token.claims().custom.issuer.as_deref()
+    }
+
+    /// Fails if the token has an issuer that is not a DID
+    pub fn issuer_did(&self) -> Result<Option<Did>> {
+        did_claim(&self.token.claims().custom.issuer, "iss")
+    }
+
+    pub fn subject(&self) -> Option<&str> {
+        self.token.claims().custom.subject.as_deref()
+    }
+
+    /// Fails if the token has a subject that is not a DID
+    pub fn subject_did(&self) -> Result<Option<Did>> {
+        did_claim(&self.token.claims().custom.subject, "sub")
+    }
+
+    pub fn audience(&self) -> &[String] {
+        &self.token.claims().custom.audience
+    }
+
+    pub fn nonce(&self) -> Option<&str> {
+        self.token.claims().custom.nonce.as_deref()
+    }
+
+    pub fn custom_claims(&self) -> &Map<String, Value> {
+        &self.token.claims().custom.custom
+    }
+
+    /// Deserializes an application specific claim, returning `None` if the token does not have it
+    pub fn custom_claim<T: DeserializeOwned>(&self, name: &str) -> Result<Option<T>> {
+        self.custom_claims()
+            .get(name)
+            .map(|value| {
+                serde_json::from_value(value.to_owned())
+                    .with_context(|| format!("Invalid JWT claim '{}'", name))
+            })
+            .transpose()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use iop_keyvault::multicipher::Jwk;
+
+    fn ed_sk() -> MPrivateKey {
+        // Example from RFC 8037 A.1
+        let jwk: Jwk = serde_json::from_str(
+            r#"{"kty":"OKP","crv":"Ed25519",
+                "d":"nWGxne_9WmC6hEr0kuwsxERJxWl7MmkZcDusAxyuf2A",
+                "x":"11qYAYKxCrfVS_7TyWQHOg7hcvPapiMlrwIaaPcHURo"}"#,
+        )
+        .unwrap();
+        MPrivateKey::from_jwk(&jwk).unwrap()
+    }
+
+    fn secp_sk() -> MPrivateKey {
+        MPrivateKey::from(SecpPrivateKey::from_ark_passphrase("jwt test").unwrap())
+    }
+
+    #[test]
+    fn rfc8037_signature() {
+        let sk = match ed_sk() {
+            MPrivateKey::Ed25519(edsk) => edsk,
+            _ => unreachable!(),
+        };
+        let message = b"eyJhbGciOiJFZERTQSJ9.RXhhbXBsZSBvZiBFZDI1NTE5IHNpZ25pbmc";
+        let expected = [
+            0x86, 0x0c, 0x98, 0xd2, 0x29, 0x7f, 0x30, 0x60, 0xa3, 0x3f, 0x42, 0x73, 0x96, 0x72,
+            0xd6, 0x1b, 0x53, 0xcf, 0x3a, 0xde, 0xfe, 0xd3, 0xd3, 0xc6, 0x72, 0xf3, 0x20, 0xdc,
+            0x02, 0x1b, 0x41, 0x1e, 0x9d, 0x59, 0xb8, 0x62, 0x8d, 0xc3, 0x51, 0xe2, 0x48, 0xb8,
+            0x8b, 0x29, 0x46, 0x8e, 0x0e, 0x41, 0x85, 0x5b, 0x0f, 0xb7, 0xd8, 0x3b, 0xb1, 0x5b,
+            0xe9, 0x02, 0xbf, 0xcc, 0xb8, 0xcd, 0x0a, 0x02,
+        ];
+
+        let sig = JwtEdDsa.sign(&sk, message);
+        assert_eq!(sig.as_bytes().as_ref(), &expected[..]);
+
+        let parsed = JwtEdSignature::try_from_slice(&expected).unwrap();
+        assert!(JwtEdDsa.verify_signature(&parsed, &sk.public_key(), message));
+    }
+
+    #[test]
+    fn standard_algorithms() -> Result<()> {
+        for (sk, algorithm) in [(ed_sk(), JwtAlgorithm::EdDsa), (secp_sk(), JwtAlgorithm::Es256k)] {
+            let builder = JwtBuilder::with_content_id(
+                "cjuzC-XxgzNMwYXtw8aMIAeS2Xjlw1hlSNKTvVtUwPuyYo".to_owned(),
+            );
+            let token = builder.sign(&sk)?;
+            let untrusted = UntrustedToken::try_from(token.as_str())?;
+            assert_eq!(untrusted.algorithm(), algorithm.name());
+            assert_eq!(untrusted.signature_bytes().len(), 64);
+
+            let parser = JwtParser::new(&token, Some(builder.created_at))?;
+            assert_eq!(parser.algorithm(), algorithm);
+            assert_eq!(parser.public_key(), sk.public_key());
+            assert_eq!(parser.content_id(), builder.content_id.as_ref());
+            assert_eq!(parser.time_to_live(), builder.time_to_live);
+
+            let parser = JwtParser::with_public_key(&token, &sk.public_key(), None)?;
+            assert_eq!(parser.algorithm(), algorithm);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn multicipher_compatibility() -> Result<()> {
+        for sk in [ed_sk(), secp_sk()] {
+            let builder =
+                JwtBuilder { algorithm: Some(JwtAlgorithm::MultiCipher), ..JwtBuilder::new() };
+            let token = builder.sign(&sk)?;
+            let parser = JwtParser::new(&token, None)?;
+            assert_eq!(parser.algorithm(), JwtAlgorithm::MultiCipher);
+            assert_eq!(parser.public_key(), sk.public_key());
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn key_mismatch() -> Result<()> {
+        let builder = JwtBuilder { algorithm: Some(JwtAlgorithm::EdDsa), ..JwtBuilder::new() };
+        assert!(builder.sign(&secp_sk()).is_err());
+
+        let token = JwtBuilder::new().sign(&ed_sk())?;
+        assert!(JwtParser::with_public_key(&token, &secp_sk().public_key(), None).is_err());
+
+        let expired = Utc::now() + Duration::minutes(10);
+        assert!(JwtParser::new(&token, Some(expired)).is_err());
+        Ok(())
+    }
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Scope {
+        read: bool,
+        paths: Vec<String>,
+    }
+
+    #[test]
+    fn claims() -> Result<()> {
+        let sk = ed_sk();
+        let issuer = Did::from(sk.public_key().key_id());
+        let subject: Did = "did:morpheus:ezbeWGSY2dqcUBqT8K7R14xr".parse()?;
+        let scope = Scope { read: true, paths: vec!["/api/v1".to_owned()] };
+
+        let mut builder = JwtBuilder::new();
+        builder.issuer = Some(issuer.clone());
+        builder.subject = Some(subject.clone());
+        builder.audience = vec!["https://api.example.com".to_owned()];
+        builder.nonce = Some("uQNj7ELF1jYxlFnaDHfxxsp".to_owned());
+        builder.set_custom_claim("scope", &scope)?;
+        assert!(builder.set_custom_claim("aud", &"https://evil.example.com").is_err());
+        let token = builder.sign(&sk)?;
+
+        let untrusted = UntrustedToken::try_from(token.as_str())?;
+        let claims = untrusted.deserialize_claims_unchecked::<Value>()?;
+        assert_eq!(claims.custom["aud"], "https://api.example.com");
+        assert_eq!(claims.custom["iss"], issuer.to_string());
+
+        let parser = JwtParser::new(&token, None)?;
+        assert_eq!(parser.issuer_did()?, Some(issuer));
+        assert_eq!(parser.subject_did()?, Some(subject));
+        assert_eq!(parser.audience(), ["https://api.example.com"]);
+        assert_eq!(parser.nonce(), Some("uQNj7ELF1jYxlFnaDHfxxsp"));
+        assert_eq!(parser.custom_claim::<Scope>("scope")?, Some(scope));
+        assert_eq!(parser.custom_claim::<Scope>("missing")?, None);
+        assert!(parser.custom_claim::<u32>("scope").is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn validation() -> Result<()> {
+        let sk = ed_sk();
+        let issuer = Did::from(sk.public_key().key_id());
+        let mut builder = JwtBuilder::new();
+        builder.issuer = Some(issuer.clone());
+        builder.audience = vec!["api-1".to_owned(), "api-2".to_owned()];
+        let token = builder.sign(&sk)?;
+
+        let validation = JwtValidation {
+            audience: Some("api-2".to_owned()),
+            issuer: Some(issuer),
+            ..Default::default()
+        };
+        let parser = JwtParser::validate_with(&token, &validation, None)?;
+        assert_eq!(parser.audience(), ["api-1", "api-2"]);
+
+        let wrong_audience =
+            JwtValidation { audience: Some("api-3".to_owned()), ..validation.clone() };
+        let err = JwtParser::validate_with(&token, &wrong_audience, None).err().unwrap();
+        assert_eq!(err.to_string(), "JWT is not issued for audience 'api-3'");
+
+        let other_issuer = Did::from(secp_sk().public_key().key_id());
+        let wrong_issuer = JwtValidation { issuer: Some(other_issuer), ..validation };
+        assert!(JwtParser::validate_with(&token, &wrong_issuer, None).is_err());
+
+        let anonymous = JwtBuilder::new().sign(&sk)?;
+        let audience_only =
+            JwtValidation { audience: Some("api-1".to_owned()), ..Default::default() };
+        assert!(JwtParser::validate_with(&anonymous, &audience_only, None).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn non_did_issuer() -> Result<()> {
+        let sk = secp_sk();
+        let claim = JwtClaim {
+            issuer: Some("https://auth.example.com".to_owned()),
+            subject: Some("user-42".to_owned()),
+            ..Default::default()
+        };
+        let now = Utc::now();
+        let options = TimeOptions::new(Duration::seconds(0), || now);
+        let claims =
+            Claims::new(claim).set_duration(&options, Duration::minutes(5)).set_not_before(now);
+        let token = JwtAlgorithm::Es256k.token(Header::default(), &claims, &sk)?;
+
+        let parser = JwtParser::with_public_key(&token, &sk.public_key(), None)?;
+        assert_eq!(parser.issuer(), Some("https://auth.example.com"));
+        assert_eq!(parser.subject(), Some("user-42"));
+        assert!(parser.issuer_did().is_err());
+
+        let validation = JwtValidation {
+            issuer: Some(Did::from(sk.public_key().key_id())),
+            public_key: Some(sk.public_key()),
+            ..Default::default()
+        };
+        assert!(JwtParser::validate_with(&token, &validation, None).is_err());
+        Ok(())
     }
 }
@@ -1,8 +1,11 @@
 use super::*;
 
+use std::collections::HashMap;
+
 use crate::{
     crypto::hash::{Content, ContentId},
     data::*,
+    login::LoginResponse,
 };
 use iop_keyvault::{
     multicipher::{MKeyId, MPrivateKey, MPublicKey, MSignature},
@@ -89,7 +92,6 @@ where
         valid
     }
 
-    // TODO add Before/AfterProofs as optional arguments here
     pub fn validate_with_did_doc(
         &self, on_behalf_of: &DidDocument, from_inc: Option<BlockHeight>,
         until_exc: Option<BlockHeight>,
@@ -105,8 +107,39 @@ where
         }
         Ok(issues)
     }
+
+    /// Like [`validate_with_did_doc`], but for a period given with timestamps. The period is
+    /// unbounded from the past and lasts until the queried block by default.
+    ///
+    /// [`validate_with_did_doc`]: Signed::validate_with_did_doc
+    pub fn validate_with_did_doc_at_time(
+        &self, on_behalf_of: &DidDocument, from_inc: Option<DateTime<Utc>>,
+        until_exc: Option<DateTime<Utc>>,
+    ) -> Result<ValidationResult> {
+        let from = from_inc.unwrap_or(DateTime::<Utc>::MIN_UTC);
+        let until = match until_exc.or(on_behalf_of.queried_at_time) {
+            Some(until) => until,
+            None => bail!(
+                "Timestamp of the queried block {} is unknown",
+                on_behalf_of.queried_at_height
+            ),
+        };
+
+        let auth = Authentication::PublicKey(self.public_key.to_owned());
+        let mut issues =
+            on_behalf_of.validate_right_at_time(&auth, Right::Impersonation, from, until)?;
+
+        if !self.validate() {
+            issues.add_issue(ValidationIssueSeverity::Error, "Signature is invalid");
+        }
+        Ok(issues)
+    }
 }
 
+/// The content id of signed data covers the signature, so registering it as a before proof proves
+/// that the signature already existed at that height.
+impl<T: Signable> Content for Signed<T> {}
+
 #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
 struct SignatureTuple {
     #[serde(with = "serde_str", rename = "publicKey")]
@@ -147,12 +180,16 @@ impl<T: Signable> From<SignatureSerializationFormat<T>> for Signed<T> {
 pub trait SyncMorpheusSigner {
     fn sign(&self, data: &[u8]) -> Result<(MPublicKey, MSignature)>;
 
+    /// Signs the request as it is. Callers have to check it against the schemas of the process with
+    /// `Process::validate_request` first.
     fn sign_witness_request(&self, request: WitnessRequest) -> Result<Signed<WitnessRequest>> {
         let content_to_sign = request.content_to_sign()?;
         let (public_key, signature) = self.sign(&content_to_sign)?;
         Ok(Signed::new(public_key, request, signature))
     }
 
+    /// Signs the statement as it is. Callers have to check it against the schemas of the process with
+    /// `Process::validate_statement` first.
     fn sign_witness_statement(
         &self, statement: WitnessStatement,
     ) -> Result<Signed<WitnessStatement>> {
@@ -168,6 +205,26 @@ pub trait SyncMorpheusSigner {
         let (public_key, signature) = self.sign(&content_to_sign)?;
         Ok(Signed::new(public_key, presentation, signature))
     }
+
+    fn sign_license(&self, license: License) -> Result<Signed<License>> {
+        let content_to_sign = license.content_to_sign()?;
+        let (public_key, signature) = self.sign(&content_to_sign)?;
+        Ok(Signed::new(public_key, license, signature))
+    }
+
+    fn sign_after_envelope(
+        &self, envelope: AfterEnvelope<serde_json::Value>,
+    ) -> Result<Signed<AfterEnvelope<serde_json::Value>>> {
+        let content_to_sign = envelope.content_to_sign()?;
+        let (public_key, signature) = self.sign(&content_to_sign)?;
+        Ok(Signed::new(public_key, envelope, signature))
+    }
+
+    fn sign_login_response(&self, response: LoginResponse) -> Result<Signed<LoginResponse>> {
+        let content_to_sign = response.content_to_sign()?;
+        let (public_key, signature) = self.sign(&content_to_sign)?;
+        Ok(Signed::new(public_key, response, signature))
+    }
 }
 
 impl<T: SyncMorpheusSigner + Sized> SyncMorpheusSigner for Box<T> {
@@ -195,6 +252,8 @@ impl SyncMorpheusSigner for PrivateKeySigner {
 
 pub type BlockHash = ContentId;
 
+/// Proves that some content was created after a given block, because it includes the hash of
+/// that block, which could not have been known earlier.
 #[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
 pub struct AfterProof {
     #[serde(rename = "blockHash")]
@@ -203,17 +262,167 @@ pub struct AfterProof {
     block_height: BlockHeight,
 }
 
-//impl Content for AfterProof {}
-//impl Signable for AfterProof {}
+impl AfterProof {
+    pub fn new(block_hash: BlockHash, block_height: BlockHeight) -> Self {
+        Self { block_hash, block_height }
+    }
+
+    pub fn block_hash(&self) -> &BlockHash {
+        &self.block_hash
+    }
+
+    pub fn block_height(&self) -> BlockHeight {
+        self.block_height
+    }
+
+    /// Checks that the oracle knows a block with the embedded hash at the embedded height.
+    pub fn verify(&self, oracle: &dyn BlockHashOracle) -> Result<bool> {
+        let known_hash = oracle.block_hash_at(self.block_height)?;
+        Ok(known_hash.as_ref() == Some(&self.block_hash))
+    }
+}
+
+impl Content for AfterProof {}
+impl Signable for AfterProof {}
+
+/// Source of trusted block hashes, typically backed by a blockchain node.
+pub trait BlockHashOracle {
+    /// Returns `None` if no block is known at the given height.
+    fn block_hash_at(&self, height: BlockHeight) -> Result<Option<BlockHash>>;
+}
+
+impl BlockHashOracle for HashMap<BlockHeight, BlockHash> {
+    fn block_hash_at(&self, height: BlockHeight) -> Result<Option<BlockHash>> {
+        Ok(self.get(&height).cloned())
+    }
+}
+
+/// Source of before proofs registered on the ledger, typically backed by a Morpheus node.
+pub trait BeforeProofOracle {
+    /// Returns the height of the block the content id was registered in, or `None` if it was not.
+    fn before_proof_height(&self, content_id: &str) -> Result<Option<BlockHeight>>;
+}
+
+impl BeforeProofOracle for HashMap<ContentId, BlockHeight> {
+    fn before_proof_height(&self, content_id: &str) -> Result<Option<BlockHeight>> {
+        Ok(self.get(content_id).cloned())
+    }
+}
 
-// TODO Eq, PartialEq and maybe PartialOrd for AfterEnvelope
-#[derive(Clone, Debug, Deserialize, Serialize)]
+/// Content anchored after a block with an [`AfterProof`]. Signing the envelope proves that the
+/// signature was created after that block.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
 pub struct AfterEnvelope<T: Signable> {
-    // TODO will contentId be fetched from the content or needs a separate field?
-    //      should we just use the contentId here and provide another way to resolve the content from it?
     content: T,
-    proof: AfterProof, // TODO is a transactionId also needed here?
+    proof: AfterProof,
 }
 
-// impl<T: Signable> MaskableContent for AfterEnvelope<T> {}
-// impl<T: Signable> Signable for AfterEnvelope<T> {}
+impl<T: Signable> AfterEnvelope<T> {
+    pub fn new(content: T, proof: AfterProof) -> Self {
+        Self { content, proof }
+    }
+
+    pub fn content(&self) -> &T {
+        &self.content
+    }
+
+    pub fn proof(&self) -> &AfterProof {
+        &self.proof
+    }
+
+    pub fn into_parts(self) -> (T, AfterProof) {
+        (self.content, self.proof)
+    }
+}
+
+impl<T: Signable> Content for AfterEnvelope<T> {}
+impl<T: Signable> Signable for AfterEnvelope<T> {}
+
+impl<T: Signable> Signed<AfterEnvelope<T>> {
+    /// Like [`validate_with_did_doc`], but the period starts at the block the content is anchored
+    /// to, which is checked against the block hash oracle. If the content id of this signed
+    /// envelope was registered as a before proof, the period ends at the height of that proof,
+    /// otherwise it lasts until the queried block.
+    ///
+    /// [`validate_with_did_doc`]: Signed::validate_with_did_doc
+    pub fn validate_with_did_doc_after_proof(
+        &self, on_behalf_of: &DidDocument, block_hashes: &dyn BlockHashOracle,
+        before_proofs: &dyn BeforeProofOracle,
+    ) -> Result<ValidationResult> {
+        let proof = self.content.proof();
+        let from = proof.block_height();
+        let before_proof_height = before_proofs.before_proof_height(&self.content_id()?)?;
+        let until = before_proof_height.unwrap_or(on_behalf_of.queried_at_height);
+
+        let mut issues = if from < until {
+            self.validate_with_did_doc(on_behalf_of, Some(from), Some(until))?
+        } else {
+            let mut issues = ValidationResult::default();
+            issues.add_issue(
+                ValidationIssueSeverity::Error,
+                "Content is anchored to a block not preceding the end of the given period",
+            );
+            if !self.validate() {
+                issues.add_issue(ValidationIssueSeverity::Error, "Signature is invalid");
+            }
+            issues
+        };
+
+        if !proof.verify(block_hashes)? {
+            issues.add_issue(ValidationIssueSeverity::Error, "Anchored block hash is unknown");
+        }
+        Ok(issues)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use crate::test_helpers::{impersonating_doc, signer};
+
+    #[test]
+    fn after_proof() -> Result<()> {
+        let (did, signer) = signer("anchored");
+        let mut doc = impersonating_doc(&did, 100);
+        let oracle: HashMap<BlockHeight, BlockHash> =
+            [(50, "cjuBlock50".to_owned()), (60, "cjuBlock60".to_owned())].into_iter().collect();
+        let content = serde_json::json!({ "message": "Hello after block 50" });
+        let proof = AfterProof::new("cjuBlock50".to_owned(), 50);
+        let signed = signer.sign_after_envelope(AfterEnvelope::new(content.clone(), proof))?;
+        let signed_id = signed.content_id()?;
+
+        let no_proofs = HashMap::<ContentId, BlockHeight>::new();
+        let check = |signed: &Signed<AfterEnvelope<serde_json::Value>>,
+                     doc: &DidDocument,
+                     before_proofs: &HashMap<ContentId, BlockHeight>| {
+            signed.validate_with_did_doc_after_proof(doc, &oracle, before_proofs).unwrap().status()
+        };
+        assert_eq!(check(&signed, &doc, &no_proofs), ValidationStatus::Valid);
+        let early_proof = [(signed_id.clone(), 50)].into_iter().collect();
+        assert_eq!(check(&signed, &doc, &early_proof), ValidationStatus::Invalid);
+
+        for proof in [
+            AfterProof::new("cjuBlock60".to_owned(), 50),
+            AfterProof::new("cjuBlock70".to_owned(), 70),
+        ] {
+            let forged = signer.sign_after_envelope(AfterEnvelope::new(content.clone(), proof))?;
+            assert_eq!(check(&forged, &doc, &no_proofs), ValidationStatus::Invalid);
+        }
+
+        let (public_key, _, signature, nonce) = signed.clone().into_parts();
+        let moved = AfterEnvelope::new(content, AfterProof::new("cjuBlock60".to_owned(), 60));
+        let tampered = Signed::from_parts(public_key, moved, signature, nonce);
+        assert!(tampered.content().proof().verify(&oracle)?);
+        assert_eq!(check(&tampered, &doc, &no_proofs), ValidationStatus::Invalid);
+
+        // The key got its right only at height 70, so it might have signed after that, unless a
+        // before proof shows that the signature already existed at height 60
+        let granted = KeyRightHistoryItem { height: Some(70), valid: true, timestamp: None };
+        doc.rights.get_mut(&Right::Impersonation).unwrap()[0].state.history = vec![granted];
+        assert_eq!(check(&signed, &doc, &no_proofs), ValidationStatus::MaybeValid);
+        let before_proof = [(signed_id, 60)].into_iter().collect();
+        assert_eq!(check(&signed, &doc, &before_proof), ValidationStatus::Invalid);
+        Ok(())
+    }
+}
@@ -3,24 +3,56 @@ use super::*;
 use crate::data::auth::Authentication;
 use crate::data::{
     did::Did,
-    validation::{ValidationIssueSeverity as Severity, ValidationResult},
+    validation::{ValidationIssueSeverity as Severity, ValidationResult, ValidationStatus},
 };
 
-#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, PartialOrd, Serialize)]
+/// Rights of keys on a DID. The system rights have special meaning for the Morpheus node and for
+/// validating signatures, while custom rights are tracked the same way for applications.
+#[derive(Clone, Debug, Eq, Hash, PartialEq, PartialOrd)]
 pub enum Right {
-    #[serde(rename = "update")]
+    /// Allows changing the DID document
     Update,
-    #[serde(rename = "impersonate")]
+    /// Allows signing on behalf of the DID
     Impersonation,
+    /// An application-defined right in the form of `namespace:name`, e.g. `myapp:sign-contracts`
+    Custom(String),
+}
+
+impl Right {
+    const UPDATE: &'static str = "update";
+    const IMPERSONATION: &'static str = "impersonate";
+
+    pub fn custom(namespace: &str, name: &str) -> Result<Self> {
+        format!("{}:{}", namespace, name).parse()
+    }
+
+    pub fn system() -> [Right; 2] {
+        [Self::Update, Self::Impersonation]
+    }
+
+    pub fn is_system(&self) -> bool {
+        !matches!(self, Self::Custom(_))
+    }
+
+    /// Maps all system rights. Custom rights are only present where they were granted.
+    pub fn map_all<T>(f: impl Fn(&Right) -> T) -> HashMap<Right, T> {
+        Self::system().into_iter().map(|r| (r.clone(), f(&r))).collect()
+    }
+
+    fn is_valid_segment(segment: &str) -> bool {
+        !segment.is_empty()
+            && segment.chars().all(|c| {
+                c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-' || c == '.' || c == '_'
+            })
+    }
 }
 
 impl Display for Right {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        let value =
-            serde_json::to_value(self).expect("Implementation error: Right is not serializable");
-        match value {
-            serde_json::Value::String(s) => write!(f, "{}", s),
-            _ => panic!("Implementation error: unexpected Right serialization"),
+        match self {
+            Self::Update => write!(f, "{}", Self::UPDATE),
+            Self::Impersonation => write!(f, "{}", Self::IMPERSONATION),
+            Self::Custom(right) => write!(f, "{}", right),
         }
     }
 }
@@ -28,13 +60,37 @@ impl Display for Right {
 impl FromStr for Right {
     type Err = anyhow::Error;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        Ok(serde_json::from_value(serde_json::Value::String(s.to_owned()))?)
+        match s {
+            Self::UPDATE => return Ok(Self::Update),
+            Self::IMPERSONATION => return Ok(Self::Impersonation),
+            _ => {}
+        }
+        let valid = match s.split_once(':') {
+            Some((namespace, name)) => {
+                Self::is_valid_segment(namespace) && Self::is_valid_segment(name)
+            }
+            None => false,
+        };
+        ensure!(
+            valid,
+            "Right '{}' is neither a system right nor a custom right like 'myapp:sign-contracts'",
+            s
+        );
+        Ok(Self::Custom(s.to_owned()))
     }
 }
 
-impl Right {
-    pub fn map_all<T>(f: impl Fn(&Right) -> T) -> HashMap<Right, T> {
-        vec![Self::Update, Self::Impersonation].drain(..).map(|r| (r, f(&r))).collect()
+impl Serialize for Right {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for Right {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        use serde::de::Error as _;
+        let right = String::deserialize(deserializer)?;
+        right.parse().map_err(D::Error::custom)
     }
 }
 
@@ -58,14 +114,34 @@ pub fn is_between(height: BlockHeight, after: BlockHeight, until_exc: BlockHeigh
     is_in_opt_range(height, Some(after + 1), Some(until_exc))
 }
 
+fn is_time_between(time: DateTime<Utc>, after: DateTime<Utc>, until_exc: DateTime<Utc>) -> bool {
+    after < time && time < until_exc
+}
+
+/// The timestamp of an optional block height, failing if the height is known, but its timestamp is not
+fn timestamp_of(
+    height: Option<BlockHeight>, timestamp: Option<DateTime<Utc>>,
+) -> Result<Option<DateTime<Utc>>> {
+    match (height, timestamp) {
+        (Some(height), None) => bail!("Timestamp of block {} is unknown", height),
+        _ => Ok(timestamp),
+    }
+}
+
 #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
 pub struct KeyState {
     #[serde(rename = "auth")]
     pub authentication: Authentication,
     #[serde(rename = "validFromHeight")]
-    pub valid_from_block: Option<BlockHeight>, // TODO should be timestamp on the long term
+    pub valid_from_block: Option<BlockHeight>,
     #[serde(rename = "validUntilHeight")]
-    pub valid_until_block: Option<BlockHeight>, // TODO should be timestamp on the long term
+    pub valid_until_block: Option<BlockHeight>,
+    /// Timestamp of the block the key was added in
+    #[serde(rename = "validFromTime", skip_serializing_if = "Option::is_none", default)]
+    pub valid_from_time: Option<DateTime<Utc>>,
+    /// Expiration time of the key or timestamp of the block it was invalidated in
+    #[serde(rename = "validUntilTime", skip_serializing_if = "Option::is_none", default)]
+    pub valid_until_time: Option<DateTime<Utc>>,
 }
 
 #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
@@ -84,7 +160,13 @@ pub struct KeyData {
 
 impl KeyData {
     fn from_auth(authentication: Authentication) -> Self {
-        let state = KeyState { authentication, valid_from_block: None, valid_until_block: None };
+        let state = KeyState {
+            authentication,
+            valid_from_block: None,
+            valid_until_block: None,
+            valid_from_time: None,
+            valid_until_time: None,
+        };
         let derived = KeyDataDerived { index: 0, valid: true };
         Self { state, derived }
     }
@@ -92,12 +174,30 @@ impl KeyData {
     fn is_valid_at(&self, height: BlockHeight) -> bool {
         is_in_opt_range(height, self.state.valid_from_block, self.state.valid_until_block)
     }
+
+    fn valid_from_time(&self) -> Result<Option<DateTime<Utc>>> {
+        timestamp_of(self.state.valid_from_block, self.state.valid_from_time)
+    }
+
+    // A key may expire at a time not reached by any block yet, so it has no height for that
+    fn valid_until_time(&self) -> Result<Option<DateTime<Utc>>> {
+        timestamp_of(self.state.valid_until_block, self.state.valid_until_time)
+    }
+
+    fn is_valid_at_time(&self, time: DateTime<Utc>) -> Result<bool> {
+        let from_ok = self.valid_from_time()?.map(|from| from <= time).unwrap_or(true);
+        let until_ok = self.valid_until_time()?.map(|until| time < until).unwrap_or(true);
+        Ok(from_ok && until_ok)
+    }
 }
 
 #[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, PartialOrd, Serialize)]
 pub struct KeyRightHistoryItem {
     pub height: Option<BlockHeight>,
     pub valid: bool,
+    /// Timestamp of the block at the given height
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub timestamp: Option<DateTime<Utc>>,
 }
 
 #[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, PartialOrd, Serialize)]
@@ -142,21 +242,100 @@ impl KeyRightHistory {
         };
         Ok(valid)
     }
+
+    fn is_true_at_time(&self, time: DateTime<Utc>) -> Result<bool> {
+        self.ensure_valid_history()?;
+
+        let mut valid = false;
+        for item in &self.state.history {
+            match timestamp_of(item.height, item.timestamp)? {
+                Some(timestamp) if time < timestamp => break,
+                _ => valid = item.valid,
+            }
+        }
+        Ok(valid)
+    }
 }
 
-#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, PartialOrd, Serialize)]
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, PartialOrd)]
 pub enum ServiceType {
-    // TODO
+    /// DIDComm v2 messaging endpoint of the DID subject
+    DidCommMessaging,
+    /// Storage holding data of the DID subject, e.g. a personal data store or IPFS gateway
+    Storage,
+    /// Web origin controlled by the DID subject
+    LinkedDomains,
+}
+
+impl ServiceType {
+    const DIDCOMM_MESSAGING: &'static str = "DIDCommMessaging";
+    const STORAGE: &'static str = "Storage";
+    const LINKED_DOMAINS: &'static str = "LinkedDomains";
+}
+
+impl Display for ServiceType {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::DidCommMessaging => write!(f, "{}", Self::DIDCOMM_MESSAGING),
+            Self::Storage => write!(f, "{}", Self::STORAGE),
+            Self::LinkedDomains => write!(f, "{}", Self::LINKED_DOMAINS),
+        }
+    }
+}
+
+impl FromStr for ServiceType {
+    type Err = anyhow::Error;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            Self::DIDCOMM_MESSAGING => Ok(Self::DidCommMessaging),
+            Self::STORAGE => Ok(Self::Storage),
+            Self::LINKED_DOMAINS => Ok(Self::LinkedDomains),
+            _ => bail!("Unknown service type {}", s),
+        }
+    }
+}
+
+impl Serialize for ServiceType {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for ServiceType {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        use serde::de::Error as _;
+        let type_ = String::deserialize(deserializer)?;
+        type_.parse().map_err(D::Error::custom)
+    }
 }
 
 #[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, PartialOrd, Serialize)]
 pub struct Service {
     #[serde(rename = "type")]
     pub type_: ServiceType,
+    /// Unique among the services of a DID at any height, used as the fragment of the service id
     pub name: String,
+    #[serde(rename = "serviceEndpoint")]
     pub service_endpoint: String, // TODO should we use multiaddr::Multiaddr here and thus add CID-dependency?
 }
 
+impl Service {
+    pub fn new(type_: ServiceType, name: impl ToString, service_endpoint: impl ToString) -> Self {
+        Self { type_, name: name.to_string(), service_endpoint: service_endpoint.to_string() }
+    }
+
+    pub fn validate(&self) -> Result<()> {
+        ensure!(!self.name.is_empty(), "Service name cannot be empty");
+        ensure!(
+            self.name.chars().all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_'),
+            "Service name {} can contain only ASCII letters, digits, '-' and '_'",
+            self.name
+        );
+        ensure!(!self.service_endpoint.is_empty(), "Endpoint of service {} is empty", self.name);
+        Ok(())
+    }
+}
+
 #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
 pub struct DidDocument {
     #[serde(with = "serde_str")]
@@ -171,6 +350,20 @@ pub struct DidDocument {
     pub tombstoned: bool,
     #[serde(rename = "queriedAtHeight")]
     pub queried_at_height: BlockHeight,
+    #[serde(rename = "tombstonedAtTime", skip_serializing_if = "Option::is_none", default)]
+    pub tombstoned_at_time: Option<DateTime<Utc>>,
+    /// Timestamp of the block at the queried height, needed by the time-based validation methods
+    #[serde(rename = "queriedAtTime", skip_serializing_if = "Option::is_none", default)]
+    pub queried_at_time: Option<DateTime<Utc>>,
+    /// Number of distinct keys with update right that have to sign operations on the DID
+    /// The node caps it at the number of keys still having update right, so expiring keys do not
+    /// lock the DID.
+    #[serde(
+        rename = "updateThreshold",
+        skip_serializing_if = "DidDocument::is_single_signer",
+        default = "DidDocument::single_signer"
+    )]
+    pub update_threshold: u32,
 }
 
 impl DidDocument {
@@ -184,9 +377,20 @@ impl DidDocument {
             tombstoned_at_height: Default::default(),
             tombstoned: Default::default(),
             queried_at_height: Default::default(),
+            tombstoned_at_time: Default::default(),
+            queried_at_time: Default::default(),
+            update_threshold: Self::single_signer(),
         }
     }
 
+    fn single_signer() -> u32 {
+        1
+    }
+
+    fn is_single_signer(threshold: &u32) -> bool {
+        *threshold == Self::single_signer()
+    }
+
     fn key(&self, key_link: &str) -> Result<KeyData> {
         ensure!(key_link.starts_with('#'), "Key links for remote DIDs are not supported yet");
         let idx_str: String = key_link.chars().skip(1).collect();
@@ -203,6 +407,9 @@ impl DidDocument {
         Ok(())
     }
 
+    /// Delegations to other DIDs are not followed, see [`has_delegated_right_at`] for that.
+    ///
+    /// [`has_delegated_right_at`]: DidDocument::has_delegated_right_at
     pub fn has_right_at(
         &self, auth: &Authentication, right: Right, height: BlockHeight,
     ) -> Result<bool> {
@@ -262,12 +469,55 @@ impl DidDocument {
             }
         }
 
+        let (key_data, key_right) = match self.key_right_of(auth, right, &mut result) {
+            Some(key_history) => key_history,
+            None => return Ok(result),
+        };
+
+        if let Some(key_valid_from) = key_data.state.valid_from_block {
+            if until < key_valid_from {
+                result.add_issue(Severity::Error, "Key was enabled only after given period");
+            }
+            if is_between(key_valid_from, from, until) {
+                result.add_issue(Severity::Warning, "Key was enabled during given period");
+            }
+        }
+
+        if let Some(key_valid_until) = key_data.state.valid_until_block {
+            if key_valid_until < from {
+                result.add_issue(Severity::Error, "Key expired before given period");
+            }
+            if is_between(key_valid_until, from, until) {
+                result.add_issue(Severity::Warning, "Key expired during given period");
+            }
+        }
+
+        let history = &key_right.state.history;
+        ensure!(! history.is_empty(), "Implementation error: key related to rights were already filtered, right must be present here");
+
+        let mut right_changes_in_range =
+            history.iter().filter(|item| is_between(item.height.unwrap_or_default(), from, until));
+
+        if !key_right.is_true_at(from)? {
+            if right_changes_in_range.next().is_none() {
+                result.add_issue(Severity::Error, "Required right was never granted for key");
+            } else {
+                result.add_issue(Severity::Warning, "Required right changed during given period");
+            }
+        }
+
+        Ok(result)
+    }
+
+    fn key_right_of(
+        &self, auth: &Authentication, right: Right, result: &mut ValidationResult,
+    ) -> Option<(KeyData, &KeyRightHistory)> {
         let keys_with_right = match self.rights.get(&right) {
             Some(entries) => entries,
             None => {
                 result
                     .add_issue(Severity::Error, "Right was never granted to given authentication");
-                return Ok(result);
+                return None;
             }
         };
 
@@ -286,43 +536,118 @@ impl DidDocument {
             Some((key_data, right_entry))
         });
 
-        let (key_data, key_right) = match key_history_opt {
-            Some(key_history) => key_history,
-            None => {
-                result.add_issue(Severity::Error, "No matching authentication found in DID");
-                return Ok(result);
+        if key_history_opt.is_none() {
+            result.add_issue(Severity::Error, "No matching authentication found in DID");
+        }
+        key_history_opt
+    }
+}
+
+/// Time-based variants of the validation methods for verifiers not following block heights. They
+/// need the block timestamps filled in by the node the document was queried from.
+impl DidDocument {
+    fn ensure_known_time(&self, time: DateTime<Utc>) -> Result<()> {
+        let queried_at = self.queried_at_time.ok_or_else(|| {
+            anyhow!("Timestamp of the queried block {} is unknown", self.queried_at_height)
+        })?;
+        if queried_at < time {
+            bail!("Queried future time {}, present is {}", time, queried_at);
+        }
+        Ok(())
+    }
+
+    fn tombstoned_time(&self) -> Result<Option<DateTime<Utc>>> {
+        timestamp_of(self.tombstoned_at_height, self.tombstoned_at_time)
+    }
+
+    pub fn is_tombstoned_at_time(&self, time: DateTime<Utc>) -> Result<bool> {
+        self.ensure_known_time(time)?;
+        Ok(self.tombstoned_time()?.map(|tombstoned| tombstoned <= time).unwrap_or(false))
+    }
+
+    /// Like [`has_right_at`], but at a given time instead of a block height.
+    ///
+    /// [`has_right_at`]: DidDocument::has_right_at
+    pub fn has_right_at_time(
+        &self, auth: &Authentication, right: Right, time: DateTime<Utc>,
+    ) -> Result<bool> {
+        if self.is_tombstoned_at_time(time)? {
+            return Ok(false);
+        }
+        let keys_with_right = match self.rights.get(&right) {
+            Some(key) => key,
+            None => return Ok(false),
+        };
+
+        for key_right in keys_with_right.iter() {
+            let key = self.key(&key_right.derived.key_link)?;
+            if !key.is_valid_at_time(time)? {
+                continue;
+            }
+            if key.state.authentication != *auth {
+                continue;
+            }
+
+            return key_right.is_true_at_time(time);
+        }
+
+        Ok(false)
+    }
+
+    /// Like [`validate_right`], but for a period between two instants instead of block heights.
+    ///
+    /// [`validate_right`]: DidDocument::validate_right
+    pub fn validate_right_at_time(
+        &self, auth: &Authentication, right: Right, from: DateTime<Utc>, until: DateTime<Utc>,
+    ) -> Result<ValidationResult> {
+        ensure!(from < until, "Invalid time range {} - {}", from, until);
+        self.ensure_known_time(until)?;
+
+        let mut result: ValidationResult = Default::default();
+
+        if self.is_tombstoned_at_time(from)? {
+            result.add_issue(Severity::Error, "DID was tombstoned before given period");
+        }
+        if let Some(tombstoned) = self.tombstoned_time()? {
+            if is_time_between(tombstoned, from, until) {
+                result.add_issue(Severity::Warning, "DID was tombstoned during given period");
             }
+        }
+
+        let (key_data, key_right) = match self.key_right_of(auth, right, &mut result) {
+            Some(key_history) => key_history,
+            None => return Ok(result),
         };
 
-        if let Some(key_valid_from) = key_data.state.valid_from_block {
+        if let Some(key_valid_from) = key_data.valid_from_time()? {
             if until < key_valid_from {
                 result.add_issue(Severity::Error, "Key was enabled only after given period");
             }
-            if is_between(key_valid_from, from, until) {
+            if is_time_between(key_valid_from, from, until) {
                 result.add_issue(Severity::Warning, "Key was enabled during given period");
             }
         }
 
-        if let Some(key_valid_until) = key_data.state.valid_until_block {
-            if key_valid_until < from {
+        if let Some(key_valid_until) = key_data.valid_until_time()? {
+            if key_valid_until <= from {
                 result.add_issue(Severity::Error, "Key expired before given period");
             }
-            if is_between(key_valid_until, from, until) {
+            if is_time_between(key_valid_until, from, until) {
                 result.add_issue(Severity::Warning, "Key expired during given period");
             }
         }
 
-        let history = &key_right.state.history;
-        ensure!(! history.is_empty(), "Implementation error: key related to rights were already filtered, right must be present here");
-
-        let mut right_changes_in_range =
-            history.iter().filter(|item| is_between(item.height.unwrap_or_default(), from, until));
-
-        if !key_right.is_true_at(from)? {
-            if right_changes_in_range.next().is_none() {
-                result.add_issue(Severity::Error, "Required right was never granted for key");
-            } else {
+        if !key_right.is_true_at_time(from)? {
+            let mut changed = false;
+            for item in &key_right.state.history {
+                if let Some(timestamp) = timestamp_of(item.height, item.timestamp)? {
+                    changed |= is_time_between(timestamp, from, until);
+                }
+            }
+            if changed {
                 result.add_issue(Severity::Warning, "Required right changed during given period");
+            } else {
+                result.add_issue(Severity::Error, "Required right was never granted for key");
             }
         }
 
@@ -330,10 +655,139 @@ impl DidDocument {
     }
 }
 
+/// Maximum number of DIDs a right can be delegated through, counting from the DID it was granted on
+pub const MAX_DELEGATION_DEPTH: usize = 4;
+
+/// Provides DID documents of delegate DIDs while rights are evaluated transitively.
+pub trait DidDocumentResolver {
+    fn resolve_at(&self, did: &Did, height: BlockHeight) -> Result<DidDocument>;
+}
+
+/// Documents queried at a single height, e.g. fetched from a node before validation
+impl DidDocumentResolver for HashMap<Did, DidDocument> {
+    fn resolve_at(&self, did: &Did, height: BlockHeight) -> Result<DidDocument> {
+        let doc = self.get(did).ok_or_else(|| anyhow!("DID document of {} is not known", did))?;
+        doc.ensure_known_height(height)?;
+        Ok(doc.to_owned())
+    }
+}
+
+impl DidDocument {
+    /// DIDs that have the given right on this DID at the given height
+    pub fn delegates_at(&self, right: Right, height: BlockHeight) -> Result<Vec<Did>> {
+        if self.is_tombstoned_at(height)? {
+            return Ok(vec![]);
+        }
+        let mut delegates = vec![];
+        for key_right in self.rights.get(&right).map(|r| r.as_slice()).unwrap_or_default() {
+            let key = self.key(&key_right.derived.key_link)?;
+            if let Authentication::Did(delegate) = &key.state.authentication {
+                if key.is_valid_at(height) && key_right.is_true_at(height)? {
+                    delegates.push(delegate.to_owned());
+                }
+            }
+        }
+        Ok(delegates)
+    }
+
+    /// Like [`has_right_at`], but also accepts the authentication if it has the same right on a
+    /// DID the right was delegated to, up to [`MAX_DELEGATION_DEPTH`] hops. Delegation cycles are
+    /// ignored.
+    ///
+    /// [`has_right_at`]: DidDocument::has_right_at
+    pub fn has_delegated_right_at(
+        &self, auth: &Authentication, right: Right, height: BlockHeight,
+        resolver: &dyn DidDocumentResolver,
+    ) -> Result<bool> {
+        let mut path = vec![self.did.to_owned()];
+        self.has_delegated_right_rec(auth, right, height, resolver, &mut path)
+    }
+
+    fn has_delegated_right_rec(
+        &self, auth: &Authentication, right: Right, height: BlockHeight,
+        resolver: &dyn DidDocumentResolver, path: &mut Vec<Did>,
+    ) -> Result<bool> {
+        if self.has_right_at(auth, right.clone(), height)? {
+            return Ok(true);
+        }
+        if path.len() > MAX_DELEGATION_DEPTH {
+            return Ok(false);
+        }
+        for delegate in self.delegates_at(right.clone(), height)? {
+            if path.contains(&delegate) {
+                continue;
+            }
+            let doc = resolver.resolve_at(&delegate, height)?;
+            path.push(delegate);
+            let found = doc.has_delegated_right_rec(auth, right.clone(), height, resolver, path)?;
+            path.pop();
+            if found {
+                return Ok(true);
+            }
+        }
+        Ok(false)
+    }
+
+    /// Like [`validate_right`], but if the authentication has no valid right on this DID, it
+    /// looks for a chain of delegations through which the right was held during the whole
+    /// period. Issues of the first chain without errors are returned, otherwise the issues of the
+    /// direct check.
+    ///
+    /// [`validate_right`]: DidDocument::validate_right
+    pub fn validate_delegated_right(
+        &self, auth: &Authentication, right: Right, from: BlockHeight, until: BlockHeight,
+        resolver: &dyn DidDocumentResolver,
+    ) -> Result<ValidationResult> {
+        let mut path = vec![self.did.to_owned()];
+        self.validate_delegated_right_rec(auth, right, from, until, resolver, &mut path)
+    }
+
+    fn validate_delegated_right_rec(
+        &self, auth: &Authentication, right: Right, from: BlockHeight, until: BlockHeight,
+        resolver: &dyn DidDocumentResolver, path: &mut Vec<Did>,
+    ) -> Result<ValidationResult> {
+        let direct = self.validate_right(auth, right.clone(), from, until)?;
+        if direct.status() != ValidationStatus::Invalid || path.len() > MAX_DELEGATION_DEPTH {
+            return Ok(direct);
+        }
+
+        let mut delegates: Vec<Did> = vec![];
+        for key in &self.keys {
+            if let Authentication::Did(delegate) = &key.state.authentication {
+                if !delegates.contains(delegate) && !path.contains(delegate) {
+                    delegates.push(delegate.to_owned());
+                }
+            }
+        }
+
+        for delegate in delegates {
+            let link_auth = Authentication::Did(delegate.to_owned());
+            let mut result = self.validate_right(&link_auth, right.clone(), from, until)?;
+            if result.status() == ValidationStatus::Invalid {
+                continue;
+            }
+            let doc = resolver.resolve_at(&delegate, until)?;
+            path.push(delegate);
+            let delegated =
+                doc.validate_delegated_right_rec(auth, right.clone(), from, until, resolver, path)?;
+            path.pop();
+            if delegated.status() != ValidationStatus::Invalid {
+                for issue in delegated.issues() {
+                    result.add_issue(issue.severity(), issue.reason());
+                }
+                return Ok(result);
+            }
+        }
+        Ok(direct)
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
-    use crate::data::validation::ValidationStatus;
+    use crate::{data::validation::ValidationStatus, test_helpers::signer};
+
+    use chrono::TimeZone;
 
     #[test]
     fn pretty_json() -> Result<()> {
@@ -556,4 +1010,198 @@ mod test {
 
         Ok(())
     }
+
+    // Default key of the DID has all rights, the delegate has only the update right from height 10
+    fn delegating_doc(did: &Did, delegate: Option<&Did>) -> DidDocument {
+        let right = |link: &str, history: Vec<KeyRightHistoryItem>| KeyRightHistory {
+            derived: KeyRightDerived {
+                key_link: link.to_owned(),
+                valid: history.last().map(|item| item.valid).unwrap_or_default(),
+            },
+            state: KeyRightState { history },
+        };
+        let always = || vec![KeyRightHistoryItem { height: None, valid: true, timestamp: None }];
+        let mut doc = DidDocument::implicit(did);
+        doc.queried_at_height = 100;
+        doc.rights.insert(Right::Update, vec![right("#0", always())]);
+        doc.rights.insert(Right::Impersonation, vec![right("#0", always())]);
+        if let Some(delegate) = delegate {
+            let mut key = KeyData::from_auth(Authentication::Did(delegate.to_owned()));
+            key.state.valid_from_block = Some(10);
+            key.derived.index = 1;
+            doc.keys.push(key);
+            let update = vec![
+                KeyRightHistoryItem { height: None, valid: false, timestamp: None },
+                KeyRightHistoryItem { height: Some(10), valid: true, timestamp: None },
+            ];
+            doc.rights.get_mut(&Right::Update).unwrap().push(right("#1", update));
+            let impersonate =
+                vec![KeyRightHistoryItem { height: None, valid: false, timestamp: None }];
+            doc.rights.get_mut(&Right::Impersonation).unwrap().push(right("#1", impersonate));
+        }
+        doc
+    }
+
+    #[test]
+    fn service_types() -> Result<()> {
+        for type_ in
+            [ServiceType::DidCommMessaging, ServiceType::Storage, ServiceType::LinkedDomains]
+        {
+            assert_eq!(type_.to_string().parse::<ServiceType>()?, type_);
+            assert_eq!(serde_json::to_value(type_)?, serde_json::Value::String(type_.to_string()));
+        }
+        assert_eq!(ServiceType::DidCommMessaging.to_string(), "DIDCommMessaging");
+        let err = "storage".parse::<ServiceType>().unwrap_err();
+        assert_eq!(err.to_string(), "Unknown service type storage");
+        assert!(serde_json::from_str::<ServiceType>("\"Hub\"").is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn custom_rights() -> Result<()> {
+        assert_eq!("update".parse::<Right>()?, Right::Update);
+        assert_eq!("impersonate".parse::<Right>()?, Right::Impersonation);
+        let sign = Right::custom("acme", "sign-contracts")?;
+        assert_eq!(sign.to_string(), "acme:sign-contracts");
+        assert!(!sign.is_system());
+        assert!(Right::Update.is_system());
+        for invalid in ["sign-contracts", "acme:Sign Contracts", "acme:", ":sign", "a:b:c"] {
+            assert!(invalid.parse::<Right>().is_err(), "{} should be invalid", invalid);
+        }
+
+        let subject = signer("custom").0;
+        let auth = Authentication::KeyId(subject.default_key_id());
+        let mut doc = delegating_doc(&subject, None);
+        let history = vec![
+            KeyRightHistoryItem { height: None, valid: false, timestamp: None },
+            KeyRightHistoryItem { height: Some(10), valid: true, timestamp: None },
+        ];
+        let key_right = KeyRightHistory {
+            derived: KeyRightDerived { key_link: "#0".to_owned(), valid: true },
+            state: KeyRightState { history },
+        };
+        doc.rights.insert(sign.clone(), vec![key_right]);
+
+        let json = serde_json::to_value(&doc)?;
+        assert!(json["rights"]["acme:sign-contracts"].is_array());
+        assert_eq!(serde_json::from_value::<DidDocument>(json)?, doc);
+
+        assert!(!doc.has_right_at(&auth, sign.clone(), 5)?);
+        assert!(doc.has_right_at(&auth, sign.clone(), 20)?);
+        assert!(!doc.has_right_at(&auth, Right::custom("acme", "manage-devices")?, 20)?);
+        assert_eq!(doc.validate_right(&auth, sign, 5, 20)?.status(), ValidationStatus::MaybeValid);
+
+        Ok(())
+    }
+
+    #[test]
+    fn time_based_validity() -> Result<()> {
+        use ValidationStatus::*;
+
+        let time = |hour| Utc.with_ymd_and_hms(2022, 7, 1, hour, 0, 0).unwrap();
+        let subject = signer("timed").0;
+        let auth = Authentication::KeyId(signer("timed key").0.default_key_id());
+        let mut doc = delegating_doc(&subject, None);
+        doc.queried_at_time = Some(time(20));
+        let mut key = KeyData::from_auth(auth.clone());
+        key.state.valid_from_block = Some(10);
+        key.state.valid_from_time = Some(time(2));
+        // Expiration time was not reached by any block at the queried height
+        key.state.valid_until_time = Some(time(22));
+        key.derived.index = 1;
+        doc.keys.push(key);
+        let history = vec![
+            KeyRightHistoryItem { height: None, valid: false, timestamp: None },
+            KeyRightHistoryItem { height: Some(15), valid: true, timestamp: Some(time(4)) },
+        ];
+        let impersonate = KeyRightHistory {
+            derived: KeyRightDerived { key_link: "#1".to_owned(), valid: true },
+            state: KeyRightState { history },
+        };
+        doc.rights.get_mut(&Right::Impersonation).unwrap().push(impersonate);
+
+        assert!(!doc.has_right_at_time(&auth, Right::Impersonation, time(3))?);
+        assert!(doc.has_right_at_time(&auth, Right::Impersonation, time(4))?);
+        assert!(!doc.has_right_at_time(&auth, Right::Update, time(4))?);
+        assert!(doc.has_right_at_time(&auth, Right::Impersonation, time(21)).is_err());
+
+        let validate = |doc: &DidDocument, from, until| {
+            doc.validate_right_at_time(&auth, Right::Impersonation, time(from), time(until))
+        };
+        assert_eq!(validate(&doc, 5, 20)?.status(), Valid);
+        assert_eq!(validate(&doc, 3, 20)?.status(), MaybeValid);
+        assert_eq!(validate(&doc, 1, 2)?.status(), Invalid);
+
+        doc.tombstoned = true;
+        doc.tombstoned_at_height = Some(30);
+        assert!(doc.is_tombstoned_at_time(time(6)).is_err());
+        doc.tombstoned_at_time = Some(time(6));
+        assert!(!doc.is_tombstoned_at_time(time(5))?);
+        assert!(doc.is_tombstoned_at_time(time(6))?);
+        assert!(!doc.has_right_at_time(&auth, Right::Impersonation, time(7))?);
+        assert_eq!(validate(&doc, 5, 20)?.status(), MaybeValid);
+
+        Ok(())
+    }
+
+    #[test]
+    fn delegation() -> Result<()> {
+        use ValidationStatus::*;
+
+        let org = signer("organization").0;
+        let admin = signer("admin").0;
+        let admin_key = Authentication::KeyId(admin.default_key_id());
+        let org_doc = delegating_doc(&org, Some(&admin));
+        // The admin delegates back to the organization, forming a cycle
+        let docs: HashMap<Did, DidDocument> = vec![
+            (org.clone(), org_doc.clone()),
+            (admin.clone(), delegating_doc(&admin, Some(&org))),
+        ]
+        .into_iter()
+        .collect();
+
+        assert_eq!(org_doc.delegates_at(Right::Update, 20)?, vec![admin.clone()]);
+        assert!(org_doc.delegates_at(Right::Update, 5)?.is_empty());
+        assert!(org_doc.delegates_at(Right::Impersonation, 20)?.is_empty());
+
+        assert!(!org_doc.has_right_at(&admin_key, Right::Update, 20)?);
+        assert!(org_doc.has_delegated_right_at(&admin_key, Right::Update, 20, &docs)?);
+        assert!(!org_doc.has_delegated_right_at(&admin_key, Right::Update, 5, &docs)?);
+        assert!(!org_doc.has_delegated_right_at(&admin_key, Right::Impersonation, 20, &docs)?);
+        let stranger = Authentication::KeyId(signer("stranger").0.default_key_id());
+        assert!(!org_doc.has_delegated_right_at(&stranger, Right::Update, 20, &docs)?);
+
+        let validate = |from, until| {
+            org_doc.validate_delegated_right(&admin_key, Right::Update, from, until, &docs)
+        };
+        assert_eq!(org_doc.validate_right(&admin_key, Right::Update, 20, 30)?.status(), Invalid);
+        assert_eq!(validate(20, 30)?.status(), Valid);
+        assert_eq!(validate(5, 30)?.status(), MaybeValid);
+        assert_eq!(validate(1, 5)?.status(), Invalid);
+
+        Ok(())
+    }
+
+    #[test]
+    fn delegation_depth() -> Result<()> {
+        let dids: Vec<Did> =
+            (0..=MAX_DELEGATION_DEPTH + 1).map(|i| signer(&format!("chain{}", i)).0).collect();
+        let docs: HashMap<Did, DidDocument> = dids
+            .iter()
+            .enumerate()
+            .map(|(i, did)| (did.to_owned(), delegating_doc(did, dids.get(i + 1))))
+            .collect();
+        let root = &docs[&dids[0]];
+
+        let last_allowed = Authentication::KeyId(dids[MAX_DELEGATION_DEPTH].default_key_id());
+        assert!(root.has_delegated_right_at(&last_allowed, Right::Update, 20, &docs)?);
+        let too_deep = Authentication::KeyId(dids[MAX_DELEGATION_DEPTH + 1].default_key_id());
+        assert!(!root.has_delegated_right_at(&too_deep, Right::Update, 20, &docs)?);
+        assert_eq!(
+            root.validate_delegated_right(&too_deep, Right::Update, 20, 30, &docs)?.status(),
+            ValidationStatus::Invalid
+        );
+
+        Ok(())
+    }
 }
@@ -0,0 +1,262 @@
+use super::*;
+
+use crate::{
+    crypto::{
+        hash::Content,
+        sign::{Signable, Signed},
+    },
+    data::{auth::Authentication, did::Did, diddoc::DidDocumentResolver, diddoc::Right},
+};
+
+/// Maximum number of licenses from the subject of a claim to the verifier
+pub const MAX_LICENSE_CHAIN_LENGTH: usize = 4;
+
+/// What the licensee may use a claim for
+#[derive(Clone, Debug, Eq, Hash, PartialEq, PartialOrd)]
+pub enum LicensePurpose {
+    /// Know Your Customer checks
+    Kyc,
+    /// Logging in or otherwise proving the identity of the subject
+    Authentication,
+    /// An application-defined purpose in the form of `namespace:name`, e.g. `myapp:age-check`
+    Custom(String),
+}
+
+impl LicensePurpose {
+    const KYC: &'static str = "KYC";
+    const AUTHENTICATION: &'static str = "authentication";
+}
+
+impl Display for LicensePurpose {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Kyc => write!(f, "{}", Self::KYC),
+            Self::Authentication => write!(f, "{}", Self::AUTHENTICATION),
+            Self::Custom(purpose) => write!(f, "{}", purpose),
+        }
+    }
+}
+
+impl FromStr for LicensePurpose {
+    type Err = anyhow::Error;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            Self::KYC => return Ok(Self::Kyc),
+            Self::AUTHENTICATION => return Ok(Self::Authentication),
+            _ => {}
+        }
+        // Custom purposes follow the same syntax as custom rights
+        ensure!(
+            matches!(s.parse::<Right>(), Ok(Right::Custom(_))),
+            "Purpose '{}' is neither a known purpose nor a custom purpose like 'myapp:age-check'",
+            s
+        );
+        Ok(Self::Custom(s.to_owned()))
+    }
+}
+
+impl Serialize for LicensePurpose {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for LicensePurpose {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        use serde::de::Error as _;
+        let purpose = String::deserialize(deserializer)?;
+        purpose.parse().map_err(D::Error::custom)
+    }
+}
+
+/// Permission to use the claims of the issuer for a purpose in a period. The licensee can share the
+/// claims further by issuing licenses for the same purpose, which makes a chain from the subject of
+/// the claims to the verifier.
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, PartialOrd, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct License {
+    #[serde(with = "serde_str")]
+    pub issuer: Did,
+    #[serde(with = "serde_str")]
+    pub issued_to: Did,
+    pub purpose: LicensePurpose,
+    pub valid_from: DateTime<Utc>,
+    pub valid_until: DateTime<Utc>,
+}
+
+impl Content for License {}
+impl Signable for License {}
+
+impl License {
+    pub fn is_valid_at(&self, time: DateTime<Utc>) -> bool {
+        self.valid_from <= time && time < self.valid_until
+    }
+}
+
+/// Who checks the licenses of a presentation, for what purpose and when
+pub struct LicenseVerifier<'a> {
+    pub verifier: &'a Did,
+    pub purpose: &'a LicensePurpose,
+    pub now: DateTime<Utc>,
+    /// DID documents of the issuers are resolved at this height to check the signatures
+    pub height: BlockHeight,
+    pub resolver: &'a dyn DidDocumentResolver,
+}
+
+impl LicenseVerifier<'_> {
+    /// Finds a chain of licenses from the subject to the verifier. Each license has to be signed by
+    /// a key with the impersonation right on its issuer, has to be valid now and has to be for the
+    /// purpose of the verifier. Claims about the verifier itself need no licenses.
+    pub fn validate_chain(&self, subject: &Did, licenses: &[Signed<License>]) -> Result<()> {
+        if subject == self.verifier {
+            return Ok(());
+        }
+        let mut path = vec![subject.to_owned()];
+        ensure!(
+            self.has_chain_rec(licenses, &mut path)?,
+            "No valid license chain from {} to {} for purpose {}",
+            subject,
+            self.verifier,
+            self.purpose
+        );
+        Ok(())
+    }
+
+    fn has_chain_rec(&self, licenses: &[Signed<License>], path: &mut Vec<Did>) -> Result<bool> {
+        if path.len() > MAX_LICENSE_CHAIN_LENGTH {
+            return Ok(false);
+        }
+        let issuer = path.last().expect("path starts with the subject").to_owned();
+        for signed in licenses {
+            let license = signed.content();
+            if license.issuer != issuer || path.contains(&license.issued_to) {
+                continue;
+            }
+            if !self.is_valid(signed)? {
+                continue;
+            }
+            if &license.issued_to == self.verifier {
+                return Ok(true);
+            }
+            path.push(license.issued_to.to_owned());
+            if self.has_chain_rec(licenses, path)? {
+                return Ok(true);
+            }
+            path.pop();
+        }
+        Ok(false)
+    }
+
+    fn is_valid(&self, signed: &Signed<License>) -> Result<bool> {
+        let license = signed.content();
+        if &license.purpose != self.purpose || !license.is_valid_at(self.now) || !signed.validate()
+        {
+            return Ok(false);
+        }
+        let signer = Authentication::PublicKey(signed.public_key().to_owned());
+        let issuer_doc = self.resolver.resolve_at(&license.issuer, self.height)?;
+        issuer_doc.has_delegated_right_at(&signer, Right::Impersonation, self.height, self.resolver)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use chrono::TimeZone;
+
+    use crate::{
+        crypto::sign::{PrivateKeySigner, SyncMorpheusSigner},
+        data::{ClaimPresentation, DidDocument, ProvenClaim},
+        test_helpers::{impersonating_doc, signer},
+    };
+
+    const HEIGHT: BlockHeight = 100;
+
+    fn month(month: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2022, month, 1, 0, 0, 0).unwrap()
+    }
+
+    fn license(
+        signer: &PrivateKeySigner, issuer: &Did, issued_to: &Did, purpose: LicensePurpose,
+    ) -> Result<Signed<License>> {
+        let license = License {
+            issuer: issuer.to_owned(),
+            issued_to: issued_to.to_owned(),
+            purpose,
+            valid_from: month(1),
+            valid_until: month(7),
+        };
+        signer.sign_license(license)
+    }
+
+    #[test]
+    fn purposes() -> Result<()> {
+        assert_eq!("KYC".parse::<LicensePurpose>()?, LicensePurpose::Kyc);
+        assert_eq!("authentication".parse::<LicensePurpose>()?, LicensePurpose::Authentication);
+        let custom: LicensePurpose = "myapp:age-check".parse()?;
+        assert_eq!(serde_json::to_value(&custom)?, serde_json::json!("myapp:age-check"));
+        for invalid in ["marketing", "kyc", "myapp:Age Check"] {
+            assert!(invalid.parse::<LicensePurpose>().is_err(), "{} should be invalid", invalid);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn license_chain() -> Result<()> {
+        let (subject, subject_signer) = signer("subject");
+        let (holder, holder_signer) = signer("holder");
+        let (verifier, _) = signer("verifier");
+        let docs: HashMap<Did, DidDocument> = [&subject, &holder]
+            .iter()
+            .map(|did| ((*did).to_owned(), impersonating_doc(did, HEIGHT)))
+            .collect();
+        let kyc = LicensePurpose::Kyc;
+        let check = LicenseVerifier {
+            verifier: &verifier,
+            purpose: &kyc,
+            now: month(3),
+            height: HEIGHT,
+            resolver: &docs,
+        };
+
+        let to_holder = license(&subject_signer, &subject, &holder, LicensePurpose::Kyc)?;
+        let to_verifier = license(&holder_signer, &holder, &verifier, LicensePurpose::Kyc)?;
+        let verifier_only = std::slice::from_ref(&to_verifier);
+        check.validate_chain(&subject, &[to_holder.clone(), to_verifier.clone()])?;
+        check.validate_chain(&holder, verifier_only)?;
+        assert!(check.validate_chain(&subject, verifier_only).is_err());
+
+        let expired = LicenseVerifier { now: month(8), ..check };
+        assert!(expired.validate_chain(&holder, verifier_only).is_err());
+        let auth = LicensePurpose::Authentication;
+        let other_purpose = LicenseVerifier { purpose: &auth, ..check };
+        assert!(other_purpose.validate_chain(&holder, verifier_only).is_err());
+
+        // Licenses can only be issued by keys with impersonation right on the issuer
+        let forged = license(&subject_signer, &holder, &verifier, LicensePurpose::Kyc)?;
+        assert!(check.validate_chain(&subject, &[to_holder.clone(), forged]).is_err());
+
+        let claim = serde_json::json!({ "subject": subject.to_string(), "content": {} });
+        let mut presentation = ClaimPresentation {
+            proven_claims: vec![ProvenClaim { claim, statements: vec![] }],
+            licenses: vec![to_verifier, to_holder],
+            nonce: None,
+        };
+        presentation.validate_licenses(&check)?;
+        presentation.licenses.pop();
+        assert!(presentation.validate_licenses(&check).is_err());
+
+        // Claims about the verifier or presented by their subject need no licenses
+        let to_self = LicenseVerifier { verifier: &subject, ..check };
+        to_self.validate_chain(&subject, &[])?;
+        presentation.licenses.clear();
+        let by_subject = subject_signer.sign_claim_presentation(presentation.clone())?;
+        by_subject.validate_licenses_presented_by(&subject, &check)?;
+        let by_holder = holder_signer.sign_claim_presentation(presentation)?;
+        assert!(by_holder.validate_licenses_presented_by(&holder, &check).is_err());
+        assert!(by_holder.validate_licenses_presented_by(&subject, &check).is_err());
+
+        Ok(())
+    }
+}
@@ -4,10 +4,13 @@ mod claim;
 mod did;
 mod diddoc;
 mod error;
+mod license;
 mod present;
 mod process;
 mod schema;
 mod validation;
+mod vc;
+mod w3c;
 
 pub use auth::*;
 pub use before_proof::*;
@@ -15,10 +18,13 @@ pub use claim::*;
 pub use did::*;
 pub use diddoc::*;
 pub use error::*;
+pub use license::*;
 pub use present::*;
 pub use process::*;
 pub use schema::*;
 pub use validation::*;
+pub use vc::*;
+pub use w3c::*;
 
 use std::collections::HashMap;
 use std::fmt::{self, Display, Formatter};
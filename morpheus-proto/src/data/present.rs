@@ -2,25 +2,16 @@ use super::*;
 
 use crate::{
     crypto::{
-        hash::Content,
+        hash::{Content, ContentId},
         sign::{Signable, Signed},
     },
-    data::did::Did,
+    data::{
+        claim::WitnessStatement,
+        did::Did,
+        license::{License, LicenseVerifier},
+    },
 };
-
-#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, PartialOrd, Serialize)]
-pub struct License {
-    #[serde(rename = "issuedTo", with = "serde_str")]
-    pub issued_to: Did,
-    pub purpose: String, // TODO should be more strictly typed, probably an enum
-    #[serde(rename = "validFrom")]
-    pub valid_from: String, // TODO should be some strict date type here, like std::time::Instant but it's not serde-serializable
-    #[serde(rename = "validUntil")]
-    pub valid_until: String,
-}
-
-impl Content for License {}
-impl Signable for License {}
+use json_digest::mask_json_subtree;
 
 // TODO this probably should be more strictly typed here
 #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
@@ -32,20 +23,184 @@ pub struct ProvenClaim {
 impl Content for ProvenClaim {}
 impl Signable for ProvenClaim {}
 
+impl ProvenClaim {
+    /// Properties of a witness statement a verifier needs besides the disclosed parts of the claim
+    const STATEMENT_PATHS: [&'static str; 4] =
+        [".processId", ".claim.subject", ".constraints", ".nonce"];
+
+    /// Creates a proven claim from a single witness statement, disclosing only the given paths of
+    /// the claim content, like `.address.city`. Everything else in the claim content is masked with
+    /// its digest, so the signature of the witness still validates. Only objects and arrays can be
+    /// masked, so claims should wrap each maskable value into an object with a nonce.
+    pub fn disclose(statement: &Signed<WitnessStatement>, content_paths: &[&str]) -> Result<Self> {
+        let mut keep_paths =
+            Self::STATEMENT_PATHS.iter().map(|p| p.to_string()).collect::<Vec<_>>();
+        for path in content_paths {
+            ensure!(path.starts_with('.'), "Path must start with '.' but it's: {}", path);
+            keep_paths.push(format!(".claim.content{}", path));
+        }
+        let keep_paths = keep_paths.iter().map(|p| p.as_str()).collect::<Vec<_>>();
+
+        let (public_key, statement, signature, nonce) = statement.to_owned().into_parts();
+        let content = mask_json_subtree(&serde_json::to_value(statement)?, keep_paths)?;
+        let claim = content["claim"].clone();
+        let masked = Signed::from_parts(public_key, content, signature, nonce);
+        Ok(Self { claim, statements: vec![masked] })
+    }
+
+    /// Checks that the claim is the same as the one in each statement, regardless of which parts are
+    /// masked, and that the signatures of the statements are valid. Returns the content id of the
+    /// claim. Whether the signers had the right to sign on behalf of the authorities has to be checked
+    /// separately with their DID documents.
+    pub fn verify(&self) -> Result<ContentId> {
+        ensure!(!self.statements.is_empty(), "Proven claim has no statements");
+        let claim_id = self.claim.content_id()?;
+        for statement in &self.statements {
+            let statement_id = statement.content().content_id()?;
+            ensure!(statement.validate(), "Signature of statement {} is invalid", statement_id);
+            ensure!(
+                statement.content()["claim"].content_id()? == claim_id,
+                "Statement {} witnesses a different claim than {}",
+                statement_id,
+                claim_id
+            );
+        }
+        Ok(claim_id)
+    }
+
+    pub fn subject(&self) -> Result<Did> {
+        let subject = self.claim["subject"]
+            .as_str()
+            .ok_or_else(|| anyhow!("Subject of the claim is missing or masked"))?;
+        subject.parse()
+    }
+}
+
 #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
 pub struct ClaimPresentation {
     #[serde(rename = "provenClaims")]
     pub proven_claims: Vec<ProvenClaim>,
-    pub licenses: Vec<License>,
+    /// Chains of licenses from the subjects of the claims to the verifier
+    pub licenses: Vec<Signed<License>>,
     #[serde(skip_serializing_if = "Option::is_none", default)]
     pub nonce: Option<Nonce264>,
-    // if subjects are different (from each other or the creator of this presentation)
-    // then the creator an optional license is needed to prove proper rights to further delegate claims
-    // consider how to do it without potentially infinite data size?
-    // claim_control_proof: Option<Signed<ClaimPresentation>>,
 }
 
 impl Content for ClaimPresentation {}
 impl Signable for ClaimPresentation {}
 
+impl ClaimPresentation {
+    /// Checks that the verifier may use every claim in the presentation for its purpose now. The
+    /// licenses have to form a chain from the subject of each claim to the verifier.
+    ///
+    /// Subjects presenting their own claims would have to license them to the verifier, too. Use
+    /// [`Signed::validate_licenses_presented_by`] on the signed presentation to avoid that.
+    pub fn validate_licenses(&self, verifier: &LicenseVerifier) -> Result<()> {
+        self.validate_licenses_except(None, verifier)
+    }
+
+    fn validate_licenses_except(
+        &self, presenter: Option<&Did>, verifier: &LicenseVerifier,
+    ) -> Result<()> {
+        for proven in &self.proven_claims {
+            let subject = proven.subject()?;
+            if Some(&subject) == presenter {
+                continue;
+            }
+            verifier.validate_chain(&subject, &self.licenses)?;
+        }
+        Ok(())
+    }
+}
+
+impl Signed<ClaimPresentation> {
+    /// Like [`ClaimPresentation::validate_licenses`], but claims about the presenter need no
+    /// licenses, as the presenter shares them directly with the verifier. The presentation has to
+    /// be signed by a key with the impersonation right on the presenter.
+    pub fn validate_licenses_presented_by(
+        &self, presenter: &Did, verifier: &LicenseVerifier,
+    ) -> Result<()> {
+        ensure!(self.validate(), "Signature of the claim presentation is invalid");
+        let signer = Authentication::PublicKey(self.public_key().to_owned());
+        let presenter_doc = verifier.resolver.resolve_at(presenter, verifier.height)?;
+        ensure!(
+            presenter_doc.has_delegated_right_at(
+                &signer,
+                Right::Impersonation,
+                verifier.height,
+                verifier.resolver
+            )?,
+            "{} has no right to present claims on behalf of {}",
+            signer,
+            presenter
+        );
+        self.content().validate_licenses_except(Some(presenter), verifier)
+    }
+}
+
 // TODO Maskable: T -> serde_json::Value
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use crate::{
+        crypto::sign::SyncMorpheusSigner,
+        data::{Claim, Constraints},
+        test_helpers::signer,
+    };
+
+    fn signed_statement() -> Result<Signed<WitnessStatement>> {
+        let (_, signer) = signer("witness");
+        let statement = WitnessStatement {
+            process_id: "cjunI8lB1BzsO8T6v4Ay1FwWMbO6enWQRqbBWb4lVlW6l8".to_owned(),
+            claim: Claim {
+                subject: "did:morpheus:ezbeWGSY2dqcUBqT8K7R14xr".parse()?,
+                content: serde_json::json!({
+                    "dateOfBirth": { "nonce": Nonce264::generate(), "value": "1980-02-14" },
+                    "address": {
+                        "city": { "nonce": Nonce264::generate(), "value": "Budapest" },
+                        "street": { "nonce": Nonce264::generate(), "value": "Andrassy ut 1" },
+                    },
+                }),
+            },
+            constraints: Constraints {
+                after: None,
+                before: None,
+                witness: "iezqztJ6XX6GDxdSgdiySiT3J".to_owned(),
+                authority: "did:morpheus:ezqztJ6XX6GDxdSgdiySiT3J".parse()?,
+                content: serde_json::json!({}),
+            },
+            nonce: Some(Nonce264::generate()),
+        };
+        signer.sign_witness_statement(statement)
+    }
+
+    #[test]
+    fn selective_disclosure() -> Result<()> {
+        let signed = signed_statement()?;
+        let claim_id = signed.content().claim.content_id()?;
+
+        let proven = ProvenClaim::disclose(&signed, &[".dateOfBirth", ".address.city"])?;
+        assert_eq!(proven.claim["content"]["dateOfBirth"]["value"], "1980-02-14");
+        assert_eq!(proven.claim["content"]["address"]["city"]["value"], "Budapest");
+        assert!(proven.claim["content"]["address"]["street"].is_string());
+        assert_eq!(proven.statements[0].content()["claim"], proven.claim);
+        assert_eq!(proven.verify()?, claim_id);
+
+        let proven = ProvenClaim::disclose(&signed, &[".dateOfBirth"])?;
+        assert!(proven.claim["content"]["address"].is_string());
+        assert_eq!(proven.verify()?, claim_id);
+        assert!(ProvenClaim::disclose(&signed, &["dateOfBirth"]).is_err());
+
+        let mut forged = proven.clone();
+        forged.claim["content"]["dateOfBirth"]["value"] = serde_json::json!("2000-02-14");
+        assert!(forged.verify().is_err());
+
+        let mut forged = proven;
+        forged.statements.clear();
+        assert!(forged.verify().is_err());
+
+        Ok(())
+    }
+}
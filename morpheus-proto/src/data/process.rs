@@ -1,7 +1,10 @@
-use serde::{Deserialize, Serialize};
+use super::*;
 
 use crate::crypto::{hash::Content, sign::Signable};
-use crate::data::schema::MorpheusSchema;
+use crate::data::{
+    claim::{WitnessRequest, WitnessStatement},
+    schema::{SchemaId, SchemaResolver},
+};
 
 pub type ProcessId = String; // TODO use something like a ContentId here
 
@@ -11,10 +14,168 @@ pub struct Process {
     name: String,
     version: u32,
     description: String,
-    evidence_schema: MorpheusSchema,
-    constraints_schema: MorpheusSchema,
-    claim_schema: MorpheusSchema,
+    evidence_schema: SchemaId,
+    constraints_schema: SchemaId,
+    claim_schema: SchemaId,
 }
 
 impl Content for Process {}
 impl Signable for Process {}
+
+impl Process {
+    pub fn new(
+        name: impl ToString, version: u32, description: impl ToString, evidence_schema: SchemaId,
+        constraints_schema: SchemaId, claim_schema: SchemaId,
+    ) -> Self {
+        Self {
+            name: name.to_string(),
+            version,
+            description: description.to_string(),
+            evidence_schema,
+            constraints_schema,
+            claim_schema,
+        }
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn version(&self) -> u32 {
+        self.version
+    }
+
+    pub fn description(&self) -> &str {
+        &self.description
+    }
+
+    pub fn evidence_schema(&self) -> &SchemaId {
+        &self.evidence_schema
+    }
+
+    pub fn constraints_schema(&self) -> &SchemaId {
+        &self.constraints_schema
+    }
+
+    pub fn claim_schema(&self) -> &SchemaId {
+        &self.claim_schema
+    }
+
+    /// Checks a witness request before signing or accepting it: it has to refer to this process, and
+    /// both its claim content and its evidence have to match the schemas of the process.
+    pub fn validate_request(
+        &self, request: &WitnessRequest, schemas: &dyn SchemaResolver,
+    ) -> Result<()> {
+        self.ensure_process_id(&request.process_id)?;
+        schemas
+            .resolve(&self.claim_schema)?
+            .validate(&request.claim.content)
+            .context("Invalid claim")?;
+        schemas
+            .resolve(&self.evidence_schema)?
+            .validate(&request.evidence)
+            .context("Invalid evidence")?;
+        Ok(())
+    }
+
+    /// Checks a witness statement before signing or accepting it: it has to refer to this process,
+    /// and both its claim content and its constraints have to match the schemas of the process.
+    pub fn validate_statement(
+        &self, statement: &WitnessStatement, schemas: &dyn SchemaResolver,
+    ) -> Result<()> {
+        self.ensure_process_id(&statement.process_id)?;
+        schemas
+            .resolve(&self.claim_schema)?
+            .validate(&statement.claim.content)
+            .context("Invalid claim")?;
+        schemas
+            .resolve(&self.constraints_schema)?
+            .validate(&statement.constraints.content)
+            .context("Invalid constraints")?;
+        Ok(())
+    }
+
+    fn ensure_process_id(&self, process_id: &str) -> Result<()> {
+        let id = self.content_id()?;
+        ensure!(id == process_id, "Expected process {}, but got {}", id, process_id);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use serde_json::json;
+
+    use crate::data::{claim::Claim, schema::MorpheusSchema};
+
+    fn add_schema(
+        schemas: &mut HashMap<SchemaId, MorpheusSchema>, schema: serde_json::Value,
+    ) -> Result<SchemaId> {
+        let schema = MorpheusSchema::new(schema)?;
+        let id = schema.content_id()?;
+        schemas.insert(id.clone(), schema);
+        Ok(id)
+    }
+
+    #[test]
+    fn schema_serialization() -> Result<()> {
+        let schema: MorpheusSchema = serde_json::from_value(json!({ "type": "object" }))?;
+        assert_eq!(serde_json::to_value(&schema)?, json!({ "type": "object" }));
+        let invalid = serde_json::from_value::<MorpheusSchema>(json!({ "type": 42 }));
+        assert!(invalid.unwrap_err().to_string().contains("Invalid JSON schema"));
+        Ok(())
+    }
+
+    #[test]
+    fn witness_request() -> Result<()> {
+        let mut schemas = HashMap::new();
+        let birth = json!({ "type": "string", "pattern": "^[0-9]{4}-[0-9]{2}-[0-9]{2}$" });
+        let claim_schema = add_schema(
+            &mut schemas,
+            json!({ "type": "object", "required": ["dateOfBirth"], "properties": { "dateOfBirth": birth } }),
+        )?;
+        let evidence_schema =
+            add_schema(&mut schemas, json!({ "type": "object", "required": ["photo"] }))?;
+        let constraints_schema = add_schema(&mut schemas, json!({ "type": "object" }))?;
+        let process = Process::new(
+            "Age over 18",
+            1,
+            "Witnessed from an ID card",
+            evidence_schema,
+            constraints_schema,
+            claim_schema,
+        );
+
+        let mut request = WitnessRequest {
+            process_id: process.content_id()?,
+            claimant: "iezqztJ6XX6GDxdSgdiySiT3J".to_owned(),
+            claim: Claim {
+                subject: "did:morpheus:ezqztJ6XX6GDxdSgdiySiT3J".parse()?,
+                content: json!({ "dateOfBirth": "1980-02-14" }),
+            },
+            evidence: json!({ "photo": "uEiD..." }),
+            nonce: None,
+        };
+        process.validate_request(&request, &schemas)?;
+
+        request.claim.content = json!({ "dateOfBirth": "14/02/1980" });
+        let err = process.validate_request(&request, &schemas).unwrap_err();
+        assert_eq!(err.to_string(), "Invalid claim");
+
+        request.claim.content = json!({ "dateOfBirth": "1980-02-14" });
+        request.evidence = json!({});
+        assert!(process.validate_request(&request, &schemas).is_err());
+
+        request.evidence = json!({ "photo": "uEiD..." });
+        request.process_id = "cjunI8lB1BzsO8T6v4Ay1FwWMbO6enWQRqbBWb4lVlW6l8".to_owned();
+        assert!(process.validate_request(&request, &schemas).is_err());
+
+        request.process_id = process.content_id()?;
+        assert!(process.validate_request(&request, &HashMap::new()).is_err());
+        assert!(MorpheusSchema::new(json!({ "type": 42 })).is_err());
+
+        Ok(())
+    }
+}
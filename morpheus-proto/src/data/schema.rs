@@ -1,4 +1,88 @@
-// TODO use something like a ContentId for schema Ids
-pub type MorpheusSchema = String;
+use super::*;
+
+use valico::json_schema;
+
+use crate::crypto::hash::{Content, ContentId};
+
+/// Content id of a [`MorpheusSchema`]
+pub type SchemaId = ContentId;
 
 pub type MorpheusValue = serde_json::Value;
+
+/// A JSON Schema (draft 6) document describing the claims, evidence or constraints of a process.
+/// Invalid schemas are rejected when deserialized, too.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(try_from = "serde_json::Value", into = "serde_json::Value")]
+pub struct MorpheusSchema(serde_json::Value);
+
+impl Content for MorpheusSchema {}
+
+impl TryFrom<serde_json::Value> for MorpheusSchema {
+    type Error = anyhow::Error;
+
+    fn try_from(schema: serde_json::Value) -> Result<Self> {
+        Self::new(schema)
+    }
+}
+
+impl From<MorpheusSchema> for serde_json::Value {
+    fn from(schema: MorpheusSchema) -> Self {
+        schema.0
+    }
+}
+
+impl MorpheusSchema {
+    pub fn new(schema: serde_json::Value) -> Result<Self> {
+        let this = Self(schema);
+        this.compile(&mut json_schema::Scope::new())?;
+        Ok(this)
+    }
+
+    pub fn to_value(&self) -> &serde_json::Value {
+        &self.0
+    }
+
+    pub fn validate(&self, value: &MorpheusValue) -> Result<()> {
+        let mut scope = json_schema::Scope::new();
+        let schema = self.compile(&mut scope)?;
+        let state = schema.validate(value);
+        if !state.is_strictly_valid() {
+            let errors = state
+                .errors
+                .iter()
+                .map(|e| format!("{} at '{}'", e.get_title(), e.get_path()))
+                .collect::<Vec<_>>();
+            bail!("Data does not match schema {}: {}", self.content_id()?, errors.join(", "));
+        }
+        Ok(())
+    }
+
+    fn compile<'a>(
+        &self, scope: &'a mut json_schema::Scope,
+    ) -> Result<json_schema::schema::ScopedSchema<'a>> {
+        scope
+            .compile_and_return(self.0.clone(), true)
+            .map_err(|e| anyhow!("Invalid JSON schema: {:?}", e))
+    }
+}
+
+/// Indexes schemas by their content id, so they can be used as a [`SchemaResolver`]
+pub fn schemas_by_id(
+    schemas: impl IntoIterator<Item = MorpheusSchema>,
+) -> Result<HashMap<SchemaId, MorpheusSchema>> {
+    schemas.into_iter().map(|schema| Ok((schema.content_id()?, schema))).collect()
+}
+
+/// Provides the schemas referred to by processes.
+pub trait SchemaResolver {
+    fn resolve(&self, id: &str) -> Result<MorpheusSchema>;
+}
+
+/// Schemas keyed by their content id, e.g. fetched from a content store before validation
+impl SchemaResolver for HashMap<SchemaId, MorpheusSchema> {
+    fn resolve(&self, id: &str) -> Result<MorpheusSchema> {
+        let schema = self.get(id).ok_or_else(|| anyhow!("Schema {} is not known", id))?;
+        ensure!(schema.content_id()? == id, "Schema {} does not match its content id", id);
+        Ok(schema.to_owned())
+    }
+}
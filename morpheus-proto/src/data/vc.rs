@@ -0,0 +1,377 @@
+//! Mapping of signed witness statements and claim presentations to the
+//! [W3C Verifiable Credentials Data Model v2.0](https://www.w3.org/TR/vc-data-model-2.0/).
+//!
+//! The VCs and VPs carry everything needed to restore the original Morpheus objects, so the
+//! multicipher signature in their proof section can still be verified on the content id of the
+//! restored statement or presentation.
+
+use super::*;
+
+use crate::crypto::sign::{Signable, Signed};
+
+pub const VC_CONTEXT: &str = "https://www.w3.org/ns/credentials/v2";
+pub const VC_TYPE: &str = "VerifiableCredential";
+pub const VP_TYPE: &str = "VerifiablePresentation";
+pub const MORPHEUS_VC_TYPE: &str = "MorpheusWitnessStatement";
+pub const MORPHEUS_VP_TYPE: &str = "MorpheusClaimPresentation";
+pub const MORPHEUS_PROCESS_SCHEMA_TYPE: &str = "MorpheusProcess";
+pub const MORPHEUS_PROOF_TYPE: &str = "MorpheusMulticipherSignature";
+
+/// Proof section carrying a multicipher signature on the content id of the Morpheus object
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VcProof {
+    #[serde(rename = "type")]
+    pub type_: String,
+    /// Either `assertionMethod` for credentials or `authentication` for presentations
+    pub proof_purpose: String,
+    #[serde(with = "serde_str")]
+    pub verification_method: MPublicKey,
+    #[serde(with = "serde_str")]
+    pub proof_value: MSignature,
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub nonce: Option<Nonce264>,
+}
+
+impl VcProof {
+    fn new<T: Signable>(signed: &Signed<T>, proof_purpose: &str) -> (Self, T) {
+        let (public_key, content, signature, nonce) = signed.to_owned().into_parts();
+        let proof = Self {
+            type_: MORPHEUS_PROOF_TYPE.to_owned(),
+            proof_purpose: proof_purpose.to_owned(),
+            verification_method: public_key,
+            proof_value: signature,
+            nonce,
+        };
+        (proof, content)
+    }
+
+    fn sign<T: Signable>(&self, content: T, proof_purpose: &str) -> Result<Signed<T>> {
+        ensure!(self.type_ == MORPHEUS_PROOF_TYPE, "Unsupported proof type {}", self.type_);
+        ensure!(
+            self.proof_purpose == proof_purpose,
+            "Proof purpose must be {}, but it was {}",
+            proof_purpose,
+            self.proof_purpose
+        );
+        Ok(Signed::from_parts(
+            self.verification_method.to_owned(),
+            content,
+            self.proof_value.to_owned(),
+            self.nonce.to_owned(),
+        ))
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VcSubject {
+    #[serde(with = "serde_str")]
+    pub id: Did,
+    pub content: MorpheusValue,
+}
+
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VcSchema {
+    /// The process the statement was witnessed in
+    pub id: ProcessId,
+    #[serde(rename = "type")]
+    pub type_: String,
+}
+
+/// Constraints of the witness statement apart from the authority, which is the issuer of the VC
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VcConstraints {
+    pub after: Option<String>,
+    pub before: Option<String>,
+    pub witness: String,
+    pub content: MorpheusValue,
+}
+
+/// A [`WitnessStatement`] signed by a witness on behalf of the authority in the VC data model
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VerifiableCredential {
+    #[serde(rename = "@context")]
+    pub context: Vec<String>,
+    #[serde(rename = "type")]
+    pub type_: Vec<String>,
+    #[serde(with = "serde_str")]
+    pub issuer: Did,
+    pub credential_subject: VcSubject,
+    pub credential_schema: VcSchema,
+    pub constraints: VcConstraints,
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub nonce: Option<Nonce264>,
+    pub proof: VcProof,
+}
+
+impl VerifiableCredential {
+    const PROOF_PURPOSE: &'static str = "assertionMethod";
+
+    pub fn from_signed(signed: &Signed<WitnessStatement>) -> Self {
+        let (proof, statement) = VcProof::new(signed, Self::PROOF_PURPOSE);
+        let WitnessStatement { process_id, claim, constraints, nonce } = statement;
+        Self {
+            context: vec![VC_CONTEXT.to_owned()],
+            type_: vec![VC_TYPE.to_owned(), MORPHEUS_VC_TYPE.to_owned()],
+            issuer: constraints.authority,
+            credential_subject: VcSubject { id: claim.subject, content: claim.content },
+            credential_schema: VcSchema {
+                id: process_id,
+                type_: MORPHEUS_PROCESS_SCHEMA_TYPE.to_owned(),
+            },
+            constraints: VcConstraints {
+                after: constraints.after,
+                before: constraints.before,
+                witness: constraints.witness,
+                content: constraints.content,
+            },
+            nonce,
+            proof,
+        }
+    }
+
+    /// Statements in a [`ProvenClaim`] are plain JSON, possibly with some parts masked. Parts
+    /// mapped to VC properties must not be masked, otherwise the statement cannot be restored.
+    pub fn from_signed_json(signed: &Signed<serde_json::Value>) -> Result<Self> {
+        let (public_key, content, signature, nonce) = signed.to_owned().into_parts();
+        let statement: WitnessStatement = serde_json::from_value(content.clone())
+            .map_err(|e| anyhow!("Statement cannot be mapped to a VC: {}", e))?;
+        ensure!(
+            serde_json::to_value(&statement)? == content,
+            "Statement has properties that cannot be mapped to a VC"
+        );
+        let signed = Signed::from_parts(public_key, statement, signature, nonce);
+        Ok(Self::from_signed(&signed))
+    }
+
+    /// Restores the signed witness statement without checking the signature.
+    pub fn to_signed(&self) -> Result<Signed<WitnessStatement>> {
+        ensure_types(&self.context, &self.type_, VC_TYPE, MORPHEUS_VC_TYPE)?;
+        ensure!(
+            self.credential_schema.type_ == MORPHEUS_PROCESS_SCHEMA_TYPE,
+            "Unsupported credential schema type {}",
+            self.credential_schema.type_
+        );
+        let statement = WitnessStatement {
+            process_id: self.credential_schema.id.to_owned(),
+            claim: Claim {
+                subject: self.credential_subject.id.to_owned(),
+                content: self.credential_subject.content.to_owned(),
+            },
+            constraints: Constraints {
+                after: self.constraints.after.to_owned(),
+                before: self.constraints.before.to_owned(),
+                witness: self.constraints.witness.to_owned(),
+                authority: self.issuer.to_owned(),
+                content: self.constraints.content.to_owned(),
+            },
+            nonce: self.nonce.to_owned(),
+        };
+        self.proof.sign(statement, Self::PROOF_PURPOSE)
+    }
+
+    /// Restores the signed witness statement and checks its signature. Whether the signer had the
+    /// right to sign on behalf of the issuer can be checked with
+    /// [`Signed::validate_with_did_doc`] on the result.
+    pub fn verify(&self) -> Result<Signed<WitnessStatement>> {
+        let signed = self.to_signed()?;
+        ensure!(signed.validate(), "Signature of the credential is invalid");
+        Ok(signed)
+    }
+
+    fn to_signed_json(&self) -> Result<Signed<serde_json::Value>> {
+        let (public_key, statement, signature, nonce) = self.verify()?.into_parts();
+        let content = serde_json::to_value(&statement)?;
+        Ok(Signed::from_parts(public_key, content, signature, nonce))
+    }
+}
+
+/// A claim of the presentation with the credentials proving it
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VpClaim {
+    pub claim: serde_json::Value,
+    /// Indexes into `verifiableCredential` of the presentation
+    pub credentials: Vec<usize>,
+}
+
+/// A [`ClaimPresentation`] signed by its presenter in the VC data model
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VerifiablePresentation {
+    #[serde(rename = "@context")]
+    pub context: Vec<String>,
+    #[serde(rename = "type")]
+    pub type_: Vec<String>,
+    pub verifiable_credential: Vec<VerifiableCredential>,
+    pub claims: Vec<VpClaim>,
+    pub licenses: Vec<Signed<License>>,
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub nonce: Option<Nonce264>,
+    pub proof: VcProof,
+}
+
+impl VerifiablePresentation {
+    const PROOF_PURPOSE: &'static str = "authentication";
+
+    pub fn from_signed(signed: &Signed<ClaimPresentation>) -> Result<Self> {
+        let (proof, presentation) = VcProof::new(signed, Self::PROOF_PURPOSE);
+        let mut verifiable_credential = vec![];
+        let mut claims = vec![];
+        for proven in presentation.proven_claims {
+            let mut credentials = vec![];
+            for statement in &proven.statements {
+                credentials.push(verifiable_credential.len());
+                verifiable_credential.push(VerifiableCredential::from_signed_json(statement)?);
+            }
+            claims.push(VpClaim { claim: proven.claim, credentials });
+        }
+        Ok(Self {
+            context: vec![VC_CONTEXT.to_owned()],
+            type_: vec![VP_TYPE.to_owned(), MORPHEUS_VP_TYPE.to_owned()],
+            verifiable_credential,
+            claims,
+            licenses: presentation.licenses,
+            nonce: presentation.nonce,
+            proof,
+        })
+    }
+
+    /// Restores the signed claim presentation and checks the signatures of the presentation and
+    /// all credentials in it.
+    pub fn verify(&self) -> Result<Signed<ClaimPresentation>> {
+        ensure_types(&self.context, &self.type_, VP_TYPE, MORPHEUS_VP_TYPE)?;
+        let mut proven_claims = vec![];
+        for claim in &self.claims {
+            let statements = claim
+                .credentials
+                .iter()
+                .map(|&idx| {
+                    let vc = self
+                        .verifiable_credential
+                        .get(idx)
+                        .ok_or_else(|| anyhow!("Credential {} is not in the presentation", idx))?;
+                    vc.to_signed_json()
+                })
+                .collect::<Result<Vec<_>>>()?;
+            proven_claims.push(ProvenClaim { claim: claim.claim.to_owned(), statements });
+        }
+        let presentation = ClaimPresentation {
+            proven_claims,
+            licenses: self.licenses.to_owned(),
+            nonce: self.nonce.to_owned(),
+        };
+        let signed = self.proof.sign(presentation, Self::PROOF_PURPOSE)?;
+        ensure!(signed.validate(), "Signature of the presentation is invalid");
+        Ok(signed)
+    }
+}
+
+fn ensure_types(
+    context: &[String], types: &[String], base_type: &str, morpheus_type: &str,
+) -> Result<()> {
+    ensure!(context.first().map(|c| c.as_str()) == Some(VC_CONTEXT), "Missing {}", VC_CONTEXT);
+    ensure!(
+        types.iter().any(|t| t == base_type) && types.iter().any(|t| t == morpheus_type),
+        "Type must include {} and {}",
+        base_type,
+        morpheus_type
+    );
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use crate::{
+        crypto::{hash::Content, sign::SyncMorpheusSigner},
+        test_helpers::signer,
+    };
+
+    fn statement() -> WitnessStatement {
+        let subject: Did = "did:morpheus:ezbeWGSY2dqcUBqT8K7R14xr".parse().unwrap();
+        let authority: Did = "did:morpheus:ezqztJ6XX6GDxdSgdiySiT3J".parse().unwrap();
+        WitnessStatement {
+            process_id: "cjunI8lB1BzsO8T6v4Ay1FwWMbO6enWQRqbBWb4lVlW6l8".to_owned(),
+            claim: Claim {
+                subject,
+                content: serde_json::json!({ "name": "Jane Doe", "age": "42" }),
+            },
+            constraints: Constraints {
+                after: None,
+                before: Some("cjuN4oa1tj2zaRp1ZTAJA6dSu8pHC9O8vxm7m2yPoYr8zc".to_owned()),
+                witness: "iezqztJ6XX6GDxdSgdiySiT3J".to_owned(),
+                authority,
+                content: serde_json::json!({}),
+            },
+            nonce: Some(Nonce264::generate()),
+        }
+    }
+
+    #[test]
+    fn credential() -> Result<()> {
+        let (_, witness) = signer("witness");
+        let signed = witness.sign_witness_statement(statement())?;
+        let vc = VerifiableCredential::from_signed(&signed);
+
+        let json = serde_json::to_value(&vc)?;
+        assert_eq!(json["type"], serde_json::json!(["VerifiableCredential", MORPHEUS_VC_TYPE]));
+        assert_eq!(json["issuer"], "did:morpheus:ezqztJ6XX6GDxdSgdiySiT3J");
+        assert_eq!(json["credentialSubject"]["id"], "did:morpheus:ezbeWGSY2dqcUBqT8K7R14xr");
+        assert_eq!(json["proof"]["proofValue"], signed.signature().to_string());
+
+        let parsed: VerifiableCredential = serde_json::from_value(json)?;
+        let restored = parsed.verify()?;
+        assert_eq!(restored.content().content_id()?, signed.content().content_id()?);
+        assert_eq!(restored.signature(), signed.signature());
+
+        let mut forged = vc.clone();
+        forged.credential_subject.content["age"] = serde_json::json!("24");
+        assert!(forged.to_signed().is_ok());
+        assert!(forged.verify().is_err());
+
+        let mut wrong_type = vc;
+        wrong_type.type_.pop();
+        assert!(wrong_type.verify().is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn presentation() -> Result<()> {
+        let (_, witness) = signer("witness");
+        let signed_statement = witness.sign_witness_statement(statement())?;
+        let (public_key, statement, signature, nonce) = signed_statement.into_parts();
+        let mut statement = serde_json::to_value(&statement)?;
+        statement["claim"]["content"]["age"] =
+            serde_json::json!(digest_data(&serde_json::json!("42"))?);
+        let masked = Signed::from_parts(public_key, statement, signature, nonce);
+        assert!(masked.validate());
+
+        let claim = masked.content()["claim"].clone();
+        let presentation = ClaimPresentation {
+            proven_claims: vec![ProvenClaim { claim, statements: vec![masked] }],
+            licenses: vec![],
+            nonce: Some(Nonce264::generate()),
+        };
+        let (_, holder) = signer("holder");
+        let signed = holder.sign_claim_presentation(presentation)?;
+
+        let vp = VerifiablePresentation::from_signed(&signed)?;
+        assert_eq!(vp.verifiable_credential.len(), 1);
+        assert_eq!(vp.claims[0].credentials, vec![0]);
+        let parsed: VerifiablePresentation = serde_json::from_str(&serde_json::to_string(&vp)?)?;
+        assert_eq!(parsed.verify()?, signed);
+
+        let mut forged = vp;
+        forged.verifiable_credential[0].constraints.witness = "iez25N5WZ1Q6TQpgpyYgiu9gTX".into();
+        assert!(forged.verify().is_err());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,258 @@
+//! Conversion of [`DidDocument`] into the [W3C DID Core](https://www.w3.org/TR/did-core/) JSON-LD
+//! representation, so generic DID resolvers can consume `did:morpheus` DIDs.
+
+use super::*;
+
+pub const W3C_DID_CONTEXT: &str = "https://www.w3.org/ns/did/v1";
+pub const W3C_MULTIKEY_CONTEXT: &str = "https://w3id.org/security/multikey/v1";
+pub const W3C_DID_CONTENT_TYPE: &str = "application/did+ld+json";
+
+/// Verification method type for keys that are known only by their multicipher key id
+pub const MORPHEUS_KEY_ID_TYPE: &str = "MorpheusKeyId";
+
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct W3cVerificationMethod {
+    pub id: String,
+    #[serde(rename = "type")]
+    pub type_: String,
+    pub controller: String,
+    /// Present if the public key is known
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub public_key_multibase: Option<String>,
+    /// Present instead of the public key if only the key id was added to the DID document
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub morpheus_key_id: Option<String>,
+}
+
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct W3cService {
+    pub id: String,
+    #[serde(rename = "type")]
+    pub type_: String,
+    pub service_endpoint: String,
+}
+
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct W3cDidDocument {
+    #[serde(rename = "@context")]
+    pub context: Vec<String>,
+    pub id: String,
+    /// DIDs the update right was delegated to
+    #[serde(skip_serializing_if = "Vec::is_empty", default)]
+    pub controller: Vec<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty", default)]
+    pub verification_method: Vec<W3cVerificationMethod>,
+    #[serde(skip_serializing_if = "Vec::is_empty", default)]
+    pub authentication: Vec<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty", default)]
+    pub capability_invocation: Vec<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty", default)]
+    pub service: Vec<W3cService>,
+}
+
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct W3cDidDocumentMetadata {
+    pub deactivated: bool,
+    /// The block height the document was queried at
+    pub version_id: String,
+}
+
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct W3cDidResolutionMetadata {
+    pub content_type: String,
+}
+
+/// The result of DID resolution in the shape universal-resolver drivers return it
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct W3cDidResolution {
+    pub did_document: W3cDidDocument,
+    pub did_document_metadata: W3cDidDocumentMetadata,
+    pub did_resolution_metadata: W3cDidResolutionMetadata,
+}
+
+impl DidDocument {
+    /// The DID document in the W3C DID Core representation at the height it was queried at. Only
+    /// keys valid at that height are listed. Keys having the impersonation right are referenced
+    /// from `authentication`, while keys having the update right are referenced from
+    /// `capabilityInvocation`. DIDs the update right was delegated to are listed as controllers.
+    /// A tombstoned DID has no verification methods.
+    pub fn to_w3c(&self) -> Result<W3cDidDocument> {
+        let did = self.did.to_string();
+        let mut doc = W3cDidDocument {
+            context: vec![W3C_DID_CONTEXT.to_owned(), W3C_MULTIKEY_CONTEXT.to_owned()],
+            id: did.to_owned(),
+            controller: vec![],
+            verification_method: vec![],
+            authentication: vec![],
+            capability_invocation: vec![],
+            service: vec![],
+        };
+        if self.is_tombstoned_at(self.queried_at_height)? {
+            return Ok(doc);
+        }
+
+        for key in self.keys.iter().filter(|key| key.derived.valid) {
+            let (type_, public_key_multibase, morpheus_key_id) = match &key.state.authentication {
+                Authentication::PublicKey(pk) => ("Multikey", Some(pk.to_multikey()), None),
+                Authentication::KeyId(id) => (MORPHEUS_KEY_ID_TYPE, None, Some(id.to_string())),
+                Authentication::Did(_) => continue,
+            };
+            doc.verification_method.push(W3cVerificationMethod {
+                id: format!("{}#{}", did, key.derived.index),
+                type_: type_.to_owned(),
+                controller: did.to_owned(),
+                public_key_multibase,
+                morpheus_key_id,
+            });
+        }
+        doc.authentication = self.w3c_methods_with(Right::Impersonation);
+        doc.capability_invocation = self.w3c_methods_with(Right::Update);
+        let controllers = self.delegates_at(Right::Update, self.queried_at_height)?;
+        doc.controller = controllers.iter().map(|did| did.to_string()).collect();
+
+        for service in &self.services {
+            doc.service.push(W3cService {
+                id: format!("{}#{}", did, service.name),
+                type_: service.type_.to_string(),
+                service_endpoint: service.service_endpoint.to_owned(),
+            });
+        }
+        Ok(doc)
+    }
+
+    /// The W3C DID resolution result including the document metadata
+    pub fn to_w3c_resolution(&self) -> Result<W3cDidResolution> {
+        Ok(W3cDidResolution {
+            did_document: self.to_w3c()?,
+            did_document_metadata: W3cDidDocumentMetadata {
+                deactivated: self.is_tombstoned_at(self.queried_at_height)?,
+                version_id: self.queried_at_height.to_string(),
+            },
+            did_resolution_metadata: W3cDidResolutionMetadata {
+                content_type: W3C_DID_CONTENT_TYPE.to_owned(),
+            },
+        })
+    }
+
+    fn w3c_methods_with(&self, right: Right) -> Vec<String> {
+        let valid_keys = self
+            .keys
+            .iter()
+            .filter(|key| {
+                key.derived.valid && !matches!(key.state.authentication, Authentication::Did(_))
+            })
+            .map(|key| key.derived.index);
+        let links = valid_keys.map(|index| format!("#{}", index)).collect::<Vec<_>>();
+        let history = self.rights.get(&right).map(|h| h.as_slice()).unwrap_or_default();
+        history
+            .iter()
+            .filter(|right| right.derived.valid && links.contains(&right.derived.key_link))
+            .map(|right| format!("{}{}", self.did, right.derived.key_link))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use iop_keyvault::multicipher::MPublicKey;
+
+    fn doc_json(tombstoned_at_height: Option<BlockHeight>) -> serde_json::Value {
+        serde_json::json!({
+            "did": "did:morpheus:ezqztJ6XX6GDxdSgdiySiT3J",
+            "keys": [
+                {
+                    "auth": "iezqztJ6XX6GDxdSgdiySiT3J",
+                    "validFromHeight": null,
+                    "validUntilHeight": null,
+                    "index": 0,
+                    "valid": true
+                },
+                {
+                    "auth": "pezDj6ea4tVfNRUTMyssVDepAAzPW67Fe3yHtuHL6ZNtcfJ",
+                    "validFromHeight": 5,
+                    "validUntilHeight": null,
+                    "index": 1,
+                    "valid": true
+                },
+                {
+                    "auth": "pez2CLkBUjHB8w8G87D3YkREjpRuiqPu6BrRsgHMQy2Pzt6",
+                    "validFromHeight": 5,
+                    "validUntilHeight": 8,
+                    "index": 2,
+                    "valid": false
+                }
+            ],
+            "rights": {
+                "impersonate": [
+                    { "history": [{ "height": null, "valid": true }], "keyLink": "#0", "valid": true },
+                    { "history": [{ "height": 5, "valid": true }], "keyLink": "#1", "valid": true },
+                    { "history": [{ "height": 5, "valid": true }], "keyLink": "#2", "valid": true }
+                ],
+                "update": [
+                    { "history": [{ "height": null, "valid": true }], "keyLink": "#0", "valid": true },
+                    { "history": [], "keyLink": "#1", "valid": false },
+                    { "history": [], "keyLink": "#2", "valid": false }
+                ]
+            },
+            "tombstonedAtHeight": tombstoned_at_height,
+            "tombstoned": tombstoned_at_height.is_some(),
+            "queriedAtHeight": 10
+        })
+    }
+
+    #[test]
+    fn w3c() -> Result<()> {
+        let doc: DidDocument = serde_json::from_value(doc_json(None))?;
+        let pk: MPublicKey = "pezDj6ea4tVfNRUTMyssVDepAAzPW67Fe3yHtuHL6ZNtcfJ".parse()?;
+        let did = "did:morpheus:ezqztJ6XX6GDxdSgdiySiT3J";
+
+        let resolution = doc.to_w3c_resolution()?;
+        assert!(!resolution.did_document_metadata.deactivated);
+        assert_eq!(resolution.did_document_metadata.version_id, "10");
+        assert_eq!(
+            serde_json::to_value(&resolution.did_document)?,
+            serde_json::json!({
+                "@context": [W3C_DID_CONTEXT, W3C_MULTIKEY_CONTEXT],
+                "id": did,
+                "verificationMethod": [
+                    {
+                        "id": format!("{}#0", did),
+                        "type": "MorpheusKeyId",
+                        "controller": did,
+                        "morpheusKeyId": "iezqztJ6XX6GDxdSgdiySiT3J"
+                    },
+                    {
+                        "id": format!("{}#1", did),
+                        "type": "Multikey",
+                        "controller": did,
+                        "publicKeyMultibase": pk.to_multikey()
+                    }
+                ],
+                "authentication": [format!("{}#0", did), format!("{}#1", did)],
+                "capabilityInvocation": [format!("{}#0", did)]
+            })
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn tombstoned() -> Result<()> {
+        let doc: DidDocument = serde_json::from_value(doc_json(Some(9)))?;
+
+        let resolution = doc.to_w3c_resolution()?;
+        assert!(resolution.did_document_metadata.deactivated);
+        assert!(resolution.did_document.verification_method.is_empty());
+        assert!(resolution.did_document.authentication.is_empty());
+
+        Ok(())
+    }
+}
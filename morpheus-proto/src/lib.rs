@@ -1,10 +1,15 @@
 pub mod crypto;
 pub mod data;
+pub mod login;
 pub mod txtype;
 
+#[cfg(test)]
+mod test_helpers;
+
 use std::ops::Deref;
 
-use anyhow::{anyhow, bail, ensure, Result};
+use anyhow::{anyhow, bail, ensure, Context, Result};
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
 use iop_journal_proto::{serializer, BlockHeight};
@@ -0,0 +1,256 @@
+//! Challenge-response protocol to sign in to a service with a DID.
+//!
+//! 1. The service creates a [`LoginChallenge`] with its [`LoginVerifier`] and sends it to the wallet.
+//! 2. The wallet signs a [`LoginResponse`] naming the DID it logs in with, e.g. using
+//!    [`SyncMorpheusSigner::sign_login_response`].
+//! 3. The service resolves the DID document at the current height and calls
+//!    [`LoginVerifier::verify`], which checks that the signer key had the impersonation right and
+//!    returns a session token.
+
+use std::collections::HashMap;
+
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+
+use iop_keyvault::{
+    multicipher::{MPrivateKey, MPublicKey},
+    PrivateKey as _,
+};
+
+use super::*;
+use crate::crypto::{
+    hash::Content,
+    jwt::{JwtBuilder, JwtParser, JwtValidation},
+    sign::{Signable, Signed},
+};
+use crate::data::{Authentication, Did, DidDocument, Right};
+
+/// Sent by the service to the wallet. Signing it proves the control of a key at the time of the
+/// login, and the audience prevents relaying the response to another service.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LoginChallenge {
+    pub nonce: Nonce264,
+    /// Identifies the service, e.g. its domain name
+    pub audience: String,
+    /// Unix timestamp after which the challenge cannot be answered
+    pub valid_until: i64,
+}
+
+impl Content for LoginChallenge {}
+
+/// Signed by the wallet to answer a [`LoginChallenge`]
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LoginResponse {
+    #[serde(with = "serde_str")]
+    pub did: Did,
+    pub challenge: LoginChallenge,
+}
+
+impl LoginResponse {
+    pub fn new(did: Did, challenge: LoginChallenge) -> Self {
+        Self { did, challenge }
+    }
+}
+
+impl Content for LoginResponse {}
+impl Signable for LoginResponse {}
+
+/// Service side of the protocol. It remembers challenges it issued, so each of them can be
+/// answered only once. Entries are forgotten after the challenge expires.
+pub struct LoginVerifier {
+    audience: String,
+    session_key: MPrivateKey,
+    challenge_ttl: Duration,
+    session_ttl: Duration,
+    // nonce -> challenge, or None after it was used
+    challenges: HashMap<String, (i64, Option<LoginChallenge>)>,
+}
+
+impl LoginVerifier {
+    /// Session tokens are signed with `session_key`, so only the service can issue them.
+    pub fn new(audience: impl ToString, session_key: MPrivateKey) -> Self {
+        Self {
+            audience: audience.to_string(),
+            session_key,
+            challenge_ttl: Duration::minutes(5),
+            session_ttl: Duration::hours(1),
+            challenges: Default::default(),
+        }
+    }
+
+    pub fn with_challenge_ttl(self, challenge_ttl: Duration) -> Self {
+        Self { challenge_ttl, ..self }
+    }
+
+    pub fn with_session_ttl(self, session_ttl: Duration) -> Self {
+        Self { session_ttl, ..self }
+    }
+
+    pub fn audience(&self) -> &str {
+        &self.audience
+    }
+
+    pub fn session_public_key(&self) -> MPublicKey {
+        self.session_key.public_key()
+    }
+
+    pub fn create_challenge(&mut self, current_time: Option<DateTime<Utc>>) -> LoginChallenge {
+        let now = current_time.unwrap_or_else(Utc::now);
+        self.forget_expired(now);
+
+        let valid_until = (now + self.challenge_ttl).timestamp();
+        let challenge = LoginChallenge {
+            nonce: Nonce264::generate(),
+            audience: self.audience.to_owned(),
+            valid_until,
+        };
+        self.challenges
+            .insert(challenge.nonce.0.to_owned(), (valid_until, Some(challenge.clone())));
+        challenge
+    }
+
+    /// Checks the signed response against the DID document queried at the current height and
+    /// returns a session token for the DID. The challenge is consumed even if the check fails, so
+    /// the wallet has to ask for a new one.
+    pub fn verify(
+        &mut self, response: &Signed<LoginResponse>, did_doc: &DidDocument,
+        current_time: Option<DateTime<Utc>>,
+    ) -> Result<String> {
+        let now = current_time.unwrap_or_else(Utc::now);
+        self.forget_expired(now);
+
+        let content = response.content();
+        let nonce = &content.challenge.nonce.0;
+        let issued = match self.challenges.get_mut(nonce) {
+            Some((_, issued)) => {
+                issued.take().ok_or_else(|| anyhow!("Challenge {} was already used", nonce))?
+            }
+            None => bail!("Challenge {} was not issued or expired", nonce),
+        };
+        ensure!(issued == content.challenge, "Challenge {} was tampered with", nonce);
+        ensure!(now.timestamp() <= issued.valid_until, "Challenge {} expired", nonce);
+        ensure!(response.validate(), "Signature of the login response is invalid");
+        ensure!(
+            did_doc.did == content.did,
+            "DID document of {} was given for {}",
+            did_doc.did,
+            content.did
+        );
+
+        let auth = Authentication::PublicKey(response.public_key().to_owned());
+        let height = did_doc.queried_at_height;
+        ensure!(
+            did_doc.has_right_at(&auth, Right::Impersonation, height)?,
+            "{} has no right to impersonate {} at height {}",
+            auth,
+            content.did,
+            height
+        );
+
+        let mut builder = JwtBuilder::new();
+        builder.created_at = now;
+        builder.time_to_live = self.session_ttl;
+        builder.subject = Some(content.did.to_owned());
+        builder.audience = vec![self.audience.to_owned()];
+        builder.nonce = Some(nonce.to_owned());
+        builder.sign(&self.session_key)
+    }
+
+    /// Checks a session token issued by this verifier and returns the DID logged in.
+    pub fn validate_session(
+        &self, token: &str, current_time: Option<DateTime<Utc>>,
+    ) -> Result<Did> {
+        let validation = JwtValidation {
+            audience: Some(self.audience.to_owned()),
+            public_key: Some(self.session_public_key()),
+            ..Default::default()
+        };
+        let parser = JwtParser::validate_with(token, &validation, current_time)?;
+        parser.subject_did()?.ok_or_else(|| anyhow!("Session token has no subject"))
+    }
+
+    fn forget_expired(&mut self, now: DateTime<Utc>) {
+        let now = now.timestamp();
+        self.challenges.retain(|_, (valid_until, _)| now <= *valid_until);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use crate::{
+        crypto::sign::{PrivateKeySigner, SyncMorpheusSigner},
+        test_helpers::{impersonating_doc, private_key as key, signer},
+    };
+
+    const HEIGHT: BlockHeight = 42;
+
+    fn service() -> LoginVerifier {
+        LoginVerifier::new("https://service.example.com", key("service"))
+    }
+
+    #[test]
+    fn login() -> Result<()> {
+        let mut verifier = service();
+        let (did, signer) = signer("wallet");
+        let doc = impersonating_doc(&did, HEIGHT);
+
+        let challenge = verifier.create_challenge(None);
+        assert_eq!(challenge.audience, "https://service.example.com");
+        let response = signer.sign_login_response(LoginResponse::new(did.clone(), challenge))?;
+
+        let token = verifier.verify(&response, &doc, None)?;
+        assert_eq!(verifier.validate_session(&token, None)?, did);
+
+        let err = verifier.verify(&response, &doc, None).unwrap_err();
+        assert!(err.to_string().contains("already used"));
+
+        let other = LoginVerifier::new("https://other.example.com", key("service"));
+        assert!(other.validate_session(&token, None).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn rejected() -> Result<()> {
+        let mut verifier = service();
+        let (did, signer) = signer("wallet");
+
+        // Key has no impersonation right
+        let challenge = verifier.create_challenge(None);
+        let response = signer.sign_login_response(LoginResponse::new(did.clone(), challenge))?;
+        let mut doc = DidDocument::implicit(&did);
+        doc.queried_at_height = HEIGHT;
+        assert!(verifier.verify(&response, &doc, None).is_err());
+
+        // Challenge not issued by this verifier
+        let challenge = service().create_challenge(None);
+        let response = signer.sign_login_response(LoginResponse::new(did.clone(), challenge))?;
+        let doc = impersonating_doc(&did, HEIGHT);
+        assert!(verifier.verify(&response, &doc, None).is_err());
+
+        // Expired challenge
+        let challenge = verifier.create_challenge(None);
+        let response = signer.sign_login_response(LoginResponse::new(did.clone(), challenge))?;
+        let later = Utc::now() + Duration::minutes(6);
+        assert!(verifier.verify(&response, &doc, Some(later)).is_err());
+
+        // Signed by a different key than the one in the DID document
+        let challenge = verifier.create_challenge(None);
+        let intruder = PrivateKeySigner::new(key("intruder"));
+        let response = intruder.sign_login_response(LoginResponse::new(did.clone(), challenge))?;
+        assert!(verifier.verify(&response, &doc, None).is_err());
+
+        // Extended validity of the challenge
+        let mut challenge = verifier.create_challenge(None);
+        challenge.valid_until += 3600;
+        let response = signer.sign_login_response(LoginResponse::new(did, challenge))?;
+        let err = verifier.verify(&response, &doc, None).unwrap_err();
+        assert!(err.to_string().contains("tampered"));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,34 @@
+//! Keys and DID documents shared by the tests of this crate.
+
+use super::*;
+
+use crate::crypto::sign::PrivateKeySigner;
+use crate::data::{
+    Did, DidDocument, KeyRightDerived, KeyRightHistory, KeyRightHistoryItem, KeyRightState, Right,
+};
+use iop_keyvault::{multicipher::MPrivateKey, secp256k1::SecpPrivateKey, PrivateKey as _};
+
+/// A secp256k1 key derived from the given passphrase the same way as Ark does.
+pub(crate) fn private_key(phrase: &str) -> MPrivateKey {
+    MPrivateKey::from(SecpPrivateKey::from_ark_passphrase(phrase).unwrap())
+}
+
+/// The default DID of the key derived from the passphrase with a signer using that key.
+pub(crate) fn signer(phrase: &str) -> (Did, PrivateKeySigner) {
+    let sk = private_key(phrase);
+    (Did::from(sk.public_key().key_id()), PrivateKeySigner::new(sk))
+}
+
+/// The implicit document of the DID queried at the given height, where the default key has always
+/// had the right to impersonate the DID.
+pub(crate) fn impersonating_doc(did: &Did, height: BlockHeight) -> DidDocument {
+    let history = vec![KeyRightHistoryItem { height: None, valid: true, timestamp: None }];
+    let impersonate = KeyRightHistory {
+        state: KeyRightState { history },
+        derived: KeyRightDerived { key_link: "#0".to_owned(), valid: true },
+    };
+    let mut doc = DidDocument::implicit(did);
+    doc.queried_at_height = height;
+    doc.rights.insert(Right::Impersonation, vec![impersonate]);
+    doc
+}
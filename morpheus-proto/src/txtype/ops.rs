@@ -46,6 +46,7 @@ impl SignableOperation {
             signables: self.signables,
             signer_public_key: signed_with_pubkey.to_string(),
             signature: signature.to_string(),
+            cosignatures: vec![],
         })
     }
 }
@@ -57,6 +58,16 @@ pub struct SignedOperation {
     signables: Vec<SignableOperationAttempt>,
     pub signer_public_key: String,
     pub signature: String,
+    /// Signatures of further keys on the same operations, needed for DIDs with an update threshold
+    #[serde(skip_serializing_if = "Vec::is_empty", default)]
+    pub cosignatures: Vec<Cosignature>,
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Cosignature {
+    pub signer_public_key: String,
+    pub signature: String,
 }
 
 impl SignedOperation {
@@ -67,12 +78,41 @@ impl SignedOperation {
     }
 
     pub fn attempts(&self) -> Result<std::slice::Iter<'_, SignableOperationAttempt>> {
-        let bytes = SignableOperation::to_signable_bytes(&self.signables)?;
-        let signer: MPublicKey = self.signer_public_key.parse()?;
-        let signature: MSignature = self.signature.parse()?;
-        ensure!(signer.verify(&bytes, &signature), "Invalid signature");
+        self.signers()?;
         Ok(self.attempts_unsafe_without_signature_checking())
     }
+
+    /// Adds the signature of another key on the same operations
+    pub fn cosign(&mut self, signer: &dyn SyncMorpheusSigner) -> Result<()> {
+        let bytes = SignableOperation::to_signable_bytes(&self.signables)?;
+        let (public_key, signature) = signer.sign(&bytes)?;
+        let signer_public_key = public_key.to_string();
+        ensure!(
+            signer_public_key != self.signer_public_key
+                && self.cosignatures.iter().all(|c| c.signer_public_key != signer_public_key),
+            "Operations were already signed by {}",
+            signer_public_key
+        );
+        self.cosignatures.push(Cosignature { signer_public_key, signature: signature.to_string() });
+        Ok(())
+    }
+
+    /// Public keys of the signer and the cosigners in order, after checking all signatures
+    pub fn signers(&self) -> Result<Vec<MPublicKey>> {
+        let bytes = SignableOperation::to_signable_bytes(&self.signables)?;
+        let cosignatures = self.cosignatures.iter().map(|c| (&c.signer_public_key, &c.signature));
+        let mut signers: Vec<MPublicKey> = vec![];
+        for (public_key, signature) in
+            std::iter::once((&self.signer_public_key, &self.signature)).chain(cosignatures)
+        {
+            let signer: MPublicKey = public_key.parse()?;
+            let signature: MSignature = signature.parse()?;
+            ensure!(signer.verify(&bytes, &signature), "Invalid signature");
+            ensure!(!signers.contains(&signer), "Operations were signed twice by {}", signer);
+            signers.push(signer);
+        }
+        Ok(signers)
+    }
 }
 
 // TODO Did probably should be strongly typed, but that complicates serialization as well.
@@ -94,10 +134,18 @@ pub enum SignableOperationDetails {
         auth: Authentication,
         #[serde(rename = "expiresAtHeight", skip_serializing_if = "Option::is_none")]
         expires_at_height: Option<u32>,
+        /// The key is expired in the first block having a timestamp not earlier than this
+        #[serde(rename = "expiresAt", skip_serializing_if = "Option::is_none", default)]
+        expires_at: Option<DateTime<Utc>>,
     },
     RevokeKey {
         auth: Authentication,
     },
+    /// Adds the new key with all rights and the expiration of the old one, and revokes the old key in the same block
+    RotateKey {
+        old: Authentication,
+        new: Authentication,
+    },
     AddRight {
         auth: Authentication,
         right: String,
@@ -107,4 +155,54 @@ pub enum SignableOperationDetails {
         right: String,
     },
     TombstoneDid {},
+    /// Number of distinct keys with update right that have to sign operations on the DID
+    SetUpdateThreshold {
+        threshold: u32,
+    },
+    AddService {
+        service: Service,
+    },
+    RevokeService {
+        name: String,
+    },
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use crate::test_helpers::signer;
+
+    #[test]
+    fn cosignatures() -> Result<()> {
+        let did: Did = "did:morpheus:ezqztJ6XX6GDxdSgdiySiT3J".parse()?;
+        let (_, first) = signer("first");
+        let (_, second) = signer("second");
+        let attempt = SignableOperationAttempt {
+            did,
+            last_tx_id: None,
+            operation: SignableOperationDetails::SetUpdateThreshold { threshold: 2 },
+        };
+        let mut signed = SignableOperation::new(vec![attempt]).sign(&first)?;
+        let single = serde_json::to_value(&signed)?;
+        assert!(single.get("cosignatures").is_none());
+
+        signed.cosign(&second)?;
+        assert!(signed.cosign(&second).is_err());
+        assert!(signed.cosign(&first).is_err());
+        let signers = signed.signers()?;
+        assert_eq!(signers.len(), 2);
+        assert_eq!(signers[0].to_string(), signed.signer_public_key);
+        assert_eq!(signed.attempts()?.count(), 1);
+
+        let mut duplicate = signed.clone();
+        duplicate.cosignatures.push(signed.cosignatures[0].clone());
+        assert!(duplicate.signers().is_err());
+
+        let mut forged = signed.clone();
+        forged.cosignatures[0].signature = signed.signature.clone();
+        assert!(forged.attempts().is_err());
+
+        Ok(())
+    }
 }
@@ -0,0 +1,233 @@
+//! The authority side of witnessing claims: receiving witness requests, reviewing them and issuing
+//! witness statements.
+
+mod service;
+mod storage;
+mod types;
+
+pub use service::*;
+pub use storage::*;
+pub use types::*;
+
+use super::*;
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use iop_keyvault::{multicipher::MPrivateKey, secp256k1::SecpPrivateKey, PrivateKey as _};
+    use iop_morpheus_proto::{
+        crypto::sign::SyncMorpheusSigner,
+        data::{
+            Claim, DidDocument, KeyRightDerived, KeyRightHistory, KeyRightHistoryItem,
+            KeyRightState,
+        },
+    };
+    use serde_json::json;
+
+    const HEIGHT: BlockHeight = 100;
+
+    fn key(phrase: &str) -> MPrivateKey {
+        MPrivateKey::from(SecpPrivateKey::from_ark_passphrase(phrase).unwrap())
+    }
+
+    fn did_of(key: &MPrivateKey) -> Did {
+        Did::from(key.public_key().key_id())
+    }
+
+    fn did_doc(did: &Did) -> DidDocument {
+        let history = vec![KeyRightHistoryItem { height: None, valid: true, timestamp: None }];
+        let impersonate = KeyRightHistory {
+            state: KeyRightState { history },
+            derived: KeyRightDerived { key_link: "#0".to_owned(), valid: true },
+        };
+        let mut doc = DidDocument::implicit(did);
+        doc.queried_at_height = HEIGHT;
+        doc.rights.insert(Right::Impersonation, vec![impersonate]);
+        doc
+    }
+
+    struct AgeApprover;
+
+    impl Approver for AgeApprover {
+        fn review(&self, request: &WitnessRequest) -> Result<Review> {
+            if request.evidence["photo"] == "blurry" {
+                return Ok(Review::Reject("Photo is not readable".to_owned()));
+            }
+            let born = request.claim.content["dateOfBirth"].as_str().unwrap_or_default();
+            // Constraints not matching the schema of the process
+            if born.starts_with("18") {
+                return Ok(Review::Approve(Approval {
+                    content: json!("forever"),
+                    ..Default::default()
+                }));
+            }
+            if born.starts_with("19") {
+                return Ok(Review::Approve(Approval { content: json!({}), ..Default::default() }));
+            }
+            Ok(Review::Manual)
+        }
+    }
+
+    fn age_authority() -> Result<(Authority<InMemoryRequestStorage>, ProcessId)> {
+        let authority_key = key("authority");
+        let mut authority =
+            Authority::new(did_of(&authority_key), authority_key, InMemoryRequestStorage::new())
+                .with_approver(AgeApprover);
+        let claim = authority.add_schema(MorpheusSchema::new(json!({
+            "type": "object",
+            "required": ["dateOfBirth"],
+            "properties": { "dateOfBirth": { "type": "string" } },
+        }))?)?;
+        let evidence =
+            authority.add_schema(MorpheusSchema::new(json!({ "required": ["photo"] }))?)?;
+        let constraints =
+            authority.add_schema(MorpheusSchema::new(json!({ "type": "object" }))?)?;
+        let process = Process::new("Age", 1, "Date of birth from ID", evidence, constraints, claim);
+        let process_id = authority.add_process(process)?;
+        Ok((authority, process_id))
+    }
+
+    fn did_docs(dids: &[&Did]) -> HashMap<Did, DidDocument> {
+        dids.iter().map(|did| ((*did).to_owned(), did_doc(did))).collect()
+    }
+
+    fn request(
+        process_id: &ProcessId, signer: &MPrivateKey, subject: &Did, born: &str, photo: &str,
+    ) -> Result<Signed<WitnessRequest>> {
+        let request = WitnessRequest {
+            process_id: process_id.to_owned(),
+            claimant: signer.public_key().key_id().to_string(),
+            claim: Claim { subject: subject.to_owned(), content: json!({ "dateOfBirth": born }) },
+            evidence: json!({ "photo": photo }),
+            nonce: Some(Nonce264::generate()),
+        };
+        PrivateKeySigner::new(signer.to_owned()).sign_witness_request(request)
+    }
+
+    #[test]
+    fn automated_review() -> Result<()> {
+        let (mut authority, process_id) = age_authority()?;
+        let holder_key = key("holder");
+        let holder = did_of(&holder_key);
+        let docs = did_docs(&[&holder, authority.did()]);
+
+        let approved = request(&process_id, &holder_key, &holder, "1980-02-14", "sharp")?;
+        let entry = authority.receive(approved.clone(), &docs, HEIGHT)?;
+        assert_eq!(entry.status, RequestStatus::Approved);
+        let statement = entry.statement.unwrap();
+        assert!(statement.validate());
+        assert_eq!(statement.content().constraints.authority, *authority.did());
+        assert_eq!(statement.content().claim.content_id()?, approved.content().claim.content_id()?);
+        assert!(authority.receive(approved, &docs, HEIGHT).is_err());
+
+        let rejected = request(&process_id, &holder_key, &holder, "1980-02-14", "blurry")?;
+        let entry = authority.receive(rejected, &docs, HEIGHT)?;
+        assert_eq!(entry.status, RequestStatus::Rejected);
+        assert_eq!(entry.rejection_reason.as_deref(), Some("Photo is not readable"));
+        assert!(authority.pending()?.is_empty());
+
+        Ok(())
+    }
+
+    #[test]
+    fn manual_review() -> Result<()> {
+        let (mut authority, process_id) = age_authority()?;
+        let holder_key = key("holder");
+        let holder = did_of(&holder_key);
+        let docs = did_docs(&[&holder, authority.did()]);
+
+        let first = request(&process_id, &holder_key, &holder, "2004-02-29", "sharp")?;
+        let first = authority.receive(first, &docs, HEIGHT)?;
+        let second = request(&process_id, &holder_key, &holder, "2005-03-01", "sharp")?;
+        let second = authority.receive(second, &docs, HEIGHT)?;
+        assert_eq!(first.status, RequestStatus::Pending);
+        let pending = authority.pending()?.into_iter().map(|e| e.id).collect::<Vec<_>>();
+        assert_eq!(pending, vec![first.id.clone(), second.id.clone()]);
+
+        let before = "cjuN4oa1tj2zaRp1ZTAJA6dSu8pHC9O8vxm7m2yPoYr8zc".to_owned();
+        let approval = Approval { before: Some(before), content: json!({}), ..Default::default() };
+        let statement = authority.approve(&first.id, approval.clone())?;
+        assert!(statement.content().constraints.before.is_some());
+        assert!(authority.approve(&first.id, approval).is_err());
+
+        // Constraints have to match the schema of the process
+        let invalid = Approval { content: json!("forever"), ..Default::default() };
+        assert!(authority.approve(&second.id, invalid).is_err());
+        authority.reject(&second.id, "Too young")?;
+        assert!(authority.reject(&second.id, "Too young").is_err());
+
+        assert!(authority.pending()?.is_empty());
+        let approved = authority.storage().by_status(RequestStatus::Approved)?;
+        assert_eq!(approved[0].statement.as_ref().unwrap().signature(), statement.signature());
+
+        Ok(())
+    }
+
+    #[test]
+    fn invalid_requests() -> Result<()> {
+        let (mut authority, process_id) = age_authority()?;
+        let holder_key = key("holder");
+        let holder = did_of(&holder_key);
+        let docs = did_docs(&[&holder, authority.did()]);
+
+        // Signed by a key without impersonation right on the subject
+        let stranger = request(&process_id, &key("stranger"), &holder, "1980-02-14", "sharp")?;
+        let err = authority.receive(stranger, &docs, HEIGHT).unwrap_err();
+        assert!(err.to_string().contains("no right to impersonate"));
+
+        // Evidence does not match the schema of the process
+        let mut invalid =
+            request(&process_id, &holder_key, &holder, "1980-02-14", "sharp")?.content().to_owned();
+        invalid.evidence = json!({ "selfie": "sharp" });
+        let invalid = PrivateKeySigner::new(holder_key.to_owned()).sign_witness_request(invalid)?;
+        assert!(authority.receive(invalid, &docs, HEIGHT).is_err());
+
+        // Claimant is not the signer
+        let mut forged =
+            request(&process_id, &holder_key, &holder, "1980-02-14", "sharp")?.content().to_owned();
+        forged.claimant = key("stranger").public_key().key_id().to_string();
+        let forged = PrivateKeySigner::new(holder_key.to_owned()).sign_witness_request(forged)?;
+        assert!(authority.receive(forged, &docs, HEIGHT).is_err());
+
+        assert!(authority.storage().by_status(RequestStatus::Pending)?.is_empty());
+
+        Ok(())
+    }
+
+    #[test]
+    fn failed_review() -> Result<()> {
+        let (mut authority, process_id) = age_authority()?;
+        let holder_key = key("holder");
+        let holder = did_of(&holder_key);
+        let docs = did_docs(&[&holder, authority.did()]);
+
+        // Nothing is queued, so the request can be sent again
+        let request = request(&process_id, &holder_key, &holder, "1890-01-01", "sharp")?;
+        assert!(authority.receive(request.clone(), &docs, HEIGHT).is_err());
+        let err = authority.receive(request, &docs, HEIGHT).unwrap_err();
+        assert!(!err.to_string().contains("already received"));
+        assert!(authority.pending()?.is_empty());
+
+        Ok(())
+    }
+
+    #[test]
+    fn witness_without_right() -> Result<()> {
+        let (mut authority, process_id) = age_authority()?;
+        let holder_key = key("holder");
+        let holder = did_of(&holder_key);
+        let mut docs = did_docs(&[&holder, authority.did()]);
+        let authority_did = authority.did().to_owned();
+        let mut authority_doc = docs[&authority_did].to_owned();
+        authority_doc.rights.clear();
+        docs.insert(authority_did, authority_doc);
+
+        let request = request(&process_id, &holder_key, &holder, "1980-02-14", "sharp")?;
+        let err = authority.receive(request, &docs, HEIGHT).unwrap_err();
+        assert!(err.to_string().contains("has no right to impersonate authority"));
+        assert!(authority.storage().by_status(RequestStatus::Approved)?.is_empty());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,173 @@
+use super::*;
+
+/// Witnesses claims on behalf of an authority DID. Requests are checked when received, then wait in
+/// the queue until approved or rejected, either by an [`Approver`] or manually.
+pub struct Authority<S: RequestStorage> {
+    did: Did,
+    witness: PrivateKeySigner,
+    witness_id: MKeyId,
+    processes: HashMap<ProcessId, Process>,
+    schemas: HashMap<SchemaId, MorpheusSchema>,
+    approver: Box<dyn Approver>,
+    storage: S,
+}
+
+impl<S: RequestStorage> Authority<S> {
+    /// The witness key has to have the impersonation right on the authority DID, so the statements
+    /// it signs are accepted on behalf of the authority. This is checked for each request received.
+    pub fn new(did: Did, witness_key: MPrivateKey, storage: S) -> Self {
+        let witness_id = witness_key.public_key().key_id();
+        Self {
+            did,
+            witness: PrivateKeySigner::new(witness_key),
+            witness_id,
+            processes: Default::default(),
+            schemas: Default::default(),
+            approver: Box::new(ManualApprover),
+            storage,
+        }
+    }
+
+    pub fn with_approver(mut self, approver: impl Approver + 'static) -> Self {
+        self.approver = Box::new(approver);
+        self
+    }
+
+    pub fn did(&self) -> &Did {
+        &self.did
+    }
+
+    pub fn storage(&self) -> &S {
+        &self.storage
+    }
+
+    pub fn add_schema(&mut self, schema: MorpheusSchema) -> Result<SchemaId> {
+        let id = schema.content_id()?;
+        self.schemas.insert(id.clone(), schema);
+        Ok(id)
+    }
+
+    /// All schemas of the process have to be added before.
+    pub fn add_process(&mut self, process: Process) -> Result<ProcessId> {
+        for schema in
+            [process.claim_schema(), process.evidence_schema(), process.constraints_schema()]
+        {
+            self.schemas.resolve(schema)?;
+        }
+        let id = process.content_id()?;
+        self.processes.insert(id.clone(), process);
+        Ok(id)
+    }
+
+    /// Checks the signature of the request, the impersonation right of the signer on the subject
+    /// DID at the given height and the claim and evidence against the schemas of the process. The
+    /// witness key also has to have the impersonation right on the authority DID at that height.
+    /// Requests passing all checks are reviewed by the [`Approver`] and stored with the outcome.
+    /// Nothing is stored if any of the checks or the review fails, so the request can be sent again.
+    pub fn receive(
+        &mut self, request: Signed<WitnessRequest>, resolver: &dyn DidDocumentResolver,
+        height: BlockHeight,
+    ) -> Result<RequestEntry> {
+        ensure!(request.validate(), "Signature of the witness request is invalid");
+        let content = request.content();
+        let id = content.content_id()?;
+        ensure!(self.storage.get(&id)?.is_none(), "Request {} was already received", id);
+
+        let signer = Authentication::PublicKey(request.public_key().to_owned());
+        let claimant: Authentication = content.claimant.parse()?;
+        ensure!(claimant == signer, "Request {} was not signed by claimant {}", id, claimant);
+        let subject = &content.claim.subject;
+        let subject_doc = resolver.resolve_at(subject, height)?;
+        ensure!(
+            subject_doc.has_delegated_right_at(&signer, Right::Impersonation, height, resolver)?,
+            "{} has no right to impersonate {} at height {}",
+            signer,
+            subject,
+            height
+        );
+        let witness = Authentication::KeyId(self.witness_id.to_owned());
+        let authority_doc = resolver.resolve_at(&self.did, height)?;
+        ensure!(
+            authority_doc.has_delegated_right_at(
+                &witness,
+                Right::Impersonation,
+                height,
+                resolver
+            )?,
+            "Witness key {} has no right to impersonate authority {} at height {}",
+            witness,
+            self.did,
+            height
+        );
+
+        let process = self.process(&content.process_id)?;
+        process.validate_request(content, &self.schemas)?;
+
+        let (status, rejection_reason, statement) = match self.approver.review(content)? {
+            Review::Approve(approval) => {
+                let statement = self.sign_statement(content, approval)?;
+                (RequestStatus::Approved, None, Some(statement))
+            }
+            Review::Reject(reason) => (RequestStatus::Rejected, Some(reason), None),
+            Review::Manual => (RequestStatus::Pending, None, None),
+        };
+        let entry = RequestEntry { id, request, status, rejection_reason, statement };
+        self.storage.insert(entry.clone())?;
+        Ok(entry)
+    }
+
+    pub fn request(&self, id: &str) -> Result<RequestEntry> {
+        self.storage.get(id)?.with_context(|| format!("Unknown request {}", id))
+    }
+
+    pub fn pending(&self) -> Result<Vec<RequestEntry>> {
+        self.storage.by_status(RequestStatus::Pending)
+    }
+
+    /// Signs a witness statement about the claim of a pending request with the given constraints.
+    pub fn approve(&mut self, id: &str, approval: Approval) -> Result<Signed<WitnessStatement>> {
+        let mut entry = self.pending_request(id)?;
+        let signed = self.sign_statement(entry.request.content(), approval)?;
+
+        entry.status = RequestStatus::Approved;
+        entry.statement = Some(signed.clone());
+        self.storage.update(entry)?;
+        Ok(signed)
+    }
+
+    fn sign_statement(
+        &self, request: &WitnessRequest, approval: Approval,
+    ) -> Result<Signed<WitnessStatement>> {
+        let statement = WitnessStatement {
+            process_id: request.process_id.to_owned(),
+            claim: request.claim.to_owned(),
+            constraints: Constraints {
+                after: approval.after,
+                before: approval.before,
+                witness: self.witness_id.to_string(),
+                authority: self.did.to_owned(),
+                content: approval.content,
+            },
+            nonce: Some(Nonce264::generate()),
+        };
+        self.process(&statement.process_id)?.validate_statement(&statement, &self.schemas)?;
+        self.witness.sign_witness_statement(statement)
+    }
+
+    pub fn reject(&mut self, id: &str, reason: &str) -> Result<()> {
+        let mut entry = self.pending_request(id)?;
+        entry.status = RequestStatus::Rejected;
+        entry.rejection_reason = Some(reason.to_owned());
+        self.storage.update(entry)
+    }
+
+    fn pending_request(&self, id: &str) -> Result<RequestEntry> {
+        let entry = self.request(id)?;
+        ensure!(entry.status == RequestStatus::Pending, "Request {} is not pending", id);
+        Ok(entry)
+    }
+
+    fn process(&self, id: &str) -> Result<&Process> {
+        self.processes.get(id).with_context(|| format!("Unknown process {}", id))
+    }
+}
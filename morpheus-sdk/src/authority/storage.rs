@@ -0,0 +1,48 @@
+use super::*;
+
+/// Persists the requests received by an authority and the outcome of their review.
+pub trait RequestStorage {
+    fn insert(&mut self, entry: RequestEntry) -> Result<()>;
+    fn update(&mut self, entry: RequestEntry) -> Result<()>;
+    fn get(&self, id: &str) -> Result<Option<RequestEntry>>;
+    /// Entries in the order they were received
+    fn by_status(&self, status: RequestStatus) -> Result<Vec<RequestEntry>>;
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct InMemoryRequestStorage {
+    entries: Vec<RequestEntry>,
+}
+
+impl InMemoryRequestStorage {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn position(&self, id: &str) -> Option<usize> {
+        self.entries.iter().position(|e| e.id == id)
+    }
+}
+
+impl RequestStorage for InMemoryRequestStorage {
+    fn insert(&mut self, entry: RequestEntry) -> Result<()> {
+        ensure!(self.position(&entry.id).is_none(), "Request {} was already received", entry.id);
+        self.entries.push(entry);
+        Ok(())
+    }
+
+    fn update(&mut self, entry: RequestEntry) -> Result<()> {
+        let idx =
+            self.position(&entry.id).with_context(|| format!("Unknown request {}", entry.id))?;
+        self.entries[idx] = entry;
+        Ok(())
+    }
+
+    fn get(&self, id: &str) -> Result<Option<RequestEntry>> {
+        Ok(self.position(id).map(|idx| self.entries[idx].to_owned()))
+    }
+
+    fn by_status(&self, status: RequestStatus) -> Result<Vec<RequestEntry>> {
+        Ok(self.entries.iter().filter(|e| e.status == status).cloned().collect())
+    }
+}
@@ -0,0 +1,57 @@
+use super::*;
+
+pub type RequestId = ContentId;
+
+/// Where a witness request is in the queue of the authority
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum RequestStatus {
+    Pending,
+    Approved,
+    Rejected,
+}
+
+/// A witness request received by the authority, together with the outcome of its review.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RequestEntry {
+    pub id: RequestId,
+    pub request: Signed<WitnessRequest>,
+    pub status: RequestStatus,
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub rejection_reason: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub statement: Option<Signed<WitnessStatement>>,
+}
+
+/// Constraints the authority puts into the witness statement when approving a request
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct Approval {
+    pub after: Option<String>,
+    pub before: Option<String>,
+    pub content: MorpheusValue,
+}
+
+/// Outcome of the automated review of a request that already passed all checks
+#[derive(Clone, Debug)]
+pub enum Review {
+    Approve(Approval),
+    Reject(String),
+    /// The request stays pending until it is approved or rejected manually
+    Manual,
+}
+
+/// Reviews requests automatically right after they were received.
+pub trait Approver {
+    fn review(&self, request: &WitnessRequest) -> Result<Review>;
+}
+
+/// Leaves all requests for manual approval
+#[derive(Clone, Copy, Debug, Default)]
+pub struct ManualApprover;
+
+impl Approver for ManualApprover {
+    fn review(&self, _request: &WitnessRequest) -> Result<Review> {
+        Ok(Review::Manual)
+    }
+}
@@ -0,0 +1,188 @@
+//! A wallet for the witness statements a holder received about their claims.
+
+mod plugin;
+mod private;
+mod public;
+mod types;
+
+pub use plugin::*;
+pub use private::*;
+pub use public::*;
+pub use types::*;
+
+use super::*;
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use iop_morpheus_proto::{
+        crypto::sign::{PrivateKeySigner, SyncMorpheusSigner},
+        data::{Claim, Constraints, LicensePurpose},
+    };
+    use serde_json::json;
+
+    use crate::vault::Plugin as MorpheusPlugin;
+
+    const UNLOCK_PASSWORD: &str = "correct horse battery staple";
+    const PROCESS_EMAIL: &str = "cjuMiVfLyOYuWzsbqc5YgtMRbUgxTaNT8pr2HG7G3bmVE4";
+    const PROCESS_AGE: &str = "cjunI8lB1BzIWeXFBaJ2aGHbCLPf3ToIafjg7uI5GLK3PI";
+
+    fn vault() -> Result<Vault> {
+        let mut vault = Vault::create(None, Seed::DEMO_PHRASE, "", UNLOCK_PASSWORD)?;
+        MorpheusPlugin::init(&mut vault, UNLOCK_PASSWORD)?;
+        Plugin::init(&mut vault)?;
+        Ok(vault)
+    }
+
+    fn persona(vault: &Vault, idx: i32) -> Result<(Did, PrivateKeySigner)> {
+        let morpheus = MorpheusPlugin::get(vault)?.private(UNLOCK_PASSWORD)?;
+        let key = morpheus.personas()?.key_mut(idx)?;
+        let did = Did::from(key.neuter().public_key().key_id());
+        Ok((did, PrivateKeySigner::new(key.private_key())))
+    }
+
+    fn witness(vault: &Vault) -> Result<PrivateKeySigner> {
+        let morpheus = MorpheusPlugin::get(vault)?.private(UNLOCK_PASSWORD)?;
+        Ok(PrivateKeySigner::new(morpheus.groups()?.key_mut(0)?.private_key()))
+    }
+
+    fn statement(
+        witness: &PrivateKeySigner, process_id: &str, subject: &Did, content: serde_json::Value,
+    ) -> Result<Signed<WitnessStatement>> {
+        let authority = Did::from(witness.sign(&[])?.0.key_id());
+        let statement = WitnessStatement {
+            process_id: process_id.to_owned(),
+            claim: Claim { subject: subject.to_owned(), content },
+            constraints: Constraints {
+                after: None,
+                before: None,
+                witness: authority.to_string(),
+                authority,
+                content: json!({}),
+            },
+            nonce: Some(Nonce264::generate()),
+        };
+        witness.sign_witness_statement(statement)
+    }
+
+    // WitnessStatement does not implement PartialEq yet
+    fn to_json(value: &impl Serialize) -> Result<serde_json::Value> {
+        Ok(serde_json::to_value(value)?)
+    }
+
+    #[test]
+    fn api() -> Result<()> {
+        let vault = vault()?;
+        let (holder, _) = persona(&vault, 0)?;
+        let (other, _) = persona(&vault, 1)?;
+        let witness = witness(&vault)?;
+        let plugin = Plugin::get(&vault)?;
+        let mut private = plugin.private(UNLOCK_PASSWORD)?;
+
+        let email = json!({ "email": "holder@example.com" });
+        let stmt1 = statement(&witness, PROCESS_EMAIL, &holder, email)?;
+        let stmt2 = statement(&witness, PROCESS_AGE, &holder, json!({ "over18": true }))?;
+        let stmt3 = statement(&witness, PROCESS_AGE, &other, json!({ "over18": false }))?;
+
+        let id1 = private.add(stmt1.clone())?;
+        let id2 = private.add(stmt2)?;
+        let id3 = private.add(stmt3)?;
+
+        let public = plugin.public()?;
+        assert_eq!(public.len()?, 3);
+        assert_eq!(id1, stmt1.content().content_id()?);
+        assert_eq!(public.by_subject(&holder)?, vec![id1.clone(), id2.clone()]);
+        assert_eq!(public.by_subject(&other)?, vec![id3.clone()]);
+        assert_eq!(public.by_process(PROCESS_AGE)?, vec![id2, id3.clone()]);
+        assert_eq!(public.entry(&id3)?.subject()?, other);
+
+        assert_eq!(to_json(&private.get(&id1)?)?, to_json(&stmt1)?);
+        assert_eq!(to_json(&private.by_process(PROCESS_EMAIL)?)?, to_json(&vec![stmt1.clone()])?);
+
+        let err = private.add(stmt1).unwrap_err();
+        assert!(err.to_string().contains("already in the vault"));
+
+        plugin.public()?.remove(&id3)?;
+        assert_eq!(public.len()?, 2);
+        assert!(private.get(&id3).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn rejects_invalid_signature() -> Result<()> {
+        let vault = vault()?;
+        let (holder, _) = persona(&vault, 0)?;
+        let witness = witness(&vault)?;
+        let mut private = Plugin::get(&vault)?.private(UNLOCK_PASSWORD)?;
+
+        let valid = statement(&witness, PROCESS_EMAIL, &holder, json!("valid"))?;
+        let (public_key, mut content, signature, nonce) = valid.into_parts();
+        content.claim.content = json!("forged");
+        let forged = Signed::from_parts(public_key, content, signature, nonce);
+
+        assert!(private.add(forged).is_err());
+        assert!(private.public().is_empty()?);
+
+        Ok(())
+    }
+
+    #[test]
+    fn encrypted_at_rest() -> Result<()> {
+        let vault = vault()?;
+        let (holder, _) = persona(&vault, 0)?;
+        let witness = witness(&vault)?;
+        let stmt = statement(&witness, PROCESS_EMAIL, &holder, json!("secret@example.com"))?;
+        let id = Plugin::get(&vault)?.private(UNLOCK_PASSWORD)?.add(stmt.clone())?;
+
+        let serialized = serde_json::to_string(&vault)?;
+        assert!(serialized.contains(&id));
+        assert!(!serialized.contains("secret@example.com"));
+
+        let restored: Vault = serde_json::from_str(&serialized)?;
+        let private = Plugin::get(&restored)?.private(UNLOCK_PASSWORD)?;
+        assert_eq!(to_json(&private.get(&id)?)?, to_json(&stmt)?);
+
+        Ok(())
+    }
+
+    #[test]
+    fn presentation() -> Result<()> {
+        let vault = vault()?;
+        let (holder, holder_signer) = persona(&vault, 0)?;
+        let (other, _) = persona(&vault, 1)?;
+        let witness = witness(&vault)?;
+        let mut private = Plugin::get(&vault)?.private(UNLOCK_PASSWORD)?;
+
+        let email = json!({ "email": "holder@example.com" });
+        let stmt1 = statement(&witness, PROCESS_EMAIL, &holder, email.clone())?;
+        let stmt2 = statement(&witness, PROCESS_EMAIL, &holder, email.clone())?;
+        let stmt3 = statement(&witness, PROCESS_AGE, &holder, json!({ "over18": true }))?;
+        let ids = vec![private.add(stmt1)?, private.add(stmt2)?, private.add(stmt3)?];
+
+        let license = holder_signer.sign_license(License {
+            issuer: holder.clone(),
+            issued_to: other.clone(),
+            purpose: LicensePurpose::Kyc,
+            valid_from: "2020-01-01T00:00:00Z".parse()?,
+            valid_until: "2021-01-01T00:00:00Z".parse()?,
+        })?;
+        let presentation = private.presentation(&ids, vec![license.clone()])?;
+
+        assert_eq!(presentation.licenses, vec![license]);
+        assert!(presentation.nonce.is_some());
+        assert_eq!(presentation.proven_claims.len(), 2);
+
+        let emails = &presentation.proven_claims[0];
+        assert_eq!(emails.claim["content"], email);
+        assert_eq!(emails.statements.len(), 2);
+        assert!(emails.statements.iter().all(|s| s.validate()));
+        assert_eq!(presentation.proven_claims[1].statements.len(), 1);
+
+        assert!(private.presentation(&[], vec![]).is_err());
+        assert!(private.presentation(&["cjunknown".to_owned()], vec![]).is_err());
+
+        Ok(())
+    }
+}
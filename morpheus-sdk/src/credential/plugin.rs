@@ -0,0 +1,66 @@
+use super::*;
+
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Inner {
+    parameters: Parameters,
+    public_state: PublicState,
+}
+
+impl Inner {
+    fn new(public_state: PublicState) -> Self {
+        Self { parameters: Default::default(), public_state }
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Plugin {
+    inner: Arc<RwLock<Inner>>,
+}
+
+#[cfg_attr(target_arch = "wasm32", typetag::serialize(name = "Credentials"))]
+#[cfg_attr(not(target_arch = "wasm32"), typetag::serde(name = "Credentials"))]
+impl VaultPlugin for Plugin {
+    fn name(&self) -> &'static str {
+        "Credentials"
+    }
+
+    fn to_any(&self) -> Box<dyn Any> {
+        Box::new(self.clone())
+    }
+
+    fn eq(&self, other: &dyn VaultPlugin) -> bool {
+        let other: Result<Box<Plugin>, _> = other.to_any().downcast();
+        other.is_ok()
+    }
+}
+
+impl Plugin {
+    pub fn new(credentials: Vec<CredentialEntry>) -> Self {
+        let imp = Inner::new(PublicState { credentials });
+        let inner = Arc::new(RwLock::new(imp));
+        Self { inner }
+    }
+
+    /// Adds an empty credential wallet to the vault. Unlike other plugins, there are no keys to be
+    /// generated, so the unlock password is not needed here.
+    pub fn init(vault: &mut Vault) -> Result<()> {
+        let plugin = Self::new(vec![]);
+        vault.add(Box::new(plugin))
+    }
+
+    pub fn get(vault: &Vault) -> Result<BoundPlugin<Plugin, Public, Private>> {
+        let credential_plugins = vault.plugins_by_type::<Plugin>()?;
+        let plugin: &Plugin = credential_plugins
+            .iter()
+            .by_ref()
+            .next()
+            .with_context(|| "Could not find Credentials plugin")?;
+        Ok(BoundPlugin::new(vault.to_owned(), plugin.to_owned()))
+    }
+
+    pub(super) fn to_state(&self) -> Box<dyn State<PublicState>> {
+        <dyn State<_>>::map(&self.inner, |s| &s.public_state, |s| &mut s.public_state)
+    }
+}
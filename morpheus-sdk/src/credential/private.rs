@@ -0,0 +1,108 @@
+use super::*;
+
+pub struct Private {
+    state: Box<dyn State<PublicState>>,
+    secret: String,
+    vault_dirty: Box<dyn State<bool>>,
+}
+
+impl PluginPrivate<Plugin> for Private {
+    fn create(plugin: &Plugin, seed: Seed, vault_dirty: Box<dyn State<bool>>) -> Result<Self> {
+        // A hardened child of the Morpheus root outside the DID kinds is used only for encrypting
+        // credentials, so they can be decrypted again after restoring the vault from its phrase.
+        let root = Morpheus.root(&seed)?;
+        let encryption_key = root.node().derive_hardened(Self::ENCRYPTION_KEY_IDX)?.private_key();
+        let secret = multibase::encode(multibase::Base::Base58Btc, encryption_key.to_bytes());
+        let state = plugin.to_state();
+        Ok(Private { state, secret, vault_dirty })
+    }
+}
+
+impl Private {
+    // "cred" in ASCII, far from the indexes of DID kinds
+    const ENCRYPTION_KEY_IDX: i32 = 0x6372_6564;
+
+    pub fn public(&self) -> Public {
+        Public::new(self.state.clone(), self.vault_dirty.clone())
+    }
+
+    /// Stores a statement after checking its signature. Returns the content id the statement can be
+    /// queried with later.
+    pub fn add(&mut self, statement: Signed<WitnessStatement>) -> Result<ContentId> {
+        ensure!(statement.validate(), "Signature of the witness statement is invalid");
+        let content_id = statement.content().content_id()?;
+        ensure!(
+            !self.public().contains(&content_id)?,
+            "Credential {} is already in the vault",
+            content_id
+        );
+
+        let entry = CredentialEntry {
+            content_id: content_id.clone(),
+            subject: statement.content().claim.subject.to_string(),
+            process_id: statement.content().process_id.clone(),
+            encrypted_statement: self.encrypt_statement(&statement)?,
+        };
+
+        let mut state = self.state.try_borrow_mut()?;
+        state.credentials.push(entry);
+        let mut dirty = self.vault_dirty.try_borrow_mut()?;
+        *dirty = true;
+        Ok(content_id)
+    }
+
+    pub fn get(&self, content_id: &str) -> Result<Signed<WitnessStatement>> {
+        let state = self.state.try_borrow()?;
+        self.decrypt_statement(state.entry(content_id)?)
+    }
+
+    pub fn by_subject(&self, subject: &Did) -> Result<Vec<Signed<WitnessStatement>>> {
+        self.public().by_subject(subject)?.iter().map(|id| self.get(id)).collect()
+    }
+
+    pub fn by_process(&self, process_id: &str) -> Result<Vec<Signed<WitnessStatement>>> {
+        self.public().by_process(process_id)?.iter().map(|id| self.get(id)).collect()
+    }
+
+    /// Assembles a presentation from the selected credentials. Statements witnessing the very same
+    /// claim are grouped into a single proven claim. The result still has to be signed by the holder.
+    pub fn presentation(
+        &self, content_ids: &[ContentId], licenses: Vec<Signed<License>>,
+    ) -> Result<ClaimPresentation> {
+        ensure!(!content_ids.is_empty(), "At least one credential has to be presented");
+
+        let mut claim_ids: Vec<ContentId> = Vec::new();
+        let mut proven_claims: Vec<ProvenClaim> = Vec::new();
+        for content_id in content_ids {
+            let (public_key, statement, signature, nonce) = self.get(content_id)?.into_parts();
+            let claim_id = statement.claim.content_id()?;
+            let statement = serde_json::to_value(statement)?;
+            let signed = Signed::from_parts(public_key, statement, signature, nonce);
+
+            match claim_ids.iter().position(|id| *id == claim_id) {
+                Some(idx) => proven_claims[idx].statements.push(signed),
+                None => {
+                    let claim = signed.content()["claim"].clone();
+                    claim_ids.push(claim_id);
+                    proven_claims.push(ProvenClaim { claim, statements: vec![signed] });
+                }
+            }
+        }
+
+        Ok(ClaimPresentation { proven_claims, licenses, nonce: Some(Nonce264::generate()) })
+    }
+
+    fn encrypt_statement(&self, statement: &Signed<WitnessStatement>) -> Result<String> {
+        let plaintext = serde_json::to_vec(statement)?;
+        let ciphertext = encrypt(plaintext, &self.secret, nonce()?)?;
+        Ok(multibase::encode(multibase::Base::Base64Url, ciphertext))
+    }
+
+    fn decrypt_statement(&self, entry: &CredentialEntry) -> Result<Signed<WitnessStatement>> {
+        let (_, ciphertext) = multibase::decode(&entry.encrypted_statement)?;
+        let plaintext = decrypt(ciphertext, &self.secret)
+            .with_context(|| format!("Could not decrypt credential {}", entry.content_id))?;
+        let statement = serde_json::from_slice(&plaintext)?;
+        Ok(statement)
+    }
+}
@@ -0,0 +1,76 @@
+use super::*;
+
+pub struct Public {
+    state: Box<dyn State<PublicState>>,
+    vault_dirty: Box<dyn State<bool>>,
+}
+
+impl PluginPublic<Plugin> for Public {
+    fn create(plugin: &Plugin, vault_dirty: Box<dyn State<bool>>) -> Result<Self> {
+        Ok(Self::new(plugin.to_state(), vault_dirty))
+    }
+}
+
+impl Public {
+    pub(super) fn new(
+        state: Box<dyn State<PublicState>>, vault_dirty: Box<dyn State<bool>>,
+    ) -> Self {
+        Self { state, vault_dirty }
+    }
+
+    pub fn len(&self) -> Result<u32> {
+        let state = self.state.try_borrow()?;
+        Ok(state.credentials.len() as u32)
+    }
+
+    pub fn is_empty(&self) -> Result<bool> {
+        let state = self.state.try_borrow()?;
+        Ok(state.credentials.is_empty())
+    }
+
+    /// Index entries of all credentials in the order they were added.
+    pub fn entries(&self) -> Result<Vec<CredentialEntry>> {
+        let state = self.state.try_borrow()?;
+        Ok(state.credentials.clone())
+    }
+
+    pub fn entry(&self, content_id: &str) -> Result<CredentialEntry> {
+        let state = self.state.try_borrow()?;
+        Ok(state.entry(content_id)?.to_owned())
+    }
+
+    pub fn contains(&self, content_id: &str) -> Result<bool> {
+        let state = self.state.try_borrow()?;
+        Ok(state.credentials.iter().any(|c| c.content_id == content_id))
+    }
+
+    /// Content ids of the statements witnessing claims about the given subject.
+    pub fn by_subject(&self, subject: &Did) -> Result<Vec<ContentId>> {
+        let subject = subject.to_string();
+        self.select(|c| c.subject == subject)
+    }
+
+    /// Content ids of the statements witnessed according to the given process.
+    pub fn by_process(&self, process_id: &str) -> Result<Vec<ContentId>> {
+        self.select(|c| c.process_id == process_id)
+    }
+
+    /// Forgets a credential. There is no need to unlock the vault for this.
+    pub fn remove(&mut self, content_id: &str) -> Result<()> {
+        let mut state = self.state.try_borrow_mut()?;
+        let idx =
+            state.credentials.iter().position(|c| c.content_id == content_id).with_context(
+                || format!("Could not find credential {} in the vault", content_id),
+            )?;
+        state.credentials.remove(idx);
+        let mut dirty = self.vault_dirty.try_borrow_mut()?;
+        *dirty = true;
+        Ok(())
+    }
+
+    fn select(&self, pred: impl Fn(&CredentialEntry) -> bool) -> Result<Vec<ContentId>> {
+        let state = self.state.try_borrow()?;
+        let ids = state.credentials.iter().filter(|c| pred(c)).map(|c| c.content_id.clone());
+        Ok(ids.collect())
+    }
+}
@@ -0,0 +1,44 @@
+use super::*;
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub(super) struct Parameters {}
+
+/// A witness statement received by the holder. Only the fields needed for indexing are kept in plain text,
+/// the signed statement itself is encrypted with a key derived from the seed of the vault.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CredentialEntry {
+    pub(super) content_id: ContentId,
+    pub(super) subject: String,
+    pub(super) process_id: ProcessId,
+    pub(super) encrypted_statement: String,
+}
+
+impl CredentialEntry {
+    pub fn content_id(&self) -> &ContentId {
+        &self.content_id
+    }
+
+    pub fn subject(&self) -> Result<Did> {
+        self.subject.parse()
+    }
+
+    pub fn process_id(&self) -> &ProcessId {
+        &self.process_id
+    }
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct PublicState {
+    #[serde(skip_serializing_if = "Vec::is_empty", default)]
+    pub(super) credentials: Vec<CredentialEntry>,
+}
+
+impl PublicState {
+    pub(super) fn entry(&self, content_id: &str) -> Result<&CredentialEntry> {
+        self.credentials
+            .iter()
+            .find(|c| c.content_id == content_id)
+            .with_context(|| format!("Could not find credential {} in the vault", content_id))
+    }
+}
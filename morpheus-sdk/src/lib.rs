@@ -1,8 +1,11 @@
+pub mod authority;
+pub mod credential;
 pub mod vault;
 
 // imports from standard library
 
 use std::any::Any;
+use std::collections::HashMap;
 use std::sync::Arc;
 
 // imports from 3rd party crates
@@ -14,12 +17,26 @@ use serde::{Deserialize, Serialize};
 
 // imports from own crates
 
+use iop_journal_proto::BlockHeight;
 use iop_keyvault::{
     ed25519::{DidKind, Ed25519, Morpheus, MorpheusKind, MorpheusPrivateKey, MorpheusRoot},
-    multicipher::{MKeyId, MPublicKey},
-    Bip32Node, PublicKey as _, Seed,
+    encrypt::{decrypt, encrypt, nonce},
+    multicipher::{MKeyId, MPrivateKey, MPublicKey},
+    Bip32Node, PrivateKey as _, PublicKey as _, Seed,
+};
+use iop_morpheus_proto::{
+    crypto::{
+        hash::{Content as _, ContentId},
+        sign::{PrivateKeySigner, Signed, SyncMorpheusSigner as _},
+    },
+    data::{
+        Authentication, ClaimPresentation, Constraints, Did, DidDocumentResolver, License,
+        MorpheusSchema, MorpheusValue, Process, ProcessId, ProvenClaim, Right, SchemaId,
+        SchemaResolver as _, WitnessRequest, WitnessStatement,
+    },
 };
 use iop_vault::{BoundPlugin, PluginPrivate, PluginPublic, State, Vault, VaultPlugin};
+use json_digest::Nonce264;
 
 #[cfg(test)]
 mod test {
@@ -54,10 +71,18 @@ mod test {
     fn builder() -> Result<()> {
         let mut builder = JwtBuilder::with_content_id(CONTENT_ID.to_owned());
         builder.created_at = test_now();
+        builder.algorithm = Some(JwtAlgorithm::MultiCipher);
         let token = builder.sign(&persona()?.private_key())?;
 
         assert_eq!(token, TOKEN);
 
+        builder.algorithm = None;
+        let token = builder.sign(&persona()?.private_key())?;
+        let parser = JwtParser::new(token, Some(test_now()))?;
+
+        assert_eq!(parser.algorithm(), JwtAlgorithm::EdDsa);
+        assert_eq!(parser.content_id(), Some(&CONTENT_ID.to_owned()));
+
         Ok(())
     }
 
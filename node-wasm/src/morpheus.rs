@@ -86,6 +86,20 @@ impl JsMorpheusState {
         Ok(js_doc)
     }
 
+    #[wasm_bindgen(js_name = resolveW3cDidDocumentAt)]
+    pub fn resolve_w3c_at(
+        &self, did_data: &str, height_opt: Option<BlockHeight>,
+    ) -> Result<JsValue, JsValue> {
+        if let Some(height) = height_opt {
+            Self::check_height(height)?;
+        }
+        let state = self.inner.state().map_err_to_js()?;
+        let doc = state.get_doc_at(did_data, height_opt).map_err_to_js()?;
+        let resolution = doc.to_w3c_resolution().map_err_to_js()?;
+        let js_resolution = to_value(&resolution)?;
+        Ok(js_resolution)
+    }
+
     #[wasm_bindgen(js_name = dryRun)]
     pub fn dry_run(&self, asset: &JsValue) -> Result<Vec<JsValue>, JsValue> {
         let asset: MorpheusAsset = from_value(asset.clone())?;
@@ -107,10 +121,14 @@ impl JsMorpheusState {
         Ok(())
     }
 
+    /// The RFC 3339 timestamp of the block is needed to invalidate keys expiring at a given time.
     #[wasm_bindgen(js_name = blockApplying)]
-    pub fn block_applying(&mut self, height: BlockHeight) -> Result<(), JsValue> {
+    pub fn block_applying(
+        &mut self, height: BlockHeight, timestamp: &JsValue,
+    ) -> Result<(), JsValue> {
         Self::check_height(height)?;
-        self.inner.block_applying(height).map_err_to_js()
+        let timestamp = from_value(timestamp.clone())?;
+        self.inner.block_applying(height, timestamp).map_err_to_js()
     }
 
     #[wasm_bindgen(js_name = applyTransaction)]
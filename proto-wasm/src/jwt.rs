@@ -40,6 +40,80 @@ impl JsJwtBuilder {
         self.inner.time_to_live = Duration::seconds(seconds);
     }
 
+    /// Gets the `alg` the token is signed with. If undefined, `EdDSA` or `ES256K` is picked based on
+    /// the type of the signing key.
+    #[wasm_bindgen(getter)]
+    pub fn algorithm(&self) -> Option<String> {
+        self.inner.algorithm.map(|alg| alg.to_string())
+    }
+
+    /// Sets the `alg` the token is signed with. Use `Multicipher` for tokens that have to be
+    /// accepted by older versions of the SDK.
+    #[wasm_bindgen(setter)]
+    pub fn set_algorithm(&mut self, algorithm: Option<String>) -> Result<(), JsValue> {
+        let algorithm = algorithm.map(|name| JwtAlgorithm::from_name(&name)).transpose();
+        self.inner.algorithm = algorithm.map_err_to_js()?;
+        Ok(())
+    }
+
+    /// Gets the DID issuing the token (`iss` claim)
+    #[wasm_bindgen(getter)]
+    pub fn issuer(&self) -> Option<String> {
+        self.inner.issuer.as_ref().map(|did| did.to_string())
+    }
+
+    /// Sets the DID issuing the token (`iss` claim)
+    #[wasm_bindgen(setter)]
+    pub fn set_issuer(&mut self, did: Option<String>) -> Result<(), JsValue> {
+        self.inner.issuer = did.map(|did| did.parse::<Did>()).transpose().map_err_to_js()?;
+        Ok(())
+    }
+
+    /// Gets the DID the token is about (`sub` claim)
+    #[wasm_bindgen(getter)]
+    pub fn subject(&self) -> Option<String> {
+        self.inner.subject.as_ref().map(|did| did.to_string())
+    }
+
+    /// Sets the DID the token is about (`sub` claim)
+    #[wasm_bindgen(setter)]
+    pub fn set_subject(&mut self, did: Option<String>) -> Result<(), JsValue> {
+        self.inner.subject = did.map(|did| did.parse::<Did>()).transpose().map_err_to_js()?;
+        Ok(())
+    }
+
+    /// Gets the array of recipients the token is intended for (`aud` claim)
+    #[wasm_bindgen(getter)]
+    pub fn audience(&self) -> Result<JsValue, JsValue> {
+        Ok(to_value(&self.inner.audience)?)
+    }
+
+    /// Sets the array of recipients the token is intended for (`aud` claim)
+    #[wasm_bindgen(setter)]
+    pub fn set_audience(&mut self, audience: JsValue) -> Result<(), JsValue> {
+        self.inner.audience = from_value(audience)?;
+        Ok(())
+    }
+
+    /// Gets the nonce binding the token to a single request (`nonce` claim)
+    #[wasm_bindgen(getter)]
+    pub fn nonce(&self) -> Option<String> {
+        self.inner.nonce.clone()
+    }
+
+    /// Sets the nonce binding the token to a single request (`nonce` claim)
+    #[wasm_bindgen(setter)]
+    pub fn set_nonce(&mut self, nonce: Option<String>) {
+        self.inner.nonce = nonce;
+    }
+
+    /// Adds an application specific claim to the token. Registered claim names are rejected.
+    #[wasm_bindgen(js_name = setCustomClaim)]
+    pub fn set_custom_claim(&mut self, name: &str, value: JsValue) -> Result<(), JsValue> {
+        let value: serde_json::Value = from_value(value)?;
+        self.inner.set_custom_claim(name, &value).map_err_to_js()
+    }
+
     /// Signs and serializes the token with the given multicipher {@link PrivateKey}
     #[wasm_bindgen]
     pub fn sign(&self, sk: &JsMPrivateKey) -> Result<String, JsValue> {
@@ -63,6 +137,65 @@ impl JsJwtParser {
         Ok(JsJwtParser { inner })
     }
 
+    /// Parse JWT from a string that does not carry the signer public key in its `kid` header, like
+    /// the ones created by standard JWT libraries
+    #[wasm_bindgen(js_name = withPublicKey)]
+    pub fn with_public_key(token: &str, pk: &JsMPublicKey) -> Result<JsJwtParser, JsValue> {
+        let inner = JwtParser::with_public_key(token, pk.inner(), None).map_err_to_js()?;
+        Ok(JsJwtParser { inner })
+    }
+
+    /// Parse JWT from a string and check that it was issued for the given audience and optionally
+    /// by the given DID
+    #[wasm_bindgen(js_name = validateWith)]
+    pub fn validate_with(
+        token: &str, audience: Option<String>, issuer: Option<String>,
+    ) -> Result<JsJwtParser, JsValue> {
+        let issuer = issuer.map(|did| did.parse::<Did>()).transpose().map_err_to_js()?;
+        let validation = JwtValidation { audience, issuer, ..Default::default() };
+        let inner = JwtParser::validate_with(token, &validation, None).map_err_to_js()?;
+        Ok(JsJwtParser { inner })
+    }
+
+    /// Returns the issuer of the token, which is a DID for tokens issued by {@link JwtBuilder}
+    #[wasm_bindgen(getter)]
+    pub fn issuer(&self) -> Option<String> {
+        self.inner.issuer().map(|issuer| issuer.to_owned())
+    }
+
+    /// Returns the subject of the token, which is a DID for tokens issued by {@link JwtBuilder}
+    #[wasm_bindgen(getter)]
+    pub fn subject(&self) -> Option<String> {
+        self.inner.subject().map(|subject| subject.to_owned())
+    }
+
+    /// Returns the array of recipients the token is intended for
+    #[wasm_bindgen(getter)]
+    pub fn audience(&self) -> Result<JsValue, JsValue> {
+        Ok(to_value(self.inner.audience())?)
+    }
+
+    /// Returns the nonce of the token
+    #[wasm_bindgen(getter)]
+    pub fn nonce(&self) -> Option<String> {
+        self.inner.nonce().map(|nonce| nonce.to_owned())
+    }
+
+    /// Returns an application specific claim of the token, or undefined if it is missing
+    #[wasm_bindgen(js_name = customClaim)]
+    pub fn custom_claim(&self, name: &str) -> Result<JsValue, JsValue> {
+        match self.inner.custom_claims().get(name) {
+            Some(value) => Ok(to_value(value)?),
+            None => Ok(JsValue::UNDEFINED),
+        }
+    }
+
+    /// Returns the `alg` the token was signed with
+    #[wasm_bindgen(getter)]
+    pub fn algorithm(&self) -> String {
+        self.inner.algorithm().to_string()
+    }
+
     /// Returns the public key that signed the token
     #[wasm_bindgen(getter = publicKey)]
     pub fn public_key(&self) -> JsMPublicKey {
@@ -7,12 +7,16 @@
 mod coeus;
 mod did;
 mod jwt;
+mod process;
 mod sign;
+mod vc;
 
 pub use coeus::*;
 pub use did::*;
 pub use jwt::*;
+pub use process::*;
 pub use sign::*;
+pub use vc::*;
 
 // imports from standard library
 
@@ -29,10 +33,14 @@ use iop_journal_proto::*;
 use iop_keyvault_wasm::*;
 use iop_morpheus_proto::{
     crypto::{
-        jwt::{JwtBuilder, JwtParser},
+        jwt::{JwtAlgorithm, JwtBuilder, JwtParser, JwtValidation},
         sign::{Signable, Signed},
     },
-    data::{Did, ValidationIssue, ValidationResult},
+    data::{
+        schemas_by_id, ClaimPresentation, Did, MorpheusSchema, Process, ProvenClaim,
+        ValidationIssue, ValidationResult, VerifiableCredential, VerifiablePresentation,
+        WitnessRequest, WitnessStatement,
+    },
 };
 
 pub use iop_keyvault_wasm::*;
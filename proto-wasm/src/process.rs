@@ -0,0 +1,29 @@
+use super::*;
+
+/// Checks a witness request before signing or accepting it. The request has to refer to the given process, and its claim and
+/// evidence have to match the JSON schemas of the process. The schemas referred to by the process are looked up by content id
+/// in the provided array of JSON schema documents.
+#[wasm_bindgen(js_name = validateWitnessRequest)]
+pub fn validate_witness_request(
+    process: &JsValue, schemas: &JsValue, request: &JsValue,
+) -> Result<(), JsValue> {
+    let process: Process = from_value(process.clone())?;
+    let schemas: Vec<MorpheusSchema> = from_value(schemas.clone())?;
+    let request: WitnessRequest = from_value(request.clone())?;
+    let schemas = schemas_by_id(schemas).map_err_to_js()?;
+    process.validate_request(&request, &schemas).map_err_to_js()
+}
+
+/// Checks a witness statement before signing or accepting it. The statement has to refer to the given process, and its claim and
+/// constraints have to match the JSON schemas of the process. The schemas referred to by the process are looked up by content id
+/// in the provided array of JSON schema documents.
+#[wasm_bindgen(js_name = validateWitnessStatement)]
+pub fn validate_witness_statement(
+    process: &JsValue, schemas: &JsValue, statement: &JsValue,
+) -> Result<(), JsValue> {
+    let process: Process = from_value(process.clone())?;
+    let schemas: Vec<MorpheusSchema> = from_value(schemas.clone())?;
+    let statement: WitnessStatement = from_value(statement.clone())?;
+    let schemas = schemas_by_id(schemas).map_err_to_js()?;
+    process.validate_statement(&statement, &schemas).map_err_to_js()
+}
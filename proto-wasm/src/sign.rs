@@ -70,6 +70,15 @@ impl JsSignedBytes {
     ) -> Result<JsValue, JsValue> {
         validate_with_did_doc(&self.inner, did_doc_str, from_height_inc, until_height_exc)
     }
+
+    /// Like {@link validateWithDidDoc}, but the time period is given with optional RFC 3339 timestamps instead of block heights. The
+    /// DID document must have been queried from a node that tracks block timestamps.
+    #[wasm_bindgen(js_name = validateWithDidDocAtTime)]
+    pub fn validate_with_did_doc_at_time(
+        &self, did_doc_str: &str, from_inc: &JsValue, until_exc: &JsValue,
+    ) -> Result<JsValue, JsValue> {
+        validate_with_did_doc_at_time(&self.inner, did_doc_str, from_inc, until_exc)
+    }
 }
 
 impl From<Signed<Box<[u8]>>> for JsSignedBytes {
@@ -159,6 +168,15 @@ impl JsSignedJson {
         validate_with_did_doc(&self.inner, did_doc_str, from_height_inc, until_height_exc)
     }
 
+    /// Like {@link validateWithDidDoc}, but the time period is given with optional RFC 3339 timestamps instead of block heights. The
+    /// DID document must have been queried from a node that tracks block timestamps.
+    #[wasm_bindgen(js_name = validateWithDidDocAtTime)]
+    pub fn validate_with_did_doc_at_time(
+        &self, did_doc_str: &str, from_inc: &JsValue, until_exc: &JsValue,
+    ) -> Result<JsValue, JsValue> {
+        validate_with_did_doc_at_time(&self.inner, did_doc_str, from_inc, until_exc)
+    }
+
     /// Serialize this object as a JSON in a format used by IOP SSI in several places
     #[wasm_bindgen(js_name = toJSON)]
     pub fn to_json(&self) -> Result<JsValue, JsValue> {
@@ -197,6 +215,17 @@ fn validate_with_did_doc<T: Signable>(
     Ok(JsValidationResult { inner: result }.into())
 }
 
+fn validate_with_did_doc_at_time<T: Signable>(
+    signed: &Signed<T>, did_doc_str: &str, from_inc: &JsValue, until_exc: &JsValue,
+) -> Result<JsValue, JsValue> {
+    let did_doc = serde_json::from_str(did_doc_str).map_err_to_js()?;
+    let from_inc = from_value(from_inc.clone())?;
+    let until_exc = from_value(until_exc.clone())?;
+    let result =
+        signed.validate_with_did_doc_at_time(&did_doc, from_inc, until_exc).map_err_to_js()?;
+    Ok(JsValidationResult { inner: result }.into())
+}
+
 /// A single issue found while validating against a DID document.
 ///
 /// @see SignedBytes.validateWithDidDoc, SignedJson.validateWithDidDoc
@@ -0,0 +1,65 @@
+use super::*;
+
+/// Converts a signed witness statement into a W3C Verifiable Credential. The multicipher signature is kept in the proof section of
+/// the credential.
+#[wasm_bindgen(js_name = witnessStatementToCredential)]
+pub fn witness_statement_to_credential(signed_statement: &JsValue) -> Result<JsValue, JsValue> {
+    let signed: Signed<WitnessStatement> = from_value(signed_statement.clone())?;
+    let vc = VerifiableCredential::from_signed(&signed);
+    let res = to_value(&vc)?;
+    Ok(res)
+}
+
+/// Verifies the signature of a W3C Verifiable Credential created by {@link witnessStatementToCredential} and returns the signed
+/// witness statement it was created from.
+#[wasm_bindgen(js_name = credentialToWitnessStatement)]
+pub fn credential_to_witness_statement(credential: &JsValue) -> Result<JsValue, JsValue> {
+    let vc: VerifiableCredential = from_value(credential.clone())?;
+    let signed = vc.verify().map_err_to_js()?;
+    let res = to_value(&signed)?;
+    Ok(res)
+}
+
+/// Converts a signed claim presentation into a W3C Verifiable Presentation. Statements in the presentation become Verifiable
+/// Credentials, so properties mapped to the credential must not be masked in them.
+#[wasm_bindgen(js_name = claimPresentationToPresentation)]
+pub fn claim_presentation_to_presentation(
+    signed_presentation: &JsValue,
+) -> Result<JsValue, JsValue> {
+    let signed: Signed<ClaimPresentation> = from_value(signed_presentation.clone())?;
+    let vp = VerifiablePresentation::from_signed(&signed).map_err_to_js()?;
+    let res = to_value(&vp)?;
+    Ok(res)
+}
+
+/// Verifies the signatures of a W3C Verifiable Presentation created by {@link claimPresentationToPresentation} and of all
+/// credentials in it, then returns the signed claim presentation it was created from.
+#[wasm_bindgen(js_name = presentationToClaimPresentation)]
+pub fn presentation_to_claim_presentation(presentation: &JsValue) -> Result<JsValue, JsValue> {
+    let vp: VerifiablePresentation = from_value(presentation.clone())?;
+    let signed = vp.verify().map_err_to_js()?;
+    let res = to_value(&signed)?;
+    Ok(res)
+}
+
+/// Creates a proven claim from a signed witness statement, disclosing only the given paths of the claim content, like
+/// `['.dateOfBirth', '.address.city']`. Everything else in the claim is masked, but the signature of the witness still validates.
+#[wasm_bindgen(js_name = discloseClaim)]
+pub fn disclose_claim(
+    signed_statement: &JsValue, content_paths: &JsValue,
+) -> Result<JsValue, JsValue> {
+    let signed: Signed<WitnessStatement> = from_value(signed_statement.clone())?;
+    let content_paths: Vec<String> = from_value(content_paths.clone())?;
+    let content_paths = content_paths.iter().map(|p| p.as_str()).collect::<Vec<_>>();
+    let proven = ProvenClaim::disclose(&signed, &content_paths).map_err_to_js()?;
+    let res = to_value(&proven)?;
+    Ok(res)
+}
+
+/// Checks that the partially masked claim of a proven claim is the one signed in all of its statements, and that the signatures are
+/// valid. Returns the content id of the claim.
+#[wasm_bindgen(js_name = verifyProvenClaim)]
+pub fn verify_proven_claim(proven_claim: &JsValue) -> Result<String, JsValue> {
+    let proven: ProvenClaim = from_value(proven_claim.clone())?;
+    proven.verify().map_err_to_js()
+}
@@ -19,6 +19,7 @@ declare -a crates=(
 	"iop-proto-wasm"
 	"iop-hydra-sdk"
 	"iop-morpheus-sdk"
+	"iop-coeus-sdk"
 	"iop-sdk"
 	"iop-sdk-wasm"
 	"iop-node-wasm"
@@ -17,6 +17,7 @@ function replace_json() {
 declare -a crates=(
     "coeus-node"
     "coeus-proto"
+    "coeus-sdk"
     "hydra-proto"
     "hydra-sdk"
     "journal-proto"
@@ -1,5 +1,8 @@
 mod operation;
+mod plugin;
 mod policy;
+mod private;
+mod public;
 mod signed;
 mod tx;
 
@@ -8,5 +11,6 @@ use super::*;
 use iop_coeus_proto::{
     NoncedBundle, RegistrationPolicy, SignedBundle, SubtreePolicies, UserOperation,
 };
+use iop_coeus_sdk::vault::{Plugin as CoeusPlugin, Private as CoeusPrivate, Public as CoeusPublic};
 use iop_hydra_proto::txtype::coeus;
 use iop_journal_proto::{BlockCount, BlockHeight, Nonce};
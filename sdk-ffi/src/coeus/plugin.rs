@@ -0,0 +1,57 @@
+use super::*;
+
+pub struct CCoeusPlugin {
+    pub(crate) plugin: BoundPlugin<CoeusPlugin, CoeusPublic, CoeusPrivate>,
+}
+
+#[no_mangle]
+pub extern "C" fn CoeusPlugin_init(
+    vault: *mut Vault, unlock_pwd: *const raw::c_char,
+) -> CPtrResult<raw::c_void> {
+    let vault = unsafe { convert::borrow_mut_in(vault) };
+    let mut fun = || {
+        let unlock_password = unsafe { convert::str_in(unlock_pwd)? };
+        CoeusPlugin::init(vault, unlock_password)?;
+        Ok(())
+    };
+    cresult_void(fun())
+}
+
+#[no_mangle]
+pub extern "C" fn CoeusPlugin_get(vault: *mut Vault) -> CPtrResult<CCoeusPlugin> {
+    let vault = unsafe { convert::borrow_mut_in(vault) };
+    let fun = || {
+        let plugin = CoeusPlugin::get(vault)?;
+        let coeus = CCoeusPlugin { plugin };
+        Ok(convert::move_out(coeus))
+    };
+    cresult(fun())
+}
+
+#[no_mangle]
+pub extern "C" fn CoeusPlugin_public_get(coeus: *mut CCoeusPlugin) -> CPtrResult<CoeusPublic> {
+    let coeus = unsafe { convert::borrow_in(coeus) };
+    let fun = || {
+        let public = coeus.plugin.public()?;
+        Ok(convert::move_out(public))
+    };
+    cresult(fun())
+}
+
+#[no_mangle]
+pub extern "C" fn CoeusPlugin_private(
+    coeus: *mut CCoeusPlugin, unlock_pwd: *const raw::c_char,
+) -> CPtrResult<CoeusPrivate> {
+    let coeus = unsafe { convert::borrow_in(coeus) };
+    let fun = || {
+        let unlock_password = unsafe { convert::str_in(unlock_pwd)? };
+        let private = coeus.plugin.private(unlock_password)?;
+        Ok(convert::move_out(private))
+    };
+    cresult(fun())
+}
+
+#[no_mangle]
+pub extern "C" fn delete_CoeusPlugin(coeus: *mut CCoeusPlugin) {
+    delete(coeus)
+}
@@ -0,0 +1,55 @@
+use super::*;
+
+#[no_mangle]
+pub extern "C" fn delete_CoeusPrivate(private: *mut CoeusPrivate) {
+    delete(private)
+}
+
+#[no_mangle]
+pub extern "C" fn CoeusPrivate_public_get(private: *mut CoeusPrivate) -> CPtrResult<CoeusPublic> {
+    let fun = || {
+        let private = unsafe { convert::borrow_in(private) };
+        let public = private.public();
+        Ok(convert::move_out(public))
+    };
+    cresult(fun())
+}
+
+#[no_mangle]
+pub extern "C" fn CoeusPrivate_key(
+    private: *mut CoeusPrivate, idx: i32,
+) -> CPtrResult<MPrivateKey> {
+    let fun = || {
+        let private = unsafe { convert::borrow_mut_in(private) };
+        let sk = private.key_mut(idx)?;
+        Ok(convert::move_out(sk))
+    };
+    cresult(fun())
+}
+
+#[no_mangle]
+pub extern "C" fn CoeusPrivate_key_by_pk(
+    private: *mut CoeusPrivate, pk: *const MPublicKey,
+) -> CPtrResult<MPrivateKey> {
+    let private = unsafe { convert::borrow_in(private) };
+    let pk = unsafe { convert::borrow_in(pk) };
+    let fun = || {
+        let sk = private.key_by_pk(pk)?;
+        Ok(convert::move_out(sk))
+    };
+    cresult(fun())
+}
+
+#[no_mangle]
+pub extern "C" fn CoeusPrivate_sign(
+    private: *mut CoeusPrivate, pk: *const MPublicKey, nonced_bundle: *const NoncedBundle,
+) -> CPtrResult<SignedBundle> {
+    let pk = unsafe { convert::borrow_in(pk) };
+    let nonced_bundle = unsafe { convert::borrow_in(nonced_bundle) };
+    let fun = || {
+        let private = unsafe { convert::borrow_mut_in(private) };
+        let signed = private.sign(pk, nonced_bundle.to_owned())?;
+        Ok(convert::move_out(signed))
+    };
+    cresult(fun())
+}
@@ -0,0 +1,117 @@
+use super::*;
+
+#[no_mangle]
+pub extern "C" fn delete_CoeusPublic(public: *mut CoeusPublic) {
+    delete(public)
+}
+
+#[no_mangle]
+pub extern "C" fn CoeusPublic_count_get(public: *mut CoeusPublic) -> CPtrResult<u32> {
+    let public = unsafe { convert::borrow_in(public) };
+    let fun = || {
+        let count = public.len()?;
+        Ok(convert::move_out(count))
+    };
+    cresult(fun())
+}
+
+#[no_mangle]
+pub extern "C" fn CoeusPublic_key(public: *mut CoeusPublic, idx: i32) -> CPtrResult<MPublicKey> {
+    let public = unsafe { convert::borrow_in(public) };
+    let fun = || {
+        let pk = public.key(idx)?;
+        Ok(convert::move_out(pk))
+    };
+    cresult(fun())
+}
+
+#[no_mangle]
+pub extern "C" fn CoeusPublic_last_nonce(
+    public: *mut CoeusPublic, pk: *const MPublicKey,
+) -> CPtrResult<Nonce> {
+    let public = unsafe { convert::borrow_in(public) };
+    let pk = unsafe { convert::borrow_in(pk) };
+    let fun = || {
+        let nonce = public.last_nonce(pk)?;
+        Ok(convert::move_out(nonce))
+    };
+    cresult(fun())
+}
+
+#[no_mangle]
+pub extern "C" fn CoeusPublic_set_last_nonce(
+    public: *mut CoeusPublic, pk: *const MPublicKey, nonce: Nonce,
+) -> CPtrResult<raw::c_void> {
+    let pk = unsafe { convert::borrow_in(pk) };
+    let fun = || {
+        let public = unsafe { convert::borrow_mut_in(public) };
+        public.set_last_nonce(pk, nonce)
+    };
+    cresult_void(fun())
+}
+
+#[no_mangle]
+pub extern "C" fn CoeusPublic_bundle(
+    public: *mut CoeusPublic, pk: *const MPublicKey, builder: *const Vec<UserOperation>,
+) -> CPtrResult<NoncedBundle> {
+    let public = unsafe { convert::borrow_in(public) };
+    let pk = unsafe { convert::borrow_in(pk) };
+    let builder = unsafe { convert::borrow_in(builder) };
+    let fun = || {
+        let bundle = public.bundle(pk, builder.to_owned())?;
+        Ok(convert::move_out(bundle))
+    };
+    cresult(fun())
+}
+
+#[no_mangle]
+pub extern "C" fn CoeusPublic_domains(
+    public: *mut CoeusPublic, pk: *const MPublicKey,
+) -> CPtrResult<CSlice<*mut raw::c_char>> {
+    let public = unsafe { convert::borrow_in(public) };
+    let pk = unsafe { convert::borrow_in(pk) };
+    let fun = || {
+        let domains = public.domains(pk)?.iter().map(|d| d.to_string()).collect::<Vec<_>>();
+        Ok(convert::move_out(CSlice::from(domains)))
+    };
+    cresult(fun())
+}
+
+#[no_mangle]
+pub extern "C" fn CoeusPublic_owner_of(
+    public: *mut CoeusPublic, domain: *const raw::c_char,
+) -> CPtrResult<MPublicKey> {
+    let public = unsafe { convert::borrow_in(public) };
+    let fun = || {
+        let domain = unsafe { convert::str_in(domain)? }.parse()?;
+        let pk = public.owner_of(&domain)?;
+        Ok(convert::move_out(pk))
+    };
+    cresult(fun())
+}
+
+#[no_mangle]
+pub extern "C" fn CoeusPublic_add_domain(
+    public: *mut CoeusPublic, pk: *const MPublicKey, domain: *const raw::c_char,
+) -> CPtrResult<raw::c_void> {
+    let pk = unsafe { convert::borrow_in(pk) };
+    let fun = || {
+        let public = unsafe { convert::borrow_mut_in(public) };
+        let domain = unsafe { convert::str_in(domain)? }.parse()?;
+        public.add_domain(pk, &domain)
+    };
+    cresult_void(fun())
+}
+
+#[no_mangle]
+pub extern "C" fn CoeusPublic_remove_domain(
+    public: *mut CoeusPublic, pk: *const MPublicKey, domain: *const raw::c_char,
+) -> CPtrResult<raw::c_void> {
+    let pk = unsafe { convert::borrow_in(pk) };
+    let fun = || {
+        let public = unsafe { convert::borrow_mut_in(public) };
+        let domain = unsafe { convert::str_in(domain)? }.parse()?;
+        public.remove_domain(pk, &domain)
+    };
+    cresult_void(fun())
+}
@@ -0,0 +1,9 @@
+mod plugin;
+mod private;
+mod public;
+
+use super::*;
+
+use iop_morpheus_sdk::credential::{
+    Plugin as CredentialPlugin, Private as CredentialPrivate, Public as CredentialPublic,
+};
@@ -0,0 +1,56 @@
+use super::*;
+
+pub struct CCredentialPlugin {
+    pub(crate) plugin: BoundPlugin<CredentialPlugin, CredentialPublic, CredentialPrivate>,
+}
+
+#[no_mangle]
+pub extern "C" fn CredentialPlugin_init(vault: *mut Vault) -> CPtrResult<raw::c_void> {
+    let vault = unsafe { convert::borrow_mut_in(vault) };
+    let mut fun = || {
+        CredentialPlugin::init(vault)?;
+        Ok(())
+    };
+    cresult_void(fun())
+}
+
+#[no_mangle]
+pub extern "C" fn CredentialPlugin_get(vault: *mut Vault) -> CPtrResult<CCredentialPlugin> {
+    let vault = unsafe { convert::borrow_mut_in(vault) };
+    let fun = || {
+        let plugin = CredentialPlugin::get(vault)?;
+        let credentials = CCredentialPlugin { plugin };
+        Ok(convert::move_out(credentials))
+    };
+    cresult(fun())
+}
+
+#[no_mangle]
+pub extern "C" fn CredentialPlugin_public_get(
+    credentials: *mut CCredentialPlugin,
+) -> CPtrResult<CredentialPublic> {
+    let credentials = unsafe { convert::borrow_in(credentials) };
+    let fun = || {
+        let public = credentials.plugin.public()?;
+        Ok(convert::move_out(public))
+    };
+    cresult(fun())
+}
+
+#[no_mangle]
+pub extern "C" fn CredentialPlugin_private(
+    credentials: *mut CCredentialPlugin, unlock_pwd: *const raw::c_char,
+) -> CPtrResult<CredentialPrivate> {
+    let credentials = unsafe { convert::borrow_in(credentials) };
+    let fun = || {
+        let unlock_password = unsafe { convert::str_in(unlock_pwd)? };
+        let private = credentials.plugin.private(unlock_password)?;
+        Ok(convert::move_out(private))
+    };
+    cresult(fun())
+}
+
+#[no_mangle]
+pub extern "C" fn delete_CredentialPlugin(credentials: *mut CCredentialPlugin) {
+    delete(credentials)
+}
@@ -0,0 +1,67 @@
+use super::*;
+
+#[no_mangle]
+pub extern "C" fn delete_CredentialPrivate(private: *mut CredentialPrivate) {
+    delete(private)
+}
+
+#[no_mangle]
+pub extern "C" fn CredentialPrivate_public_get(
+    private: *mut CredentialPrivate,
+) -> CPtrResult<CredentialPublic> {
+    let fun = || {
+        let private = unsafe { convert::borrow_in(private) };
+        let public = private.public();
+        Ok(convert::move_out(public))
+    };
+    cresult(fun())
+}
+
+#[no_mangle]
+pub extern "C" fn CredentialPrivate_add(
+    private: *mut CredentialPrivate, statement: *const Signed<serde_json::Value>,
+) -> CPtrResult<raw::c_char> {
+    let statement = unsafe { convert::borrow_in(statement) };
+    let fun = || {
+        let private = unsafe { convert::borrow_mut_in(private) };
+        let (public_key, content, signature, nonce) = statement.to_owned().into_parts();
+        let content: WitnessStatement = serde_json::from_value(content)?;
+        let statement = Signed::from_parts(public_key, content, signature, nonce);
+        let content_id = private.add(statement)?;
+        Ok(convert::string_out(content_id))
+    };
+    cresult(fun())
+}
+
+#[no_mangle]
+pub extern "C" fn CredentialPrivate_get(
+    private: *mut CredentialPrivate, content_id: *const raw::c_char,
+) -> CPtrResult<Signed<serde_json::Value>> {
+    let private = unsafe { convert::borrow_in(private) };
+    let fun = || {
+        let content_id = unsafe { convert::str_in(content_id)? };
+        let (public_key, content, signature, nonce) = private.get(content_id)?.into_parts();
+        let content = serde_json::to_value(content)?;
+        let statement = Signed::from_parts(public_key, content, signature, nonce);
+        Ok(convert::move_out(statement))
+    };
+    cresult(fun())
+}
+
+/// Both the content ids and the licenses are passed as JSON arrays, the assembled presentation is returned as JSON.
+#[no_mangle]
+pub extern "C" fn CredentialPrivate_presentation(
+    private: *mut CredentialPrivate, content_ids: *const raw::c_char, licenses: *const raw::c_char,
+) -> CPtrResult<raw::c_char> {
+    let private = unsafe { convert::borrow_in(private) };
+    let fun = || {
+        let content_ids: Vec<String> =
+            serde_json::from_str(unsafe { convert::str_in(content_ids)? })?;
+        let licenses: Vec<Signed<License>> =
+            serde_json::from_str(unsafe { convert::str_in(licenses)? })?;
+        let presentation = private.presentation(&content_ids, licenses)?;
+        let json_str = serde_json::to_string(&presentation)?;
+        Ok(convert::string_out(json_str))
+    };
+    cresult(fun())
+}
@@ -0,0 +1,92 @@
+use super::*;
+
+#[no_mangle]
+pub extern "C" fn delete_CredentialPublic(public: *mut CredentialPublic) {
+    delete(public)
+}
+
+#[no_mangle]
+pub extern "C" fn CredentialPublic_count_get(public: *mut CredentialPublic) -> CPtrResult<u32> {
+    let public = unsafe { convert::borrow_in(public) };
+    let fun = || {
+        let count = public.len()?;
+        Ok(convert::move_out(count))
+    };
+    cresult(fun())
+}
+
+#[no_mangle]
+pub extern "C" fn CredentialPublic_content_ids(
+    public: *mut CredentialPublic,
+) -> CPtrResult<CSlice<*mut raw::c_char>> {
+    let public = unsafe { convert::borrow_in(public) };
+    let fun = || {
+        let ids = public.entries()?.iter().map(|e| e.content_id().to_owned()).collect::<Vec<_>>();
+        Ok(convert::move_out(CSlice::from(ids)))
+    };
+    cresult(fun())
+}
+
+#[no_mangle]
+pub extern "C" fn CredentialPublic_subject_of(
+    public: *mut CredentialPublic, content_id: *const raw::c_char,
+) -> CPtrResult<Did> {
+    let public = unsafe { convert::borrow_in(public) };
+    let fun = || {
+        let content_id = unsafe { convert::str_in(content_id)? };
+        let subject = public.entry(content_id)?.subject()?;
+        Ok(convert::move_out(subject))
+    };
+    cresult(fun())
+}
+
+#[no_mangle]
+pub extern "C" fn CredentialPublic_process_of(
+    public: *mut CredentialPublic, content_id: *const raw::c_char,
+) -> CPtrResult<raw::c_char> {
+    let public = unsafe { convert::borrow_in(public) };
+    let fun = || {
+        let content_id = unsafe { convert::str_in(content_id)? };
+        let process_id = public.entry(content_id)?.process_id().to_owned();
+        Ok(convert::string_out(process_id))
+    };
+    cresult(fun())
+}
+
+#[no_mangle]
+pub extern "C" fn CredentialPublic_by_subject(
+    public: *mut CredentialPublic, subject: *const Did,
+) -> CPtrResult<CSlice<*mut raw::c_char>> {
+    let public = unsafe { convert::borrow_in(public) };
+    let subject = unsafe { convert::borrow_in(subject) };
+    let fun = || {
+        let ids = public.by_subject(subject)?;
+        Ok(convert::move_out(CSlice::from(ids)))
+    };
+    cresult(fun())
+}
+
+#[no_mangle]
+pub extern "C" fn CredentialPublic_by_process(
+    public: *mut CredentialPublic, process_id: *const raw::c_char,
+) -> CPtrResult<CSlice<*mut raw::c_char>> {
+    let public = unsafe { convert::borrow_in(public) };
+    let fun = || {
+        let process_id = unsafe { convert::str_in(process_id)? };
+        let ids = public.by_process(process_id)?;
+        Ok(convert::move_out(CSlice::from(ids)))
+    };
+    cresult(fun())
+}
+
+#[no_mangle]
+pub extern "C" fn CredentialPublic_remove(
+    public: *mut CredentialPublic, content_id: *const raw::c_char,
+) -> CPtrResult<raw::c_void> {
+    let fun = || {
+        let public = unsafe { convert::borrow_mut_in(public) };
+        let content_id = unsafe { convert::str_in(content_id)? };
+        public.remove(content_id)
+    };
+    cresult_void(fun())
+}
@@ -31,6 +31,14 @@ pub(crate) unsafe fn str_in<'a>(s: *const raw::c_char) -> Result<&'a str> {
     Ok(s)
 }
 
+pub(crate) unsafe fn str_in_opt<'a>(s: *const raw::c_char) -> Result<Option<&'a str>> {
+    if s.is_null() {
+        Ok(None)
+    } else {
+        str_in(s).map(Some)
+    }
+}
+
 pub(crate) fn string_out(s: String) -> *mut raw::c_char {
     let c_str = ffi::CString::new(s).unwrap();
     c_str.into_raw()
@@ -24,6 +24,20 @@ pub extern "C" fn HydraPlugin_init(
     cresult_void(fun())
 }
 
+#[no_mangle]
+pub extern "C" fn HydraPlugin_import_xpub(
+    vault: *mut Vault, network: *const raw::c_char, account: i32, xpub: *const raw::c_char,
+) -> CPtrResult<raw::c_void> {
+    let vault = unsafe { convert::borrow_mut_in(vault) };
+    let mut fun = || {
+        let xpub = unsafe { convert::str_in(xpub)? };
+        let params = params(network, account)?;
+        Plugin::import_xpub(vault, &params, xpub)?;
+        Ok(())
+    };
+    cresult_void(fun())
+}
+
 #[no_mangle]
 pub extern "C" fn HydraPlugin_get(
     vault: *mut Vault, network: *const raw::c_char, account: i32,
@@ -61,6 +75,16 @@ pub extern "C" fn HydraPlugin_public(hydra: *mut CHydraPlugin) -> CPtrResult<Pub
     cresult(fun())
 }
 
+#[no_mangle]
+pub extern "C" fn HydraPlugin_watch_only_get(hydra: *mut CHydraPlugin) -> CPtrResult<raw::c_uchar> {
+    let hydra = unsafe { convert::borrow_in(hydra) };
+    let fun = || {
+        let is_watch_only = hydra.plugin.plugin().is_watch_only();
+        Ok(convert::bool_out(is_watch_only))
+    };
+    cresult(fun())
+}
+
 #[no_mangle]
 pub extern "C" fn delete_HydraPlugin(hydra: *mut CHydraPlugin) {
     delete(hydra)
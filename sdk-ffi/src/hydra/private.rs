@@ -32,6 +32,24 @@ pub extern "C" fn HydraPrivate_xprv_get(private: *mut Private) -> *mut raw::c_ch
     convert::string_out(xprv)
 }
 
+#[no_mangle]
+pub extern "C" fn HydraPrivate_master_fingerprint_get(private: *mut Private) -> *mut raw::c_char {
+    let private = unsafe { convert::borrow_in(private) };
+    convert::string_out(private.master_fingerprint().to_string())
+}
+
+#[no_mangle]
+pub extern "C" fn HydraPrivate_key_origin_get(private: *mut Private) -> *mut raw::c_char {
+    let private = unsafe { convert::borrow_in(private) };
+    convert::string_out(private.key_origin())
+}
+
+#[no_mangle]
+pub extern "C" fn HydraPrivate_descriptor(private: *mut Private, change: bool) -> *mut raw::c_char {
+    let private = unsafe { convert::borrow_in(private) };
+    convert::string_out(private.descriptor(Some(Chain::from(change))))
+}
+
 #[no_mangle]
 pub extern "C" fn HydraPrivate_receive_keys_get(private: *mut Private) -> CPtrResult<u32> {
     let private = unsafe { convert::borrow_in(private) };
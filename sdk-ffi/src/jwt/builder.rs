@@ -58,3 +58,114 @@ pub extern "C" fn JwtBuilder_time_to_live_get(builder: *const JwtBuilder) -> i64
     let builder = unsafe { convert::borrow_in(builder) };
     builder.time_to_live.num_seconds()
 }
+
+#[no_mangle]
+pub extern "C" fn JwtBuilder_algorithm_set(
+    builder: *mut JwtBuilder, algorithm: *const raw::c_char,
+) -> CPtrResult<raw::c_void> {
+    let builder = unsafe { convert::borrow_mut_in(builder) };
+    let mut fun = || {
+        let algorithm = unsafe { convert::str_in_opt(algorithm)? };
+        builder.algorithm = algorithm.map(JwtAlgorithm::from_name).transpose()?;
+        Ok(())
+    };
+    cresult_void(fun())
+}
+
+#[no_mangle]
+pub extern "C" fn JwtBuilder_algorithm_get(builder: *const JwtBuilder) -> *mut raw::c_char {
+    let builder = unsafe { convert::borrow_in(builder) };
+    convert::string_out_opt(builder.algorithm.map(|alg| alg.to_string()))
+}
+
+#[no_mangle]
+pub extern "C" fn JwtBuilder_issuer_set(
+    builder: *mut JwtBuilder, did: *const raw::c_char,
+) -> CPtrResult<raw::c_void> {
+    let builder = unsafe { convert::borrow_mut_in(builder) };
+    let mut fun = || {
+        let did = unsafe { convert::str_in_opt(did)? };
+        builder.issuer = did.map(Did::from_str).transpose()?;
+        Ok(())
+    };
+    cresult_void(fun())
+}
+
+#[no_mangle]
+pub extern "C" fn JwtBuilder_issuer_get(builder: *const JwtBuilder) -> *mut raw::c_char {
+    let builder = unsafe { convert::borrow_in(builder) };
+    convert::string_out_opt(builder.issuer.as_ref().map(|did| did.to_string()))
+}
+
+#[no_mangle]
+pub extern "C" fn JwtBuilder_subject_set(
+    builder: *mut JwtBuilder, did: *const raw::c_char,
+) -> CPtrResult<raw::c_void> {
+    let builder = unsafe { convert::borrow_mut_in(builder) };
+    let mut fun = || {
+        let did = unsafe { convert::str_in_opt(did)? };
+        builder.subject = did.map(Did::from_str).transpose()?;
+        Ok(())
+    };
+    cresult_void(fun())
+}
+
+#[no_mangle]
+pub extern "C" fn JwtBuilder_subject_get(builder: *const JwtBuilder) -> *mut raw::c_char {
+    let builder = unsafe { convert::borrow_in(builder) };
+    convert::string_out_opt(builder.subject.as_ref().map(|did| did.to_string()))
+}
+
+#[no_mangle]
+pub extern "C" fn JwtBuilder_audience_add(
+    builder: *mut JwtBuilder, audience: *const raw::c_char,
+) -> CPtrResult<raw::c_void> {
+    let builder = unsafe { convert::borrow_mut_in(builder) };
+    let mut fun = || {
+        let audience = unsafe { convert::str_in(audience)? };
+        builder.audience.push(audience.to_owned());
+        Ok(())
+    };
+    cresult_void(fun())
+}
+
+#[no_mangle]
+pub extern "C" fn JwtBuilder_audience_get(
+    builder: *const JwtBuilder,
+) -> *mut CSlice<*mut raw::c_char> {
+    let builder = unsafe { convert::borrow_in(builder) };
+    convert::move_out(CSlice::from(builder.audience.clone()))
+}
+
+#[no_mangle]
+pub extern "C" fn JwtBuilder_nonce_set(
+    builder: *mut JwtBuilder, nonce: *const raw::c_char,
+) -> CPtrResult<raw::c_void> {
+    let builder = unsafe { convert::borrow_mut_in(builder) };
+    let mut fun = || {
+        let nonce = unsafe { convert::str_in_opt(nonce)? };
+        builder.nonce = nonce.map(|nonce| nonce.to_owned());
+        Ok(())
+    };
+    cresult_void(fun())
+}
+
+#[no_mangle]
+pub extern "C" fn JwtBuilder_nonce_get(builder: *const JwtBuilder) -> *mut raw::c_char {
+    let builder = unsafe { convert::borrow_in(builder) };
+    convert::string_out_opt(builder.nonce.clone())
+}
+
+#[no_mangle]
+pub extern "C" fn JwtBuilder_custom_claim_set(
+    builder: *mut JwtBuilder, name: *const raw::c_char, json: *const raw::c_char,
+) -> CPtrResult<raw::c_void> {
+    let builder = unsafe { convert::borrow_mut_in(builder) };
+    let mut fun = || {
+        let name = unsafe { convert::str_in(name)? };
+        let json = unsafe { convert::str_in(json)? };
+        let value: serde_json::Value = serde_json::from_str(json)?;
+        builder.set_custom_claim(name, &value)
+    };
+    cresult_void(fun())
+}
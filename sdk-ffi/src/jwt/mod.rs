@@ -1,6 +1,6 @@
 mod builder;
 mod parser;
 
-use chrono::{Duration, TimeZone as _, Utc};
+use chrono::{DateTime, Duration, TimeZone as _, Utc};
 
 use super::*;
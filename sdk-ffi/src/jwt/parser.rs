@@ -5,20 +5,66 @@ pub extern "C" fn delete_JwtParser(parser: *mut JwtParser) {
     delete(parser)
 }
 
+unsafe fn current_time_in(current_time: *const i64) -> Result<Option<DateTime<Utc>>> {
+    convert::borrow_in_opt(current_time)
+        .map(|secs| {
+            Utc.timestamp_opt(*secs, 0)
+                .single()
+                .ok_or_else(|| format_err!("Timestamp {} is out of range", secs))
+        })
+        .transpose()
+}
+
 #[no_mangle]
 pub extern "C" fn JwtParser_new(
     token: *const raw::c_char, current_time: *const i64,
 ) -> CPtrResult<JwtParser> {
     let fun = || {
         let token = unsafe { convert::str_in(token)? };
-        let current_time =
-            unsafe { convert::borrow_in_opt(current_time) }.map(|secs| Utc.timestamp(*secs, 0));
+        let current_time = unsafe { current_time_in(current_time)? };
         let parser = JwtParser::new(token, current_time)?;
         Ok(convert::move_out(parser))
     };
     cresult(fun())
 }
 
+#[no_mangle]
+pub extern "C" fn JwtParser_with_public_key(
+    token: *const raw::c_char, pk: *const MPublicKey, current_time: *const i64,
+) -> CPtrResult<JwtParser> {
+    let fun = || {
+        let token = unsafe { convert::str_in(token)? };
+        let pk = unsafe { convert::borrow_in(pk) };
+        let current_time = unsafe { current_time_in(current_time)? };
+        let parser = JwtParser::with_public_key(token, pk, current_time)?;
+        Ok(convert::move_out(parser))
+    };
+    cresult(fun())
+}
+
+#[no_mangle]
+pub extern "C" fn JwtParser_validate_with(
+    token: *const raw::c_char, audience: *const raw::c_char, issuer: *const raw::c_char,
+    current_time: *const i64,
+) -> CPtrResult<JwtParser> {
+    let fun = || {
+        let token = unsafe { convert::str_in(token)? };
+        let audience = unsafe { convert::str_in_opt(audience)? }.map(|aud| aud.to_owned());
+        let issuer = unsafe { convert::str_in_opt(issuer)? }.map(Did::from_str).transpose()?;
+        let current_time = unsafe { current_time_in(current_time)? };
+        let validation = JwtValidation { audience, issuer, ..Default::default() };
+        let parser = JwtParser::validate_with(token, &validation, current_time)?;
+        Ok(convert::move_out(parser))
+    };
+    cresult(fun())
+}
+
+#[no_mangle]
+pub extern "C" fn JwtParser_algorithm_get(parser: *const JwtParser) -> *mut raw::c_char {
+    let parser = unsafe { convert::borrow_in(parser) };
+    convert::string_out(parser.algorithm().to_string())
+}
+
 #[no_mangle]
 pub extern "C" fn JwtParser_public_key_get(parser: *const JwtParser) -> *mut MPublicKey {
     let parser = unsafe { convert::borrow_in(parser) };
@@ -43,3 +89,42 @@ pub extern "C" fn JwtParser_content_id_get(parser: *const JwtParser) -> *mut raw
     let content_id: Option<String> = parser.content_id().cloned();
     convert::string_out_opt(content_id)
 }
+
+#[no_mangle]
+pub extern "C" fn JwtParser_issuer_get(parser: *const JwtParser) -> *mut raw::c_char {
+    let parser = unsafe { convert::borrow_in(parser) };
+    convert::string_out_opt(parser.issuer().map(|issuer| issuer.to_owned()))
+}
+
+#[no_mangle]
+pub extern "C" fn JwtParser_subject_get(parser: *const JwtParser) -> *mut raw::c_char {
+    let parser = unsafe { convert::borrow_in(parser) };
+    convert::string_out_opt(parser.subject().map(|subject| subject.to_owned()))
+}
+
+#[no_mangle]
+pub extern "C" fn JwtParser_audience_get(
+    parser: *const JwtParser,
+) -> *mut CSlice<*mut raw::c_char> {
+    let parser = unsafe { convert::borrow_in(parser) };
+    convert::move_out(CSlice::from(parser.audience().to_vec()))
+}
+
+#[no_mangle]
+pub extern "C" fn JwtParser_nonce_get(parser: *const JwtParser) -> *mut raw::c_char {
+    let parser = unsafe { convert::borrow_in(parser) };
+    convert::string_out_opt(parser.nonce().map(|nonce| nonce.to_owned()))
+}
+
+#[no_mangle]
+pub extern "C" fn JwtParser_custom_claim(
+    parser: *const JwtParser, name: *const raw::c_char,
+) -> CPtrResult<raw::c_char> {
+    let parser = unsafe { convert::borrow_in(parser) };
+    let fun = || {
+        let name = unsafe { convert::str_in(name)? };
+        let json = parser.custom_claims().get(name).map(|value| value.to_string());
+        Ok(convert::string_out_opt(json))
+    };
+    cresult(fun())
+}
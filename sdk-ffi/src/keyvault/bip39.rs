@@ -1,6 +1,8 @@
 use super::*;
 
-use iop_keyvault::Bip39;
+use iop_keyvault::{
+    Bip39, EntropyMixing, EntropySource, RecoveryCandidate, RecoveryTarget, UserEntropy,
+};
 
 #[no_mangle]
 pub extern "C" fn Bip39_generate_phrase(lang: *const raw::c_char) -> CPtrResult<raw::c_char> {
@@ -42,3 +44,122 @@ pub extern "C" fn Bip39_list_words(
     };
     cresult(fun())
 }
+
+#[no_mangle]
+pub extern "C" fn Bip39_recover(
+    lang: *const raw::c_char, phrase: *const raw::c_char,
+) -> CPtrResult<CSlice<*mut raw::c_char>> {
+    let fun = || {
+        let lang_code = unsafe { convert::str_in(lang)? };
+        let phrase = unsafe { convert::str_in(phrase)? };
+        let candidates = Bip39::language_code(lang_code)?.recover(phrase)?;
+        Ok(candidate_phrases(candidates))
+    };
+    cresult(fun())
+}
+
+#[no_mangle]
+pub extern "C" fn Bip39_recover_by_address(
+    lang: *const raw::c_char, phrase: *const raw::c_char, password: *const raw::c_char,
+    network: *const raw::c_char, address: *const raw::c_char,
+) -> CPtrResult<CSlice<*mut raw::c_char>> {
+    let fun = || {
+        let lang_code = unsafe { convert::str_in(lang)? };
+        let phrase = unsafe { convert::str_in(phrase)? };
+        let password = unsafe { convert::str_in(password)? };
+        let network = Networks::by_name(unsafe { convert::str_in(network)? })?;
+        let address = unsafe { convert::str_in(address)? }.to_owned();
+        let target = RecoveryTarget::HydraAddress { network, address };
+        let bip39 = Bip39::language_code(lang_code)?;
+        Ok(candidate_phrases(bip39.recover_matching(phrase, password, &target)?))
+    };
+    cresult(fun())
+}
+
+#[no_mangle]
+pub extern "C" fn Bip39_recover_by_public_key(
+    lang: *const raw::c_char, phrase: *const raw::c_char, password: *const raw::c_char,
+    public_key: *const MPublicKey,
+) -> CPtrResult<CSlice<*mut raw::c_char>> {
+    let public_key = unsafe { convert::borrow_in(public_key) };
+    let fun = || {
+        let lang_code = unsafe { convert::str_in(lang)? };
+        let phrase = unsafe { convert::str_in(phrase)? };
+        let password = unsafe { convert::str_in(password)? };
+        let target = RecoveryTarget::MorpheusPublicKey(public_key.to_owned());
+        let bip39 = Bip39::language_code(lang_code)?;
+        Ok(candidate_phrases(bip39.recover_matching(phrase, password, &target)?))
+    };
+    cresult(fun())
+}
+
+fn candidate_phrases(candidates: Vec<RecoveryCandidate>) -> *mut CSlice<*mut raw::c_char> {
+    let phrases = candidates.into_iter().map(|c| c.phrase).collect::<Vec<_>>();
+    convert::move_out(CSlice::from(phrases))
+}
+
+#[no_mangle]
+pub extern "C" fn UserEntropy_parse(
+    source: *const raw::c_char, input: *const raw::c_char,
+) -> CPtrResult<UserEntropy> {
+    let fun = || {
+        let source = EntropySource::from_name(unsafe { convert::str_in(source)? })?;
+        let input = unsafe { convert::str_in(input)? };
+        let entropy = UserEntropy::parse(source, input)?;
+        Ok(convert::move_out(entropy))
+    };
+    cresult(fun())
+}
+
+#[no_mangle]
+pub extern "C" fn delete_UserEntropy(entropy: *mut UserEntropy) {
+    delete(entropy)
+}
+
+#[no_mangle]
+pub extern "C" fn UserEntropy_bits_get(entropy: *const UserEntropy) -> f64 {
+    let entropy = unsafe { convert::borrow_in(entropy) };
+    entropy.bits()
+}
+
+#[no_mangle]
+pub extern "C" fn UserEntropy_warnings_get(
+    entropy: *const UserEntropy,
+) -> *mut CSlice<*mut raw::c_char> {
+    let entropy = unsafe { convert::borrow_in(entropy) };
+    let warnings = entropy.warnings().to_vec();
+    convert::move_out(CSlice::from(warnings))
+}
+
+#[no_mangle]
+pub extern "C" fn UserEntropy_to_string(entropy: *const UserEntropy) -> *mut raw::c_char {
+    let entropy = unsafe { convert::borrow_in(entropy) };
+    convert::string_out(entropy.to_string())
+}
+
+#[no_mangle]
+pub extern "C" fn Bip39_generate_with_user_entropy(
+    lang: *const raw::c_char, entropy: *const UserEntropy,
+) -> CPtrResult<raw::c_char> {
+    generate_with(lang, entropy, EntropyMixing::WithSystem)
+}
+
+#[no_mangle]
+pub extern "C" fn Bip39_generate_from_user_entropy_only(
+    lang: *const raw::c_char, entropy: *const UserEntropy,
+) -> CPtrResult<raw::c_char> {
+    generate_with(lang, entropy, EntropyMixing::UserOnly)
+}
+
+fn generate_with(
+    lang: *const raw::c_char, entropy: *const UserEntropy, mixing: EntropyMixing,
+) -> CPtrResult<raw::c_char> {
+    let entropy = unsafe { convert::borrow_in(entropy) };
+    let fun = || {
+        let lang_code = unsafe { convert::str_in(lang)? };
+        let bip39 = Bip39::language_code(lang_code)?;
+        let phrase = bip39.generate_with(entropy, mixing)?;
+        Ok(convert::string_out(phrase.as_phrase().to_string()))
+    };
+    cresult(fun())
+}
@@ -71,3 +71,66 @@ pub extern "C" fn MPublicKey_verify(
     let sig = unsafe { convert::borrow_in(sig) };
     pk.verify(data.as_slice(), sig)
 }
+
+#[no_mangle]
+pub extern "C" fn MPublicKey_from_pem(pem: *const raw::c_char) -> CPtrResult<MPublicKey> {
+    let fun = || {
+        let pem = unsafe { convert::str_in(pem)? };
+        let pk = MPublicKey::from_pem(pem)?;
+        Ok(convert::move_out(pk))
+    };
+    cresult(fun())
+}
+
+#[no_mangle]
+pub extern "C" fn MPublicKey_to_pem(pk: *mut MPublicKey) -> *mut raw::c_char {
+    let pk = unsafe { convert::borrow_in(pk) };
+    convert::string_out(pk.to_pem())
+}
+
+#[no_mangle]
+pub extern "C" fn MPublicKey_from_spki_der(der: *mut CSlice<u8>) -> CPtrResult<MPublicKey> {
+    let der = unsafe { convert::borrow_in(der) };
+    let fun = || {
+        let pk = MPublicKey::from_spki_der(der.as_slice())?;
+        Ok(convert::move_out(pk))
+    };
+    cresult(fun())
+}
+
+#[no_mangle]
+pub extern "C" fn MPublicKey_to_spki_der(pk: *mut MPublicKey) -> *mut CSlice<u8> {
+    let pk = unsafe { convert::borrow_in(pk) };
+    convert::move_out(CSlice::from(pk.to_spki_der()))
+}
+
+#[no_mangle]
+pub extern "C" fn MPublicKey_from_jwk(jwk: *const raw::c_char) -> CPtrResult<MPublicKey> {
+    let fun = || {
+        let jwk = unsafe { convert::str_in(jwk)? };
+        let jwk: Jwk = serde_json::from_str(jwk)?;
+        let pk = MPublicKey::from_jwk(&jwk)?;
+        Ok(convert::move_out(pk))
+    };
+    cresult(fun())
+}
+
+#[no_mangle]
+pub extern "C" fn MPublicKey_to_jwk(pk: *mut MPublicKey) -> CPtrResult<raw::c_char> {
+    let pk = unsafe { convert::borrow_in(pk) };
+    let fun = || {
+        let jwk = serde_json::to_string(&pk.to_jwk())?;
+        Ok(convert::string_out(jwk))
+    };
+    cresult(fun())
+}
+
+#[no_mangle]
+pub extern "C" fn MPublicKey_jwk_thumbprint(pk: *mut MPublicKey) -> CPtrResult<raw::c_char> {
+    let pk = unsafe { convert::borrow_in(pk) };
+    let fun = || {
+        let thumbprint = pk.to_jwk().thumbprint()?;
+        Ok(convert::string_out(thumbprint))
+    };
+    cresult(fun())
+}
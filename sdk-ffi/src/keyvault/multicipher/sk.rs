@@ -25,3 +25,56 @@ pub extern "C" fn MPrivateKey_sign(sk: *mut MPrivateKey, data: *mut CSlice<u8>)
     let sig = sk.sign(data.as_slice());
     convert::move_out(sig)
 }
+
+#[no_mangle]
+pub extern "C" fn MPrivateKey_from_pem(pem: *const raw::c_char) -> CPtrResult<MPrivateKey> {
+    let fun = || {
+        let pem = unsafe { convert::str_in(pem)? };
+        let sk = MPrivateKey::from_pem(pem)?;
+        Ok(convert::move_out(sk))
+    };
+    cresult(fun())
+}
+
+#[no_mangle]
+pub extern "C" fn MPrivateKey_to_pem(sk: *mut MPrivateKey) -> *mut raw::c_char {
+    let sk = unsafe { convert::borrow_in(sk) };
+    convert::string_out(sk.to_pem())
+}
+
+#[no_mangle]
+pub extern "C" fn MPrivateKey_from_pkcs8_der(der: *mut CSlice<u8>) -> CPtrResult<MPrivateKey> {
+    let der = unsafe { convert::borrow_in(der) };
+    let fun = || {
+        let sk = MPrivateKey::from_pkcs8_der(der.as_slice())?;
+        Ok(convert::move_out(sk))
+    };
+    cresult(fun())
+}
+
+#[no_mangle]
+pub extern "C" fn MPrivateKey_to_pkcs8_der(sk: *mut MPrivateKey) -> *mut CSlice<u8> {
+    let sk = unsafe { convert::borrow_in(sk) };
+    convert::move_out(CSlice::from(sk.to_pkcs8_der()))
+}
+
+#[no_mangle]
+pub extern "C" fn MPrivateKey_from_jwk(jwk: *const raw::c_char) -> CPtrResult<MPrivateKey> {
+    let fun = || {
+        let jwk = unsafe { convert::str_in(jwk)? };
+        let jwk: Jwk = serde_json::from_str(jwk)?;
+        let sk = MPrivateKey::from_jwk(&jwk)?;
+        Ok(convert::move_out(sk))
+    };
+    cresult(fun())
+}
+
+#[no_mangle]
+pub extern "C" fn MPrivateKey_to_jwk(sk: *mut MPrivateKey) -> CPtrResult<raw::c_char> {
+    let sk = unsafe { convert::borrow_in(sk) };
+    let fun = || {
+        let jwk = serde_json::to_string(&sk.to_jwk())?;
+        Ok(convert::string_out(jwk))
+    };
+    cresult(fun())
+}
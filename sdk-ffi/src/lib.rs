@@ -2,6 +2,7 @@
 #![allow(non_snake_case)]
 
 mod coeus;
+mod credential;
 mod crypto;
 mod did;
 mod ffi;
@@ -9,15 +10,18 @@ mod hydra;
 mod jwt;
 mod keyvault;
 mod morpheus;
+mod process;
 mod signed;
+mod subtree;
 mod validation;
 mod vault;
+mod vc;
 
 // use std::panic::catch_unwind; // TODO consider panic unwinding strategies
 use std::os::raw;
 use std::str::FromStr;
 
-use anyhow::{ensure, Result};
+use anyhow::{ensure, format_err, Result};
 
 use iop_hydra_proto::txtype::{
     Aip29Transaction, CommonTransactionFields, OptionalTransactionFields,
@@ -27,12 +31,13 @@ use iop_keyvault::{
     ed25519::{DidKind, MorpheusPrivateKey, MorpheusPublicKey},
     multicipher::*,
     secp256k1::*,
-    Bip32Node, Bip32PublicNode, Bip44Key, Bip44PublicKey, Network, Networks, PrivateKey as _,
-    PublicKey as _,
+    Bip32Node, Bip32PublicNode, Bip39Language, Bip44Key, Bip44PublicKey, Chain, Network, Networks,
+    PrivateKey as _, PublicKey as _,
 };
 use iop_morpheus_proto::{
     crypto::{jwt::*, sign::*},
     data::*,
+    login::{LoginChallenge, LoginResponse},
 };
 use iop_morpheus_sdk::vault::{
     Plugin as MorpheusPlugin, Private as MorpheusPrivate, PrivateKind as MorpheusPrivateKind,
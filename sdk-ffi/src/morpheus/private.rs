@@ -154,6 +154,59 @@ pub extern "C" fn MorpheusPrivate_sign_claim_presentation(
     cresult(fun())
 }
 
+#[no_mangle]
+pub extern "C" fn MorpheusPrivate_sign_license(
+    private: *mut MorpheusPrivate, id: *mut MKeyId, license: *mut raw::c_char,
+) -> CPtrResult<Signed<serde_json::Value>> {
+    let private = unsafe { convert::borrow_in(private) };
+    let id = unsafe { convert::borrow_in(id) };
+    let fun = || {
+        let license = unsafe { convert::str_in(license)? };
+        let signer = create_signer(private, id)?;
+        let license: License = serde_json::from_str(license)?;
+        let signed_license = signer.sign_license(license)?;
+        let signed_json = into_signed_json(signed_license)?;
+        Ok(convert::move_out(signed_json))
+    };
+    cresult(fun())
+}
+
+#[no_mangle]
+pub extern "C" fn MorpheusPrivate_sign_after_envelope(
+    private: *mut MorpheusPrivate, id: *mut MKeyId, envelope: *const raw::c_char,
+) -> CPtrResult<Signed<serde_json::Value>> {
+    let private = unsafe { convert::borrow_in(private) };
+    let id = unsafe { convert::borrow_in(id) };
+    let fun = || {
+        let envelope = unsafe { convert::str_in(envelope)? };
+        let signer = create_signer(private, id)?;
+        let envelope: AfterEnvelope<serde_json::Value> = serde_json::from_str(envelope)?;
+        let signed_envelope = signer.sign_after_envelope(envelope)?;
+        let signed_json = into_signed_json(signed_envelope)?;
+        Ok(convert::move_out(signed_json))
+    };
+    cresult(fun())
+}
+
+#[no_mangle]
+pub extern "C" fn MorpheusPrivate_sign_login_response(
+    private: *mut MorpheusPrivate, id: *mut MKeyId, did: *const raw::c_char,
+    challenge: *const raw::c_char,
+) -> CPtrResult<Signed<serde_json::Value>> {
+    let private = unsafe { convert::borrow_in(private) };
+    let id = unsafe { convert::borrow_in(id) };
+    let fun = || {
+        let did: Did = unsafe { convert::str_in(did)? }.parse()?;
+        let challenge = unsafe { convert::str_in(challenge)? };
+        let signer = create_signer(private, id)?;
+        let challenge: LoginChallenge = serde_json::from_str(challenge)?;
+        let signed_response = signer.sign_login_response(LoginResponse::new(did, challenge))?;
+        let signed_json = into_signed_json(signed_response)?;
+        Ok(convert::move_out(signed_json))
+    };
+    cresult(fun())
+}
+
 fn create_signer(private: &MorpheusPrivate, id: &MKeyId) -> Result<PrivateKeySigner> {
     let sk: MPrivateKey = key_by_id(private, id)?.private_key();
     Ok(PrivateKeySigner::new(sk))
@@ -48,7 +48,25 @@ pub extern "C" fn MorpheusOperationBuilder_add_key(
         let builder = unsafe { convert::borrow_in(builder) };
         let auth = unsafe { convert::str_in(authentication) }?.parse()?;
         let expires_at_height = if expires_at_height == 0 { None } else { Some(expires_at_height) };
-        let operation = SignableOperationDetails::AddKey { auth, expires_at_height };
+        let operation =
+            SignableOperationDetails::AddKey { auth, expires_at_height, expires_at: None };
+        let attempt = builder.op_to_attempt(operation);
+        Ok(convert::move_out(attempt))
+    };
+    cresult(fun())
+}
+
+#[no_mangle]
+pub extern "C" fn MorpheusOperationBuilder_add_key_expiring_at(
+    builder: *mut MorpheusOperationBuilder, authentication: *const raw::c_char,
+    expires_at: *const raw::c_char,
+) -> CPtrResult<SignableOperationAttempt> {
+    let fun = || {
+        let builder = unsafe { convert::borrow_in(builder) };
+        let auth = unsafe { convert::str_in(authentication) }?.parse()?;
+        let expires_at = Some(unsafe { convert::str_in(expires_at) }?.parse()?);
+        let operation =
+            SignableOperationDetails::AddKey { auth, expires_at_height: None, expires_at };
         let attempt = builder.op_to_attempt(operation);
         Ok(convert::move_out(attempt))
     };
@@ -69,6 +87,22 @@ pub extern "C" fn MorpheusOperationBuilder_revoke_key(
     cresult(fun())
 }
 
+#[no_mangle]
+pub extern "C" fn MorpheusOperationBuilder_rotate_key(
+    builder: *mut MorpheusOperationBuilder, old_authentication: *const raw::c_char,
+    new_authentication: *const raw::c_char,
+) -> CPtrResult<SignableOperationAttempt> {
+    let fun = || {
+        let builder = unsafe { convert::borrow_in(builder) };
+        let old = unsafe { convert::str_in(old_authentication) }?.parse()?;
+        let new = unsafe { convert::str_in(new_authentication) }?.parse()?;
+        let operation = SignableOperationDetails::RotateKey { old, new };
+        let attempt = builder.op_to_attempt(operation);
+        Ok(convert::move_out(attempt))
+    };
+    cresult(fun())
+}
+
 #[no_mangle]
 pub extern "C" fn MorpheusOperationBuilder_add_right(
     builder: *mut MorpheusOperationBuilder, authentication: *const raw::c_char,
@@ -77,7 +111,7 @@ pub extern "C" fn MorpheusOperationBuilder_add_right(
     let fun = || {
         let builder = unsafe { convert::borrow_in(builder) };
         let auth = unsafe { convert::str_in(authentication) }?.parse()?;
-        let right = unsafe { convert::str_in(right) }?.to_owned();
+        let right = unsafe { convert::str_in(right) }?.parse::<Right>()?.to_string();
         let operation = SignableOperationDetails::AddRight { auth, right };
         let attempt = builder.op_to_attempt(operation);
         Ok(convert::move_out(attempt))
@@ -93,7 +127,7 @@ pub extern "C" fn MorpheusOperationBuilder_revoke_right(
     let fun = || {
         let builder = unsafe { convert::borrow_in(builder) };
         let auth = unsafe { convert::str_in(authentication) }?.parse()?;
-        let right = unsafe { convert::str_in(right) }?.to_owned();
+        let right = unsafe { convert::str_in(right) }?.parse::<Right>()?.to_string();
         let operation = SignableOperationDetails::RevokeRight { auth, right };
         let attempt = builder.op_to_attempt(operation);
         Ok(convert::move_out(attempt))
@@ -111,6 +145,48 @@ pub extern "C" fn MorpheusOperationBuilder_tombstone_did(
     convert::move_out(attempt)
 }
 
+#[no_mangle]
+pub extern "C" fn MorpheusOperationBuilder_add_service(
+    builder: *mut MorpheusOperationBuilder, service_type: *const raw::c_char,
+    name: *const raw::c_char, service_endpoint: *const raw::c_char,
+) -> CPtrResult<SignableOperationAttempt> {
+    let fun = || {
+        let builder = unsafe { convert::borrow_in(builder) };
+        let type_ = unsafe { convert::str_in(service_type) }?.parse()?;
+        let name = unsafe { convert::str_in(name) }?;
+        let service_endpoint = unsafe { convert::str_in(service_endpoint) }?;
+        let service = Service::new(type_, name, service_endpoint);
+        let operation = SignableOperationDetails::AddService { service };
+        let attempt = builder.op_to_attempt(operation);
+        Ok(convert::move_out(attempt))
+    };
+    cresult(fun())
+}
+
+#[no_mangle]
+pub extern "C" fn MorpheusOperationBuilder_revoke_service(
+    builder: *mut MorpheusOperationBuilder, name: *const raw::c_char,
+) -> CPtrResult<SignableOperationAttempt> {
+    let fun = || {
+        let builder = unsafe { convert::borrow_in(builder) };
+        let name = unsafe { convert::str_in(name) }?.to_owned();
+        let operation = SignableOperationDetails::RevokeService { name };
+        let attempt = builder.op_to_attempt(operation);
+        Ok(convert::move_out(attempt))
+    };
+    cresult(fun())
+}
+
+#[no_mangle]
+pub extern "C" fn MorpheusOperationBuilder_set_update_threshold(
+    builder: *mut MorpheusOperationBuilder, threshold: u32,
+) -> *mut SignableOperationAttempt {
+    let builder = unsafe { convert::borrow_in(builder) };
+    let operation = SignableOperationDetails::SetUpdateThreshold { threshold };
+    let attempt = builder.op_to_attempt(operation);
+    convert::move_out(attempt)
+}
+
 #[no_mangle]
 pub extern "C" fn delete_MorpheusOperation(attempt: *mut SignableOperationAttempt) {
     delete(attempt)
@@ -216,6 +292,31 @@ pub extern "C" fn MorpheusSignedOperation_to_string(
     cresult(fun())
 }
 
+#[no_mangle]
+pub extern "C" fn MorpheusSignedOperation_from_string(
+    input: *const raw::c_char,
+) -> CPtrResult<SignedOperation> {
+    let fun = || {
+        let input = unsafe { convert::str_in(input)? };
+        let signed_op: SignedOperation = serde_json::from_str(input)?;
+        Ok(convert::move_out(signed_op))
+    };
+    cresult(fun())
+}
+
+#[no_mangle]
+pub extern "C" fn MorpheusSignedOperation_cosign_with_key(
+    signed_op: *mut SignedOperation, private_key: *const MPrivateKey,
+) -> CPtrResult<raw::c_void> {
+    let fun = || {
+        let signed_op = unsafe { convert::borrow_mut_in(signed_op) };
+        let private_key = unsafe { convert::borrow_in(private_key) };
+        let signer = PrivateKeySigner::new(private_key.to_owned());
+        signed_op.cosign(&signer)
+    };
+    cresult_void(fun())
+}
+
 pub struct MorpheusAssetBuilder {
     op_attempts: Vec<OperationAttempt>,
 }
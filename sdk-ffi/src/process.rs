@@ -0,0 +1,32 @@
+use super::*;
+
+/// The schemas are passed as a JSON array of JSON schema documents, looked up by their content id.
+#[no_mangle]
+pub extern "C" fn Process_validate_witness_request(
+    process: *const raw::c_char, schemas: *const raw::c_char, request: *const raw::c_char,
+) -> CPtrResult<raw::c_void> {
+    let fun = || {
+        let process: Process = serde_json::from_str(unsafe { convert::str_in(process)? })?;
+        let schemas: Vec<MorpheusSchema> =
+            serde_json::from_str(unsafe { convert::str_in(schemas)? })?;
+        let request: WitnessRequest = serde_json::from_str(unsafe { convert::str_in(request)? })?;
+        process.validate_request(&request, &schemas_by_id(schemas)?)
+    };
+    cresult_void(fun())
+}
+
+/// The schemas are passed as a JSON array of JSON schema documents, looked up by their content id.
+#[no_mangle]
+pub extern "C" fn Process_validate_witness_statement(
+    process: *const raw::c_char, schemas: *const raw::c_char, statement: *const raw::c_char,
+) -> CPtrResult<raw::c_void> {
+    let fun = || {
+        let process: Process = serde_json::from_str(unsafe { convert::str_in(process)? })?;
+        let schemas: Vec<MorpheusSchema> =
+            serde_json::from_str(unsafe { convert::str_in(schemas)? })?;
+        let statement: WitnessStatement =
+            serde_json::from_str(unsafe { convert::str_in(statement)? })?;
+        process.validate_statement(&statement, &schemas_by_id(schemas)?)
+    };
+    cresult_void(fun())
+}
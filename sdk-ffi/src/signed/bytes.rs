@@ -56,3 +56,11 @@ pub extern "C" fn SignedBytes_validate_with_did_doc(
 ) -> CPtrResult<ValidationResult> {
     validate_with_did_doc(signed, did_doc_str, from_height_inc, until_height_exc)
 }
+
+#[no_mangle]
+pub extern "C" fn SignedBytes_validate_with_did_doc_at_time(
+    signed: *mut Signed<Box<[u8]>>, did_doc_str: *const raw::c_char, from_inc: *const raw::c_char,
+    until_exc: *const raw::c_char,
+) -> CPtrResult<ValidationResult> {
+    validate_with_did_doc_at_time(signed, did_doc_str, from_inc, until_exc)
+}
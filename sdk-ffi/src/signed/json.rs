@@ -69,6 +69,14 @@ pub extern "C" fn SignedJson_validate_with_did_doc(
     validate_with_did_doc(signed, did_doc_str, from_height_inc, until_height_exc)
 }
 
+#[no_mangle]
+pub extern "C" fn SignedJson_validate_with_did_doc_at_time(
+    signed: *const Signed<serde_json::Value>, did_doc_str: *const raw::c_char,
+    from_inc: *const raw::c_char, until_exc: *const raw::c_char,
+) -> CPtrResult<ValidationResult> {
+    validate_with_did_doc_at_time(signed, did_doc_str, from_inc, until_exc)
+}
+
 #[no_mangle]
 pub extern "C" fn SignedJson_to_json(signed: *const Signed<serde_json::Value>) -> *mut raw::c_char {
     let signed = unsafe { convert::borrow_in(signed) };
@@ -34,3 +34,21 @@ fn validate_with_did_doc<T: Signable>(
     };
     cresult(fun())
 }
+
+fn validate_with_did_doc_at_time<T: Signable>(
+    signed: *const Signed<T>, did_doc_str: *const raw::c_char, from_inc: *const raw::c_char,
+    until_exc: *const raw::c_char,
+) -> CPtrResult<ValidationResult> {
+    let signed = unsafe { convert::borrow_in(signed) };
+    let fun = || {
+        let did_doc_str = unsafe { convert::str_in(did_doc_str)? };
+        let did_doc = serde_json::from_str(did_doc_str)?;
+        let from_inc = unsafe { convert::str_in(from_inc) }.ok().map(|t| t.parse()).transpose()?;
+        let until_exc =
+            unsafe { convert::str_in(until_exc) }.ok().map(|t| t.parse()).transpose()?;
+        let validation_result =
+            signed.validate_with_did_doc_at_time(&did_doc, from_inc, until_exc)?;
+        Ok(convert::move_out(validation_result))
+    };
+    cresult(fun())
+}
@@ -0,0 +1,10 @@
+mod plugin;
+mod private;
+mod public;
+
+use super::*;
+
+use iop_vault::subtree::{
+    Parameters as SubtreeParameters, Plugin as SubtreePlugin, Private as SubtreePrivate,
+    Public as SubtreePublic,
+};
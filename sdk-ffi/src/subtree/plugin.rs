@@ -0,0 +1,70 @@
+use super::*;
+
+pub struct CSubtreePlugin {
+    pub(crate) plugin: BoundPlugin<SubtreePlugin, SubtreePublic, SubtreePrivate>,
+}
+
+fn params(suite: *const raw::c_char, path: *const raw::c_char) -> Result<SubtreeParameters> {
+    let suite = unsafe { convert::str_in(suite)? }.parse()?;
+    let path = unsafe { convert::str_in(path)? };
+    SubtreeParameters::new(suite, path)
+}
+
+#[no_mangle]
+pub extern "C" fn SubtreePlugin_init(
+    vault: *mut Vault, unlock_pwd: *const raw::c_char, suite: *const raw::c_char,
+    path: *const raw::c_char,
+) -> CPtrResult<raw::c_void> {
+    let vault = unsafe { convert::borrow_mut_in(vault) };
+    let mut fun = || {
+        let unlock_password = unsafe { convert::str_in(unlock_pwd)? };
+        let params = params(suite, path)?;
+        SubtreePlugin::init(vault, unlock_password, &params)?;
+        Ok(())
+    };
+    cresult_void(fun())
+}
+
+#[no_mangle]
+pub extern "C" fn SubtreePlugin_get(
+    vault: *mut Vault, suite: *const raw::c_char, path: *const raw::c_char,
+) -> CPtrResult<CSubtreePlugin> {
+    let vault = unsafe { convert::borrow_mut_in(vault) };
+    let fun = || {
+        let params = params(suite, path)?;
+        let plugin = SubtreePlugin::get(vault, &params)?;
+        let subtree = CSubtreePlugin { plugin };
+        Ok(convert::move_out(subtree))
+    };
+    cresult(fun())
+}
+
+#[no_mangle]
+pub extern "C" fn SubtreePlugin_public_get(
+    subtree: *mut CSubtreePlugin,
+) -> CPtrResult<SubtreePublic> {
+    let subtree = unsafe { convert::borrow_in(subtree) };
+    let fun = || {
+        let public = subtree.plugin.public()?;
+        Ok(convert::move_out(public))
+    };
+    cresult(fun())
+}
+
+#[no_mangle]
+pub extern "C" fn SubtreePlugin_private(
+    subtree: *mut CSubtreePlugin, unlock_pwd: *const raw::c_char,
+) -> CPtrResult<SubtreePrivate> {
+    let subtree = unsafe { convert::borrow_in(subtree) };
+    let fun = || {
+        let unlock_password = unsafe { convert::str_in(unlock_pwd)? };
+        let private = subtree.plugin.private(unlock_password)?;
+        Ok(convert::move_out(private))
+    };
+    cresult(fun())
+}
+
+#[no_mangle]
+pub extern "C" fn delete_SubtreePlugin(subtree: *mut CSubtreePlugin) {
+    delete(subtree)
+}
@@ -0,0 +1,43 @@
+use super::*;
+
+#[no_mangle]
+pub extern "C" fn delete_SubtreePrivate(private: *mut SubtreePrivate) {
+    delete(private)
+}
+
+#[no_mangle]
+pub extern "C" fn SubtreePrivate_public_get(
+    private: *mut SubtreePrivate,
+) -> CPtrResult<SubtreePublic> {
+    let fun = || {
+        let private = unsafe { convert::borrow_in(private) };
+        let public = private.public();
+        Ok(convert::move_out(public))
+    };
+    cresult(fun())
+}
+
+#[no_mangle]
+pub extern "C" fn SubtreePrivate_key(
+    private: *mut SubtreePrivate, idx: i32,
+) -> CPtrResult<MPrivateKey> {
+    let fun = || {
+        let private = unsafe { convert::borrow_mut_in(private) };
+        let sk = private.key_mut(idx)?;
+        Ok(convert::move_out(sk))
+    };
+    cresult(fun())
+}
+
+#[no_mangle]
+pub extern "C" fn SubtreePrivate_key_by_pk(
+    private: *mut SubtreePrivate, pk: *const MPublicKey,
+) -> CPtrResult<MPrivateKey> {
+    let private = unsafe { convert::borrow_in(private) };
+    let pk = unsafe { convert::borrow_in(pk) };
+    let fun = || {
+        let sk = private.key_by_pk(pk)?;
+        Ok(convert::move_out(sk))
+    };
+    cresult(fun())
+}
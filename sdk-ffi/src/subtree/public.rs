@@ -0,0 +1,41 @@
+use super::*;
+
+#[no_mangle]
+pub extern "C" fn delete_SubtreePublic(public: *mut SubtreePublic) {
+    delete(public)
+}
+
+#[no_mangle]
+pub extern "C" fn SubtreePublic_count_get(public: *mut SubtreePublic) -> CPtrResult<u32> {
+    let public = unsafe { convert::borrow_in(public) };
+    let fun = || {
+        let count = public.len()?;
+        Ok(convert::move_out(count))
+    };
+    cresult(fun())
+}
+
+#[no_mangle]
+pub extern "C" fn SubtreePublic_key(
+    public: *mut SubtreePublic, idx: i32,
+) -> CPtrResult<MPublicKey> {
+    let public = unsafe { convert::borrow_in(public) };
+    let fun = || {
+        let pk = public.key(idx)?;
+        Ok(convert::move_out(pk))
+    };
+    cresult(fun())
+}
+
+#[no_mangle]
+pub extern "C" fn SubtreePublic_key_by_id(
+    public: *mut SubtreePublic, id: *const MKeyId,
+) -> CPtrResult<MPublicKey> {
+    let public = unsafe { convert::borrow_in(public) };
+    let id = unsafe { convert::borrow_in(id) };
+    let fun = || {
+        let pk = public.key_by_id(id)?;
+        Ok(convert::move_out(pk))
+    };
+    cresult(fun())
+}
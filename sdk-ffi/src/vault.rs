@@ -36,6 +36,22 @@ pub extern "C" fn Vault_create(
     cresult(fun())
 }
 
+#[no_mangle]
+pub extern "C" fn Vault_create_watch_only() -> *mut CVault {
+    let vault = CVault { inner: Vault::create_watch_only() };
+    convert::move_out(vault)
+}
+
+#[no_mangle]
+pub extern "C" fn Vault_watch_only_get(vault: *mut CVault) -> CPtrResult<raw::c_uchar> {
+    let vault = unsafe { convert::borrow_in(vault) };
+    let fun = || {
+        let is_watch_only = vault.inner.is_watch_only()?;
+        Ok(convert::bool_out(is_watch_only))
+    };
+    cresult(fun())
+}
+
 #[no_mangle]
 pub extern "C" fn Vault_load(json: *const raw::c_char) -> CPtrResult<CVault> {
     let fun = || {
@@ -72,3 +88,50 @@ pub extern "C" fn Vault_dirty_get(vault: *mut CVault) -> CPtrResult<raw::c_uchar
     };
     cresult(fun())
 }
+
+#[no_mangle]
+pub extern "C" fn Vault_bip85_mnemonic(
+    vault: *mut CVault, unlock_pwd: *const raw::c_char, lang: *const raw::c_char, words: i32,
+    index: i32,
+) -> CPtrResult<raw::c_char> {
+    let vault = unsafe { convert::borrow_in(vault) };
+    let fun = || {
+        let unlock_password = unsafe { convert::str_in(unlock_pwd)? };
+        let lang_code = unsafe { convert::str_in(lang)? };
+        let lang = Bip39Language::from_language_code(lang_code)
+            .ok_or_else(|| format_err!("Invalid BIP39 language code {}", lang_code))?;
+        ensure!(words > 0, "Number of words must be positive");
+        let bip85 = vault.inner.bip85(unlock_password)?;
+        let phrase = bip85.bip39(lang, words as usize, index)?;
+        Ok(convert::string_out(phrase.as_phrase().to_owned()))
+    };
+    cresult(fun())
+}
+
+#[no_mangle]
+pub extern "C" fn Vault_bip85_wif(
+    vault: *mut CVault, unlock_pwd: *const raw::c_char, network: *const raw::c_char, index: i32,
+) -> CPtrResult<raw::c_char> {
+    let vault = unsafe { convert::borrow_in(vault) };
+    let fun = || {
+        let unlock_password = unsafe { convert::str_in(unlock_pwd)? };
+        let network = Networks::by_name(unsafe { convert::str_in(network)? })?;
+        let bip85 = vault.inner.bip85(unlock_password)?;
+        Ok(convert::string_out(bip85.wif(network, index)?))
+    };
+    cresult(fun())
+}
+
+#[no_mangle]
+pub extern "C" fn Vault_bip85_hex(
+    vault: *mut CVault, unlock_pwd: *const raw::c_char, num_bytes: i32, index: i32,
+) -> CPtrResult<raw::c_char> {
+    let vault = unsafe { convert::borrow_in(vault) };
+    let fun = || {
+        let unlock_password = unsafe { convert::str_in(unlock_pwd)? };
+        ensure!(num_bytes > 0, "Number of bytes must be positive");
+        let bip85 = vault.inner.bip85(unlock_password)?;
+        Ok(convert::string_out(bip85.hex(num_bytes as usize, index)?))
+    };
+    cresult(fun())
+}
@@ -0,0 +1,81 @@
+use super::*;
+
+#[no_mangle]
+pub extern "C" fn witness_statement_to_credential(
+    signed_statement: *const raw::c_char,
+) -> CPtrResult<raw::c_char> {
+    let fun = || {
+        let signed_str = unsafe { convert::str_in(signed_statement)? };
+        let signed: Signed<WitnessStatement> = serde_json::from_str(signed_str)?;
+        let vc = VerifiableCredential::from_signed(&signed);
+        Ok(convert::string_out(serde_json::to_string(&vc)?))
+    };
+    cresult(fun())
+}
+
+#[no_mangle]
+pub extern "C" fn credential_to_witness_statement(
+    credential: *const raw::c_char,
+) -> CPtrResult<raw::c_char> {
+    let fun = || {
+        let vc_str = unsafe { convert::str_in(credential)? };
+        let vc: VerifiableCredential = serde_json::from_str(vc_str)?;
+        let signed = vc.verify()?;
+        Ok(convert::string_out(serde_json::to_string(&signed)?))
+    };
+    cresult(fun())
+}
+
+#[no_mangle]
+pub extern "C" fn claim_presentation_to_presentation(
+    signed_presentation: *const raw::c_char,
+) -> CPtrResult<raw::c_char> {
+    let fun = || {
+        let signed_str = unsafe { convert::str_in(signed_presentation)? };
+        let signed: Signed<ClaimPresentation> = serde_json::from_str(signed_str)?;
+        let vp = VerifiablePresentation::from_signed(&signed)?;
+        Ok(convert::string_out(serde_json::to_string(&vp)?))
+    };
+    cresult(fun())
+}
+
+#[no_mangle]
+pub extern "C" fn presentation_to_claim_presentation(
+    presentation: *const raw::c_char,
+) -> CPtrResult<raw::c_char> {
+    let fun = || {
+        let vp_str = unsafe { convert::str_in(presentation)? };
+        let vp: VerifiablePresentation = serde_json::from_str(vp_str)?;
+        let signed = vp.verify()?;
+        Ok(convert::string_out(serde_json::to_string(&signed)?))
+    };
+    cresult(fun())
+}
+
+/// The content paths are passed as a JSON array, the proven claim is returned as JSON.
+#[no_mangle]
+pub extern "C" fn disclose_claim(
+    signed_statement: *const raw::c_char, content_paths: *const raw::c_char,
+) -> CPtrResult<raw::c_char> {
+    let fun = || {
+        let signed_str = unsafe { convert::str_in(signed_statement)? };
+        let signed: Signed<WitnessStatement> = serde_json::from_str(signed_str)?;
+        let content_paths: Vec<String> =
+            serde_json::from_str(unsafe { convert::str_in(content_paths)? })?;
+        let content_paths = content_paths.iter().map(|p| p.as_str()).collect::<Vec<_>>();
+        let proven = ProvenClaim::disclose(&signed, &content_paths)?;
+        Ok(convert::string_out(serde_json::to_string(&proven)?))
+    };
+    cresult(fun())
+}
+
+#[no_mangle]
+pub extern "C" fn verify_proven_claim(proven_claim: *const raw::c_char) -> CPtrResult<raw::c_char> {
+    let fun = || {
+        let proven_str = unsafe { convert::str_in(proven_claim)? };
+        let proven: ProvenClaim = serde_json::from_str(proven_str)?;
+        let claim_id = proven.verify()?;
+        Ok(convert::string_out(claim_id))
+    };
+    cresult(fun())
+}
@@ -0,0 +1,13 @@
+mod plugin;
+mod private;
+mod public;
+
+pub use plugin::*;
+pub use private::*;
+pub use public::*;
+
+use super::*;
+
+type CoeusPublic = hd_coeus::Public;
+type CoeusPrivate = hd_coeus::Private;
+type CoeusBoundPlugin = BoundPlugin<hd_coeus::Plugin, CoeusPublic, CoeusPrivate>;
@@ -0,0 +1,52 @@
+use super::*;
+
+/// Represents the Coeus subtree in a given vault, which holds keys owning domain names.
+#[wasm_bindgen(js_name = CoeusPlugin)]
+pub struct JsCoeusPlugin {
+    inner: CoeusBoundPlugin,
+}
+
+#[wasm_bindgen(js_class = CoeusPlugin)]
+impl JsCoeusPlugin {
+    /// Creates the Coeus subtree in the vault. If the subtree already exists, an error will be
+    /// thrown. An existing subtree has to be retrieved from the vault using {@link get}.
+    pub fn init(vault: &mut JsVault, unlock_password: &str) -> Result<(), JsValue> {
+        hd_coeus::Plugin::init(vault.inner_mut(), unlock_password).map_err_to_js()?;
+        Ok(())
+    }
+
+    /// Retrieves an existing Coeus subtree from the vault. If the subtree is missing, an error will be thrown. A new subtree can be
+    /// created with {@link init}.
+    pub fn get(vault: &JsVault) -> Result<JsCoeusPlugin, JsValue> {
+        let inner = hd_coeus::Plugin::get(vault.inner()).map_err_to_js()?;
+        Ok(Self { inner })
+    }
+
+    /// Accessor for the public keys, owned domains and nonces in the Coeus subtree.
+    #[wasm_bindgen(getter = pub)]
+    pub fn public(&self) -> Result<JsCoeusPublic, JsValue> {
+        let inner = self.inner.public().map_err_to_js()?;
+        Ok(JsCoeusPublic::from(inner))
+    }
+
+    /// Accessor for the private keys in the Coeus subtree. Needs the unlock password.
+    ///
+    /// @see Vault.unlock
+    #[wasm_bindgen(js_name = priv)]
+    pub fn private(&self, unlock_password: &str) -> Result<JsCoeusPrivate, JsValue> {
+        let inner = self.inner.private(unlock_password).map_err_to_js()?;
+        Ok(JsCoeusPrivate::from(inner))
+    }
+}
+
+impl From<CoeusBoundPlugin> for JsCoeusPlugin {
+    fn from(inner: CoeusBoundPlugin) -> Self {
+        Self { inner }
+    }
+}
+
+impl Wraps<CoeusBoundPlugin> for JsCoeusPlugin {
+    fn inner(&self) -> &CoeusBoundPlugin {
+        &self.inner
+    }
+}
@@ -0,0 +1,52 @@
+use super::*;
+
+/// Private keys of domain owners in the Coeus subtree in a vault.
+///
+/// @see CoeusPlugin.priv
+#[wasm_bindgen(js_name = CoeusPrivate)]
+pub struct JsCoeusPrivate {
+    inner: CoeusPrivate,
+}
+
+#[wasm_bindgen(js_class = CoeusPrivate)]
+impl JsCoeusPrivate {
+    /// Access to the public keys in the subtree. Same as {@link CoeusPlugin.pub} would return.
+    #[wasm_bindgen(getter = pub)]
+    pub fn public(&self) -> JsCoeusPublic {
+        JsCoeusPublic::from(self.inner.public())
+    }
+
+    /// Returns the multicipher {@link PrivateKey} with the given index, generating it and all keys before it if needed.
+    pub fn key(&mut self, idx: i32) -> Result<JsMPrivateKey, JsValue> {
+        let inner = self.inner.key_mut(idx).map_err_to_js()?;
+        Ok(JsMPrivateKey::from(inner))
+    }
+
+    /// Finds the multicipher {@link PrivateKey} that belongs to the given {@link PublicKey}.
+    #[wasm_bindgen(js_name = keyByPublicKey)]
+    pub fn key_by_pk(&self, pk: &JsMPublicKey) -> Result<JsMPrivateKey, JsValue> {
+        let inner = self.inner.key_by_pk(pk.inner()).map_err_to_js()?;
+        Ok(JsMPrivateKey::from(inner))
+    }
+
+    /// Signs the bundle with the owner key that belongs to the given {@link PublicKey}. The nonce of the bundle must not have been
+    /// used by that key before, and it is remembered as its last nonce after signing.
+    pub fn sign(
+        &mut self, pk: &JsMPublicKey, bundle: &JsNoncedBundle,
+    ) -> Result<JsSignedBundle, JsValue> {
+        let inner = self.inner.sign(pk.inner(), bundle.inner().to_owned()).map_err_to_js()?;
+        Ok(JsSignedBundle::from(inner))
+    }
+}
+
+impl From<CoeusPrivate> for JsCoeusPrivate {
+    fn from(inner: CoeusPrivate) -> Self {
+        Self { inner }
+    }
+}
+
+impl Wraps<CoeusPrivate> for JsCoeusPrivate {
+    fn inner(&self) -> &CoeusPrivate {
+        &self.inner
+    }
+}
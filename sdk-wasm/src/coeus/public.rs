@@ -0,0 +1,90 @@
+use super::*;
+
+/// Public keys of domain owners in the Coeus subtree in a vault. The vault also remembers the domains owned and the last nonce used
+/// by each key.
+///
+/// @see CoeusPlugin.pub
+#[wasm_bindgen(js_name = CoeusPublic)]
+pub struct JsCoeusPublic {
+    inner: CoeusPublic,
+}
+
+#[wasm_bindgen(js_class = CoeusPublic)]
+impl JsCoeusPublic {
+    /// Retrieves how many owner keys have already been generated.
+    #[wasm_bindgen(getter)]
+    pub fn count(&self) -> Result<u32, JsValue> {
+        self.inner.len().map_err_to_js()
+    }
+
+    /// Retrieves the multicipher {@link PublicKey} with the given index.
+    ///
+    /// An error is thrown if that index was not generated yet with {@link CoeusPrivate.key}.
+    pub fn key(&self, idx: i32) -> Result<JsMPublicKey, JsValue> {
+        let inner = self.inner.key(idx).map_err_to_js()?;
+        Ok(JsMPublicKey::from(inner))
+    }
+
+    /// The nonce of the last bundle signed by the given key.
+    #[wasm_bindgen(js_name = lastNonce)]
+    pub fn last_nonce(&self, pk: &JsMPublicKey) -> Result<Nonce, JsValue> {
+        self.inner.last_nonce(pk.inner()).map_err_to_js()
+    }
+
+    /// The nonce the next bundle signed by the given key has to use.
+    #[wasm_bindgen(js_name = nextNonce)]
+    pub fn next_nonce(&self, pk: &JsMPublicKey) -> Result<Nonce, JsValue> {
+        self.inner.next_nonce(pk.inner()).map_err_to_js()
+    }
+
+    /// Overwrites the last nonce remembered for the given key, e.g. to synchronize it with the state of the ledger.
+    #[wasm_bindgen(js_name = setLastNonce)]
+    pub fn set_last_nonce(&mut self, pk: &JsMPublicKey, nonce: Nonce) -> Result<(), JsValue> {
+        self.inner.set_last_nonce(pk.inner(), nonce).map_err_to_js()
+    }
+
+    /// Creates a {@link NoncedBundle} of the operations in the builder using the next nonce of the given key.
+    pub fn bundle(
+        &self, pk: &JsMPublicKey, builder: &JsNoncedBundleBuilder,
+    ) -> Result<JsNoncedBundle, JsValue> {
+        let nonce = self.inner.next_nonce(pk.inner()).map_err_to_js()?;
+        Ok(builder.build(nonce))
+    }
+
+    /// Domain names remembered to be owned by the given key.
+    pub fn domains(&self, pk: &JsMPublicKey) -> Result<Vec<JsValue>, JsValue> {
+        let domains = self.inner.domains(pk.inner()).map_err_to_js()?;
+        Ok(domains.into_iter().map(|d| JsValue::from(JsDomainName::from(d))).collect())
+    }
+
+    /// Finds the key that is remembered to own the given domain name.
+    #[wasm_bindgen(js_name = ownerOf)]
+    pub fn owner_of(&self, name: &JsDomainName) -> Result<JsMPublicKey, JsValue> {
+        let inner = self.inner.owner_of(name.inner()).map_err_to_js()?;
+        Ok(JsMPublicKey::from(inner))
+    }
+
+    /// Remembers that the given key owns a domain name.
+    #[wasm_bindgen(js_name = addDomain)]
+    pub fn add_domain(&mut self, pk: &JsMPublicKey, name: &JsDomainName) -> Result<(), JsValue> {
+        self.inner.add_domain(pk.inner(), name.inner()).map_err_to_js()
+    }
+
+    /// Forgets that the given key owns a domain name.
+    #[wasm_bindgen(js_name = removeDomain)]
+    pub fn remove_domain(&mut self, pk: &JsMPublicKey, name: &JsDomainName) -> Result<(), JsValue> {
+        self.inner.remove_domain(pk.inner(), name.inner()).map_err_to_js()
+    }
+}
+
+impl From<CoeusPublic> for JsCoeusPublic {
+    fn from(inner: CoeusPublic) -> Self {
+        Self { inner }
+    }
+}
+
+impl Wraps<CoeusPublic> for JsCoeusPublic {
+    fn inner(&self) -> &CoeusPublic {
+        &self.inner
+    }
+}
@@ -0,0 +1,14 @@
+mod plugin;
+mod private;
+mod public;
+
+pub use plugin::*;
+pub use private::*;
+pub use public::*;
+
+use super::*;
+
+type CredentialPublic = hd_credential::Public;
+type CredentialPrivate = hd_credential::Private;
+type CredentialBoundPlugin =
+    BoundPlugin<hd_credential::Plugin, CredentialPublic, CredentialPrivate>;
@@ -0,0 +1,52 @@
+use super::*;
+
+/// Represents the credential wallet in a given vault, which holds the witness statements received by the owner of the vault.
+#[wasm_bindgen(js_name = CredentialPlugin)]
+pub struct JsCredentialPlugin {
+    inner: CredentialBoundPlugin,
+}
+
+#[wasm_bindgen(js_class = CredentialPlugin)]
+impl JsCredentialPlugin {
+    /// Creates an empty credential wallet in the vault. If the wallet already exists, an error will be thrown. An existing wallet
+    /// has to be retrieved from the vault using {@link get}.
+    pub fn init(vault: &mut JsVault) -> Result<(), JsValue> {
+        hd_credential::Plugin::init(vault.inner_mut()).map_err_to_js()?;
+        Ok(())
+    }
+
+    /// Retrieves an existing credential wallet from the vault. If the wallet is missing, an error will be thrown. A new wallet can be
+    /// created with {@link init}.
+    pub fn get(vault: &JsVault) -> Result<JsCredentialPlugin, JsValue> {
+        let inner = hd_credential::Plugin::get(vault.inner()).map_err_to_js()?;
+        Ok(Self { inner })
+    }
+
+    /// Accessor for the index of credentials, which can be queried without unlocking the vault.
+    #[wasm_bindgen(getter = pub)]
+    pub fn public(&self) -> Result<JsCredentialPublic, JsValue> {
+        let inner = self.inner.public().map_err_to_js()?;
+        Ok(JsCredentialPublic::from(inner))
+    }
+
+    /// Accessor for the decrypted credentials. Needs the unlock password.
+    ///
+    /// @see Vault.unlock
+    #[wasm_bindgen(js_name = priv)]
+    pub fn private(&self, unlock_password: &str) -> Result<JsCredentialPrivate, JsValue> {
+        let inner = self.inner.private(unlock_password).map_err_to_js()?;
+        Ok(JsCredentialPrivate::from(inner))
+    }
+}
+
+impl From<CredentialBoundPlugin> for JsCredentialPlugin {
+    fn from(inner: CredentialBoundPlugin) -> Self {
+        Self { inner }
+    }
+}
+
+impl Wraps<CredentialBoundPlugin> for JsCredentialPlugin {
+    fn inner(&self) -> &CredentialBoundPlugin {
+        &self.inner
+    }
+}
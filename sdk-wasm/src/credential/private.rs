@@ -0,0 +1,62 @@
+use super::*;
+
+/// Decrypted credentials in a vault.
+///
+/// @see CredentialPlugin.priv
+#[wasm_bindgen(js_name = CredentialPrivate)]
+pub struct JsCredentialPrivate {
+    inner: CredentialPrivate,
+}
+
+#[wasm_bindgen(js_class = CredentialPrivate)]
+impl JsCredentialPrivate {
+    /// Access to the index of credentials. Same as {@link CredentialPlugin.pub} would return.
+    #[wasm_bindgen(getter = pub)]
+    pub fn public(&self) -> JsCredentialPublic {
+        JsCredentialPublic::from(self.inner.public())
+    }
+
+    /// Stores a signed witness statement in the vault and returns its content id. An error will be thrown if the JSON does not
+    /// conform to the schema of a witness statement or its signature is invalid.
+    pub fn add(&mut self, statement: &JsSignedJson) -> Result<String, JsValue> {
+        let (public_key, content, signature, nonce) = statement.inner().to_owned().into_parts();
+        let content: WitnessStatement = serde_json::from_value(content).map_err_to_js()?;
+        let statement = Signed::from_parts(public_key, content, signature, nonce);
+        self.inner.add(statement).map_err_to_js()
+    }
+
+    /// Decrypts the signed witness statement with the given content id.
+    pub fn get(&self, content_id: &str) -> Result<JsSignedJson, JsValue> {
+        let statement = self.inner.get(content_id).map_err_to_js()?;
+        into_signed_json(statement)
+    }
+
+    /// Assembles a claim presentation from the credentials with the given content ids and the signed licenses provided as JSON. The
+    /// presentation still has to be signed by the holder, e.g. with {@link MorpheusPrivate.signClaimPresentation}.
+    pub fn presentation(
+        &self, content_ids: Vec<JsValue>, licenses: &JsValue,
+    ) -> Result<JsValue, JsValue> {
+        let content_ids = content_ids
+            .iter()
+            .map(|id| {
+                id.as_string().ok_or_else(|| JsValue::from_str("Content id must be a string"))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        let licenses: Vec<Signed<License>> = from_value(licenses.clone())?;
+        let presentation = self.inner.presentation(&content_ids, licenses).map_err_to_js()?;
+        let res = to_value(&presentation)?;
+        Ok(res)
+    }
+}
+
+impl From<CredentialPrivate> for JsCredentialPrivate {
+    fn from(inner: CredentialPrivate) -> Self {
+        Self { inner }
+    }
+}
+
+impl Wraps<CredentialPrivate> for JsCredentialPrivate {
+    fn inner(&self) -> &CredentialPrivate {
+        &self.inner
+    }
+}
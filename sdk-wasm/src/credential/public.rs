@@ -0,0 +1,77 @@
+use super::*;
+
+/// Index of the credentials in a vault. Subjects, processes and content ids of the witness statements are visible without the
+/// unlock password, but the statements themselves are encrypted.
+///
+/// @see CredentialPlugin.pub
+#[wasm_bindgen(js_name = CredentialPublic)]
+pub struct JsCredentialPublic {
+    inner: CredentialPublic,
+}
+
+#[wasm_bindgen(js_class = CredentialPublic)]
+impl JsCredentialPublic {
+    /// Retrieves how many credentials are stored in the vault.
+    #[wasm_bindgen(getter)]
+    pub fn count(&self) -> Result<u32, JsValue> {
+        self.inner.len().map_err_to_js()
+    }
+
+    /// Content ids of all credentials in the order they were added.
+    #[wasm_bindgen(js_name = contentIds)]
+    pub fn content_ids(&self) -> Result<Vec<JsValue>, JsValue> {
+        let entries = self.inner.entries().map_err_to_js()?;
+        Ok(entries.iter().map(|e| JsValue::from_str(e.content_id())).collect())
+    }
+
+    /// Whether a credential with the given content id is stored in the vault.
+    pub fn contains(&self, content_id: &str) -> Result<bool, JsValue> {
+        self.inner.contains(content_id).map_err_to_js()
+    }
+
+    /// The subject of the claim in the credential with the given content id.
+    #[wasm_bindgen(js_name = subjectOf)]
+    pub fn subject_of(&self, content_id: &str) -> Result<JsDid, JsValue> {
+        let entry = self.inner.entry(content_id).map_err_to_js()?;
+        let subject = entry.subject().map_err_to_js()?;
+        Ok(JsDid::from(subject))
+    }
+
+    /// The process the credential with the given content id was witnessed according to.
+    #[wasm_bindgen(js_name = processOf)]
+    pub fn process_of(&self, content_id: &str) -> Result<String, JsValue> {
+        let entry = self.inner.entry(content_id).map_err_to_js()?;
+        Ok(entry.process_id().to_owned())
+    }
+
+    /// Content ids of the credentials witnessing claims about the given subject.
+    #[wasm_bindgen(js_name = bySubject)]
+    pub fn by_subject(&self, subject: &JsDid) -> Result<Vec<JsValue>, JsValue> {
+        let ids = self.inner.by_subject(subject.inner()).map_err_to_js()?;
+        Ok(ids.iter().map(|id| JsValue::from_str(id)).collect())
+    }
+
+    /// Content ids of the credentials witnessed according to the given process.
+    #[wasm_bindgen(js_name = byProcess)]
+    pub fn by_process(&self, process_id: &str) -> Result<Vec<JsValue>, JsValue> {
+        let ids = self.inner.by_process(process_id).map_err_to_js()?;
+        Ok(ids.iter().map(|id| JsValue::from_str(id)).collect())
+    }
+
+    /// Forgets the credential with the given content id.
+    pub fn remove(&mut self, content_id: &str) -> Result<(), JsValue> {
+        self.inner.remove(content_id).map_err_to_js()
+    }
+}
+
+impl From<CredentialPublic> for JsCredentialPublic {
+    fn from(inner: CredentialPublic) -> Self {
+        Self { inner }
+    }
+}
+
+impl Wraps<CredentialPublic> for JsCredentialPublic {
+    fn inner(&self) -> &CredentialPublic {
+        &self.inner
+    }
+}
@@ -18,6 +18,17 @@ impl JsHydraPlugin {
         Ok(())
     }
 
+    /// Adds a watch-only Hydra account to the vault from its extended public key, without needing the seed or the unlock password.
+    /// Addresses can be generated in such an account, but {@link priv} will throw an error, because it cannot sign anything.
+    #[wasm_bindgen(js_name = importXpub)]
+    pub fn import_xpub(
+        vault: &mut JsVault, parameters: &JsHydraParameters, xpub: &str,
+    ) -> Result<(), JsValue> {
+        hd_hydra::Plugin::import_xpub(vault.inner_mut(), parameters.inner(), xpub)
+            .map_err_to_js()?;
+        Ok(())
+    }
+
     /// Retrieves an existing Hydra account from the vault. If the account is missing, an error will be thrown. A new account can be
     /// created with {@link init}.
     pub fn get(vault: &JsVault, parameters: &JsHydraParameters) -> Result<JsHydraPlugin, JsValue> {
@@ -25,6 +36,12 @@ impl JsHydraPlugin {
         Ok(Self { inner })
     }
 
+    /// Whether the account was imported from an xpub and therefore has no private keys in the vault.
+    #[wasm_bindgen(getter = watchOnly)]
+    pub fn is_watch_only(&self) -> bool {
+        self.inner.plugin().is_watch_only()
+    }
+
     /// Accessor for the public keys in the Hydra account
     #[wasm_bindgen(getter = pub)]
     pub fn public(&self) -> Result<JsHydraPublic, JsValue> {
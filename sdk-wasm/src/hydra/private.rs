@@ -58,6 +58,25 @@ impl JsHydraPrivate {
         self.inner().xprv()
     }
 
+    /// The fingerprint of the master key of the vault as 8 hex digits, used by other wallets to identify the seed.
+    #[wasm_bindgen(getter = masterFingerprint)]
+    pub fn master_fingerprint(&self) -> String {
+        self.inner().master_fingerprint().to_string()
+    }
+
+    /// The xpub of the account with its key origin, like `[d34db33f/44'/4741444'/0']xpub...`, so hardware wallets and coordinators
+    /// can tell which keys belong to this vault.
+    #[wasm_bindgen(getter = keyOrigin)]
+    pub fn key_origin(&self) -> String {
+        self.inner().key_origin()
+    }
+
+    /// The `pkh(...)` output descriptor of the receiving (`change` is false) or change (`change` is true) addresses. If `change` is
+    /// not given, the descriptor covers both chains.
+    pub fn descriptor(&self, change: Option<bool>) -> String {
+        self.inner().descriptor(change.map(Chain::from))
+    }
+
     /// How many receive addresses have been used in this {@link Bip44Account}
     #[wasm_bindgen(getter = receiveKeys)]
     pub fn receive_keys(&self) -> Result<u32, JsValue> {
@@ -5,12 +5,18 @@
 //! This module provides WASM bindings to functionality needed in a client application using the IOP Stack™. Network related
 //! asynchronous code is provided in the TypeScript part of the SDK and is not done from these WASM bindings.
 
+mod coeus;
+mod credential;
 mod hydra;
 mod morpheus;
+mod subtree;
 mod vault;
 
+pub use coeus::*;
+pub use credential::*;
 pub use hydra::*;
 pub use morpheus::*;
+pub use subtree::*;
 pub use vault::*;
 
 // imports from standard library
@@ -26,24 +32,30 @@ use wasm_bindgen::prelude::*;
 
 // imports from own crates
 
+use iop_coeus_sdk::vault as hd_coeus;
 use iop_hydra_proto::{
     txtype::{hyd_core, Aip29Transaction, CommonTransactionFields, OptionalTransactionFields},
     TransactionData as HydraTransactionData,
 };
 use iop_hydra_sdk::vault as hd_hydra;
+use iop_journal_proto::Nonce;
 use iop_keyvault::{
     ed25519::DidKind,
     multicipher::*,
     secp256k1::{Secp256k1, SecpPublicKey},
-    Network, Networks, PublicKey as _,
+    Bip39Language, Chain, Network, Networks, PublicKey as _,
 };
 use iop_keyvault_wasm::*;
 use iop_morpheus_proto::{
-    crypto::sign::{PrivateKeySigner, Signable, Signed, SyncMorpheusSigner},
-    data::{Authentication, ClaimPresentation, Did, WitnessRequest, WitnessStatement},
+    crypto::sign::{AfterEnvelope, PrivateKeySigner, Signable, Signed, SyncMorpheusSigner},
+    data::{
+        Authentication, ClaimPresentation, Did, License, Right, Service, ServiceType,
+        WitnessRequest, WitnessStatement,
+    },
+    login::{LoginChallenge, LoginResponse},
 };
-use iop_morpheus_sdk::vault as hd_morpheus;
-use iop_vault::{BoundPlugin, Vault, VaultPlugin};
+use iop_morpheus_sdk::{credential as hd_credential, vault as hd_morpheus};
+use iop_vault::{subtree as hd_subtree, BoundPlugin, Vault, VaultPlugin};
 
 pub use iop_keyvault_wasm::*;
 pub use iop_proto_wasm::*;
@@ -153,6 +153,55 @@ impl JsMorpheusPrivate {
         into_signed_json(signed_presentation)
     }
 
+    /// Signs a license with a private key that belongs to the given multicipher {@link KeyId}. The key has to have the
+    /// impersonation right on the issuer of the license. An error will be thrown if the JSON does not conform to the schema of a
+    /// license.
+    ///
+    /// @see keyById
+    #[wasm_bindgen(js_name = signLicense)]
+    pub fn sign_license(
+        &self, id: &JsMKeyId, js_license: &JsValue,
+    ) -> Result<JsSignedJson, JsValue> {
+        let signer = self.create_signer(id)?;
+        let license: License = from_value(js_license.clone())?;
+        let signed_license = signer.sign_license(license).map_err_to_js()?;
+
+        into_signed_json(signed_license)
+    }
+
+    /// Signs JSON content anchored to a recent block with a private key that belongs to the given multicipher {@link KeyId}. The
+    /// envelope has the form `{ content, proof: { blockHash, blockHeight } }`, where the block hash proves that the signature was
+    /// created after that block.
+    ///
+    /// @see keyById
+    #[wasm_bindgen(js_name = signAfterEnvelope)]
+    pub fn sign_after_envelope(
+        &self, id: &JsMKeyId, js_envelope: &JsValue,
+    ) -> Result<JsSignedJson, JsValue> {
+        let signer = self.create_signer(id)?;
+        let envelope: AfterEnvelope<serde_json::Value> = from_value(js_envelope.clone())?;
+        let signed_envelope = signer.sign_after_envelope(envelope).map_err_to_js()?;
+
+        into_signed_json(signed_envelope)
+    }
+
+    /// Answers a login challenge of a service with a private key that belongs to the given multicipher {@link KeyId}. The key
+    /// has to have the impersonation right on the DID to log in with.
+    ///
+    /// @see keyById
+    #[wasm_bindgen(js_name = signLoginResponse)]
+    pub fn sign_login_response(
+        &self, id: &JsMKeyId, did: &str, js_challenge: &JsValue,
+    ) -> Result<JsSignedJson, JsValue> {
+        let signer = self.create_signer(id)?;
+        let did: Did = did.parse().map_err_to_js()?;
+        let challenge: LoginChallenge = from_value(js_challenge.clone())?;
+        let response = LoginResponse::new(did, challenge);
+        let signed_response = signer.sign_login_response(response).map_err_to_js()?;
+
+        into_signed_json(signed_response)
+    }
+
     fn kind_impl(&self, did_kind: DidKind) -> Result<JsMorpheusPrivateKind, JsValue> {
         let inner = self.inner.kind(did_kind).map_err_to_js()?;
         Ok(JsMorpheusPrivateKind::from(inner))
@@ -177,7 +226,7 @@ impl Wraps<MorpheusPrivate> for JsMorpheusPrivate {
     }
 }
 
-fn into_signed_json<T: Signable>(signed: Signed<T>) -> Result<JsSignedJson, JsValue> {
+pub(crate) fn into_signed_json<T: Signable>(signed: Signed<T>) -> Result<JsSignedJson, JsValue> {
     let (public_key, content, signature, nonce) = signed.into_parts();
     let content = serde_json::to_value(content).map_err(err_to_js)?;
     let signed_json = Signed::from_parts(public_key, content, signature, nonce);
@@ -138,17 +138,22 @@ impl JsMorpheusOperationBuilder {
 
     /// Create an add key operation. The key can be a {@link KeyId} or a {@link PublicKey} serialized into a string. The expiration can
     /// be left `null`, or it can be a block height, when the key is automatically revoked on-chain without a new transaction sent in.
+    /// The key can also expire at an RFC 3339 timestamp passed as `expiresAt`, in the first block not earlier than that.
+    ///
+    /// The key can also be another DID. Rights added to such an entry are delegated to that DID, so keys having the same right on the
+    /// delegate DID can use it on this DID as well.
     ///
     /// The same key cannot be added when it has not been revoked or before has expired, even if one addition uses an identifier of
     /// the key, and the other addition uses the public key. But the key can be re-added after it has expired or been revoked from the
     /// DID.
     #[wasm_bindgen(js_name = addKey)]
     pub fn add_key(
-        &self, authentication: &str, expires_at_height: &JsValue,
+        &self, authentication: &str, expires_at_height: &JsValue, expires_at: &JsValue,
     ) -> Result<JsMorpheusSignableOperation, JsValue> {
         let auth = Authentication::from_str(authentication).map_err_to_js()?;
         let expires_at_height = from_value(expires_at_height.clone())?;
-        let operation = SignableOperationDetails::AddKey { auth, expires_at_height };
+        let expires_at = from_value(expires_at.clone())?;
+        let operation = SignableOperationDetails::AddKey { auth, expires_at_height, expires_at };
         self.to_attempt(operation)
     }
 
@@ -160,8 +165,22 @@ impl JsMorpheusOperationBuilder {
         self.to_attempt(operation)
     }
 
-    /// Add a given right to a key. 'impersonate' or 'update' are the only choices yet. Cannot add a right to a key that has not yet
-    /// been added to the DID document. Cannot add a right if it was already granted to the key on this DID.
+    /// Create a rotate key operation. The new key gets all rights and the expiration of the old key, and the old key is revoked in
+    /// the same block. The old key has to be valid, and the new key cannot be present in the DID document as a valid key already.
+    /// Unlike revoking, the old key can sign its own rotation.
+    #[wasm_bindgen(js_name = rotateKey)]
+    pub fn rotate_key(
+        &self, old_authentication: &str, new_authentication: &str,
+    ) -> Result<JsMorpheusSignableOperation, JsValue> {
+        let old = Authentication::from_str(old_authentication).map_err_to_js()?;
+        let new = Authentication::from_str(new_authentication).map_err_to_js()?;
+        let operation = SignableOperationDetails::RotateKey { old, new };
+        self.to_attempt(operation)
+    }
+
+    /// Add a given right to a key. Besides the system rights 'impersonate' and 'update', applications can use their own rights in the
+    /// form of 'namespace:name', like 'myapp:sign-contracts'. Cannot add a right to a key that has not yet been added to the DID
+    /// document. Cannot add a right if it was already granted to the key on this DID.
     ///
     /// @see SystemRights
     #[wasm_bindgen(js_name = addRight)]
@@ -169,12 +188,14 @@ impl JsMorpheusOperationBuilder {
         &self, authentication: &str, right: &str,
     ) -> Result<JsMorpheusSignableOperation, JsValue> {
         let auth = Authentication::from_str(authentication).map_err_to_js()?;
-        let operation = SignableOperationDetails::AddRight { auth, right: right.to_owned() };
+        let right = Right::from_str(right).map_err_to_js()?.to_string();
+        let operation = SignableOperationDetails::AddRight { auth, right };
         self.to_attempt(operation)
     }
 
-    /// Revoke a given right from a key. 'impersonate' or 'update' are the only choices yet. Cannot revoke a right to a key that has
-    /// not yet been added to the DID document. Cannot revoke a right if it was not yet granted to the key on this DID.
+    /// Revoke a given right from a key. Either a system right or a custom right in the form of 'namespace:name'. Cannot revoke a right
+    /// to a key that has not yet been added to the DID document. Cannot revoke a right if it was not yet granted to the key on this
+    /// DID.
     ///
     /// @see SystemRights
     #[wasm_bindgen(js_name = revokeRight)]
@@ -182,7 +203,8 @@ impl JsMorpheusOperationBuilder {
         &self, authentication: &str, right: &str,
     ) -> Result<JsMorpheusSignableOperation, JsValue> {
         let auth = Authentication::from_str(authentication).map_err_to_js()?;
-        let operation = SignableOperationDetails::RevokeRight { auth, right: right.to_owned() };
+        let right = Right::from_str(right).map_err_to_js()?.to_string();
+        let operation = SignableOperationDetails::RevokeRight { auth, right };
         self.to_attempt(operation)
     }
 
@@ -192,6 +214,37 @@ impl JsMorpheusOperationBuilder {
         let operation = SignableOperationDetails::TombstoneDid {};
         self.to_attempt(operation)
     }
+
+    /// Add a service endpoint to the DID document. The type can be 'DIDCommMessaging', 'Storage' or 'LinkedDomains'. The name
+    /// identifies the service within the DID, and can contain only ASCII letters, digits, '-' and '_'. Cannot add a service while
+    /// another one with the same name has not been revoked.
+    #[wasm_bindgen(js_name = addService)]
+    pub fn add_service(
+        &self, service_type: &str, name: &str, service_endpoint: &str,
+    ) -> Result<JsMorpheusSignableOperation, JsValue> {
+        let type_ = ServiceType::from_str(service_type).map_err_to_js()?;
+        let service = Service::new(type_, name, service_endpoint);
+        let operation = SignableOperationDetails::AddService { service };
+        self.to_attempt(operation)
+    }
+
+    /// Revoke the service with the given name from the DID document. A service cannot be revoked if it was not added or has
+    /// already been revoked.
+    #[wasm_bindgen(js_name = revokeService)]
+    pub fn revoke_service(&self, name: &str) -> Result<JsMorpheusSignableOperation, JsValue> {
+        let operation = SignableOperationDetails::RevokeService { name: name.to_owned() };
+        self.to_attempt(operation)
+    }
+
+    /// Set how many distinct keys with update right have to sign any later operations on the DID. The threshold cannot be
+    /// higher than the number of keys currently holding the update right.
+    #[wasm_bindgen(js_name = setUpdateThreshold)]
+    pub fn set_update_threshold(
+        &self, threshold: u32,
+    ) -> Result<JsMorpheusSignableOperation, JsValue> {
+        let operation = SignableOperationDetails::SetUpdateThreshold { threshold };
+        self.to_attempt(operation)
+    }
 }
 
 impl JsMorpheusOperationBuilder {
@@ -294,6 +347,28 @@ impl JsMorpheusSignedOperation {
     pub fn to_json(&self) -> Result<JsValue, JsValue> {
         to_value(&self.inner).map_err_to_js()
     }
+
+    /// Adds a co-signature with the provided {@link PrivateKey}. DIDs with an update threshold above 1 need operations signed by
+    /// that many distinct keys.
+    #[wasm_bindgen(js_name = cosignWithKey)]
+    pub fn cosign_with_key(&mut self, private_key: &JsMPrivateKey) -> Result<(), JsValue> {
+        self.cosign_inner(private_key.inner().to_owned())
+    }
+
+    /// A convenience method to add a co-signature with a {@link PublicKey} from the vault.
+    ///
+    /// @see MorpheusPrivate, MorpheusPrivate.key_by_pk
+    pub fn cosign(
+        &mut self, public_key: JsMPublicKey, morpheus_private: &JsMorpheusPrivate,
+    ) -> Result<(), JsValue> {
+        let private_key = morpheus_private.inner().key_by_pk(public_key.inner()).map_err_to_js()?;
+        self.cosign_inner(private_key.private_key())
+    }
+
+    fn cosign_inner(&mut self, private_key: MPrivateKey) -> Result<(), JsValue> {
+        let signer = PrivateKeySigner::new(private_key);
+        self.inner.cosign(&signer).map_err_to_js()
+    }
 }
 
 impl From<SignedOperation> for JsMorpheusSignedOperation {
@@ -0,0 +1,16 @@
+mod parameters;
+mod plugin;
+mod private;
+mod public;
+
+pub use parameters::*;
+pub use plugin::*;
+pub use private::*;
+pub use public::*;
+
+use super::*;
+
+type SubtreeParameters = hd_subtree::Parameters;
+type SubtreePublic = hd_subtree::Public;
+type SubtreePrivate = hd_subtree::Private;
+type SubtreeBoundPlugin = BoundPlugin<hd_subtree::Plugin, SubtreePublic, SubtreePrivate>;
@@ -0,0 +1,51 @@
+use super::*;
+
+/// Parameters of an application specific BIP32 subtree added to a {@link Vault}
+#[wasm_bindgen(js_name = SubtreeParameters)]
+pub struct JsSubtreeParameters {
+    inner: SubtreeParameters,
+}
+
+#[wasm_bindgen(js_class = SubtreeParameters)]
+impl JsSubtreeParameters {
+    /// Creates a parameter object for a subtree. The cipher suite is either 'ed25519' or 'secp256k1', the path is a BIP32 derivation
+    /// path like `m/9791'/0'`. Ed25519 subtrees can only have hardened indices in their path.
+    #[wasm_bindgen(constructor)]
+    pub fn new(suite: &str, path: &str) -> Result<JsSubtreeParameters, JsValue> {
+        let suite = CipherSuite::from_str(suite).map_err_to_js()?;
+        let inner = SubtreeParameters::new(suite, path).map_err_to_js()?;
+        Ok(JsSubtreeParameters::from(inner))
+    }
+
+    /// Creates a parameter object for the subtree `m/purpose'` reserved for an application by BIP43.
+    pub fn bip43(suite: &str, purpose: i32) -> Result<JsSubtreeParameters, JsValue> {
+        let suite = CipherSuite::from_str(suite).map_err_to_js()?;
+        let inner = SubtreeParameters::bip43(suite, purpose).map_err_to_js()?;
+        Ok(JsSubtreeParameters::from(inner))
+    }
+
+    /// The cipher suite of the keys in the subtree.
+    #[wasm_bindgen(getter)]
+    pub fn suite(&self) -> String {
+        self.inner.suite().to_string()
+    }
+
+    /// The BIP32 path of the parent node of the keys in the subtree.
+    #[wasm_bindgen(getter)]
+    pub fn path(&self) -> Result<String, JsValue> {
+        let path = self.inner.path().map_err_to_js()?;
+        Ok(path.to_string())
+    }
+}
+
+impl From<SubtreeParameters> for JsSubtreeParameters {
+    fn from(inner: SubtreeParameters) -> Self {
+        Self { inner }
+    }
+}
+
+impl Wraps<SubtreeParameters> for JsSubtreeParameters {
+    fn inner(&self) -> &SubtreeParameters {
+        &self.inner
+    }
+}
@@ -0,0 +1,57 @@
+use super::*;
+
+/// Represents an application specific BIP32 subtree in a given vault.
+#[wasm_bindgen(js_name = SubtreePlugin)]
+pub struct JsSubtreePlugin {
+    inner: SubtreeBoundPlugin,
+}
+
+#[wasm_bindgen(js_class = SubtreePlugin)]
+impl JsSubtreePlugin {
+    /// Creates a new subtree with the given parameters in the vault and issues its first key. If the same subtree already exists,
+    /// an error will be thrown. An existing subtree has to be retrieved from the vault using {@link get}.
+    pub fn init(
+        vault: &mut JsVault, unlock_password: &str, parameters: &JsSubtreeParameters,
+    ) -> Result<(), JsValue> {
+        hd_subtree::Plugin::init(vault.inner_mut(), unlock_password, parameters.inner())
+            .map_err_to_js()?;
+        Ok(())
+    }
+
+    /// Retrieves an existing subtree from the vault. If the subtree is missing, an error will be thrown. A new subtree can be
+    /// created with {@link init}.
+    pub fn get(
+        vault: &JsVault, parameters: &JsSubtreeParameters,
+    ) -> Result<JsSubtreePlugin, JsValue> {
+        let inner = hd_subtree::Plugin::get(vault.inner(), parameters.inner()).map_err_to_js()?;
+        Ok(Self { inner })
+    }
+
+    /// Accessor for the public keys issued in the subtree.
+    #[wasm_bindgen(getter = pub)]
+    pub fn public(&self) -> Result<JsSubtreePublic, JsValue> {
+        let inner = self.inner.public().map_err_to_js()?;
+        Ok(JsSubtreePublic::from(inner))
+    }
+
+    /// Accessor for the private keys in the subtree. Needs the unlock password.
+    ///
+    /// @see Vault.unlock
+    #[wasm_bindgen(js_name = priv)]
+    pub fn private(&self, unlock_password: &str) -> Result<JsSubtreePrivate, JsValue> {
+        let inner = self.inner.private(unlock_password).map_err_to_js()?;
+        Ok(JsSubtreePrivate::from(inner))
+    }
+}
+
+impl From<SubtreeBoundPlugin> for JsSubtreePlugin {
+    fn from(inner: SubtreeBoundPlugin) -> Self {
+        Self { inner }
+    }
+}
+
+impl Wraps<SubtreeBoundPlugin> for JsSubtreePlugin {
+    fn inner(&self) -> &SubtreeBoundPlugin {
+        &self.inner
+    }
+}
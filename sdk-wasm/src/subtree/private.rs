@@ -0,0 +1,43 @@
+use super::*;
+
+/// Private keys in an application specific BIP32 subtree in a vault.
+///
+/// @see SubtreePlugin.priv
+#[wasm_bindgen(js_name = SubtreePrivate)]
+pub struct JsSubtreePrivate {
+    inner: SubtreePrivate,
+}
+
+#[wasm_bindgen(js_class = SubtreePrivate)]
+impl JsSubtreePrivate {
+    /// Access to the public keys in the subtree. Same as {@link SubtreePlugin.pub} would return.
+    #[wasm_bindgen(getter = pub)]
+    pub fn public(&self) -> JsSubtreePublic {
+        JsSubtreePublic::from(self.inner.public())
+    }
+
+    /// Returns the multicipher {@link PrivateKey} with the given index, issuing it and all keys before it if needed.
+    pub fn key(&mut self, idx: i32) -> Result<JsMPrivateKey, JsValue> {
+        let inner = self.inner.key_mut(idx).map_err_to_js()?;
+        Ok(JsMPrivateKey::from(inner))
+    }
+
+    /// Finds the multicipher {@link PrivateKey} that belongs to the given {@link PublicKey}.
+    #[wasm_bindgen(js_name = keyByPublicKey)]
+    pub fn key_by_pk(&self, pk: &JsMPublicKey) -> Result<JsMPrivateKey, JsValue> {
+        let inner = self.inner.key_by_pk(pk.inner()).map_err_to_js()?;
+        Ok(JsMPrivateKey::from(inner))
+    }
+}
+
+impl From<SubtreePrivate> for JsSubtreePrivate {
+    fn from(inner: SubtreePrivate) -> Self {
+        Self { inner }
+    }
+}
+
+impl Wraps<SubtreePrivate> for JsSubtreePrivate {
+    fn inner(&self) -> &SubtreePrivate {
+        &self.inner
+    }
+}
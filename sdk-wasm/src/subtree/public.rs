@@ -0,0 +1,45 @@
+use super::*;
+
+/// Public keys issued in an application specific BIP32 subtree in a vault.
+///
+/// @see SubtreePlugin.pub
+#[wasm_bindgen(js_name = SubtreePublic)]
+pub struct JsSubtreePublic {
+    inner: SubtreePublic,
+}
+
+#[wasm_bindgen(js_class = SubtreePublic)]
+impl JsSubtreePublic {
+    /// Retrieves how many keys have already been issued in the subtree.
+    #[wasm_bindgen(getter)]
+    pub fn count(&self) -> Result<u32, JsValue> {
+        self.inner.len().map_err_to_js()
+    }
+
+    /// Retrieves the multicipher {@link PublicKey} with the given index.
+    ///
+    /// An error is thrown if that index was not issued yet with {@link SubtreePrivate.key}.
+    pub fn key(&self, idx: i32) -> Result<JsMPublicKey, JsValue> {
+        let inner = self.inner.key(idx).map_err_to_js()?;
+        Ok(JsMPublicKey::from(inner))
+    }
+
+    /// Finds the multicipher {@link PublicKey} that matches the given {@link KeyId}.
+    #[wasm_bindgen(js_name = keyById)]
+    pub fn key_by_id(&self, id: &JsMKeyId) -> Result<JsMPublicKey, JsValue> {
+        let inner = self.inner.key_by_id(id.inner()).map_err_to_js()?;
+        Ok(JsMPublicKey::from(inner))
+    }
+}
+
+impl From<SubtreePublic> for JsSubtreePublic {
+    fn from(inner: SubtreePublic) -> Self {
+        Self { inner }
+    }
+}
+
+impl Wraps<SubtreePublic> for JsSubtreePublic {
+    fn inner(&self) -> &SubtreePublic {
+        &self.inner
+    }
+}
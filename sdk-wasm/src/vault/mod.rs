@@ -23,6 +23,19 @@ impl JsVault {
         Ok(Self { inner })
     }
 
+    /// Creates a new in-memory vault object without a seed. Only watch-only accounts, like the ones imported with
+    /// {@link HydraPlugin.importXpub}, can be added to it, and it cannot be unlocked.
+    #[wasm_bindgen(js_name = createWatchOnly)]
+    pub fn create_watch_only() -> JsVault {
+        Self { inner: Vault::create_watch_only() }
+    }
+
+    /// Returns whether the vault was created without a seed.
+    #[wasm_bindgen(getter = watchOnly)]
+    pub fn is_watch_only(&self) -> Result<bool, JsValue> {
+        self.inner.is_watch_only().map_err_to_js()
+    }
+
     /// Loads the vault from its JSON serialization format. Note that no private keys can be calculated without unlocking the loaded
     /// vault with {@link unlock} or with some plugins like {@link HydraPlugin.private} or {@link MorpheusPlugin.private}. The public
     /// keys can be enumerated and used without the unlock password.
@@ -69,6 +82,40 @@ impl JsVault {
         Ok(JsSeed::from(seed))
     }
 
+    /// Derives a child BIP39 phrase from the vault seed according to BIP85. The phrase can be imported into another wallet
+    /// and it can always be recovered from the vault, but it reveals nothing about the vault seed. The number of words can
+    /// be 12, 15, 18, 21 or 24 and the language code is 'en' by default.
+    #[wasm_bindgen(js_name = bip85Mnemonic)]
+    pub fn bip85_mnemonic(
+        &self, unlock_password: &str, words: usize, index: i32, language: Option<String>,
+    ) -> Result<String, JsValue> {
+        let code = language.as_deref().unwrap_or("en");
+        let lang = Bip39Language::from_language_code(code)
+            .ok_or_else(|| JsValue::from(format!("Invalid BIP39 language code {}", code)))?;
+        let bip85 = self.inner.bip85(unlock_password).map_err_to_js()?;
+        let phrase = bip85.bip39(lang, words, index).map_err_to_js()?;
+        Ok(phrase.as_phrase().to_owned())
+    }
+
+    /// Derives a child private key from the vault seed according to BIP85 in the Wallet Import Format of the given network.
+    #[wasm_bindgen(js_name = bip85Wif)]
+    pub fn bip85_wif(
+        &self, unlock_password: &str, network: &str, index: i32,
+    ) -> Result<String, JsValue> {
+        let network = Networks::by_name(network).map_err_to_js()?;
+        let bip85 = self.inner.bip85(unlock_password).map_err_to_js()?;
+        bip85.wif(network, index).map_err_to_js()
+    }
+
+    /// Derives a hex encoded child secret of 16 to 64 bytes from the vault seed according to BIP85.
+    #[wasm_bindgen(js_name = bip85Hex)]
+    pub fn bip85_hex(
+        &self, unlock_password: &str, num_bytes: usize, index: i32,
+    ) -> Result<String, JsValue> {
+        let bip85 = self.inner.bip85(unlock_password).map_err_to_js()?;
+        bip85.hex(num_bytes, index).map_err_to_js()
+    }
+
     fn set_dirty(&mut self, value: bool) -> Result<(), JsValue> {
         let mut vault_dirty = self.inner.to_modifiable();
         let mut dirty = vault_dirty.try_borrow_mut().map_err_to_js()?;
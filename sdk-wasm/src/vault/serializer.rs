@@ -6,6 +6,9 @@ use super::*;
 #[derive(Clone, Debug, Deserialize, Serialize)]
 #[serde(tag = "pluginName")]
 enum VaultPluginSerializer {
+    Bip32Subtree(hd_subtree::Plugin),
+    Coeus(hd_coeus::Plugin),
+    Credentials(hd_credential::Plugin),
     Hydra(hd_hydra::Plugin),
     Morpheus(hd_morpheus::Plugin),
 }
@@ -14,6 +17,9 @@ impl From<VaultPluginSerializer> for Box<dyn VaultPlugin> {
     fn from(plugin: VaultPluginSerializer) -> Box<dyn VaultPlugin> {
         use VaultPluginSerializer::*;
         match plugin {
+            Bip32Subtree(x) => Box::new(x),
+            Coeus(x) => Box::new(x),
+            Credentials(x) => Box::new(x),
             Hydra(x) => Box::new(x),
             Morpheus(x) => Box::new(x),
         }
@@ -24,7 +30,8 @@ impl From<VaultPluginSerializer> for Box<dyn VaultPlugin> {
 #[derive(Clone, Debug, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct VaultSerializer {
-    encrypted_seed: String,
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    encrypted_seed: Option<String>,
     plugins: Vec<VaultPluginSerializer>,
 }
 
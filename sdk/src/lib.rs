@@ -30,12 +30,13 @@ pub mod coeus {
 }
 
 pub mod vault {
+    pub use iop_coeus_sdk::vault as coeus;
     pub use iop_hydra_sdk::vault as hydra;
     pub use iop_keyvault::{
         Bip39, Bip39ErrorKind, Bip39Language, Bip39Phrase, Network, Networks, PrivateKey,
         PublicKey, Seed, Subtree,
     };
-    pub use iop_morpheus_sdk::vault as morpheus;
+    pub use iop_morpheus_sdk::{credential, vault as morpheus};
     pub use iop_vault::*;
 }
 
@@ -180,6 +181,7 @@ mod test {
             operation: morpheus::txtype::SignableOperationDetails::AddKey {
                 auth: auth.clone(),
                 expires_at_height: None,
+                expires_at: None,
             },
         };
         let addright_attempt = morpheus::txtype::SignableOperationAttempt {
@@ -1,5 +1,7 @@
 // sub-modules
 
+pub mod subtree;
+
 mod state;
 mod vault;
 
@@ -27,4 +29,4 @@ use serde::Serialize;
 
 // imports from own crates
 
-use iop_keyvault::{encrypt::*, Bip39, Seed};
+use iop_keyvault::{encrypt::*, Bip39, Bip85, Seed};
@@ -0,0 +1,108 @@
+//! A vault plugin for applications that want to reserve their own key hierarchy in the vault.
+//! The subtree is chosen by a cipher suite and a BIP32 derivation path, the keys are the
+//! hardened children of that node.
+
+mod plugin;
+mod private;
+mod public;
+mod types;
+
+pub use plugin::*;
+pub use private::*;
+pub use public::*;
+pub use types::*;
+
+use super::*;
+
+use anyhow::{bail, Context as _};
+use iop_keyvault::{
+    ed25519::{Ed25519, EdExtPrivateKey, EdKeyId, EdPublicKey},
+    multicipher::{CipherSuite, MKeyId, MPrivateKey, MPublicKey},
+    secp256k1::{Secp256k1, SecpExtPrivateKey, SecpKeyId, SecpPublicKey},
+    Bip32, Bip32Node, Bip43Path, ChildIndex, KeyDerivationCrypto as _, Path as Bip32Path,
+    PrivateKey as _, PublicKey as _, Subtree,
+};
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use iop_keyvault::BIP43_PURPOSE_MERCURY;
+
+    const UNLOCK_PASSWORD: &str = "correct horse battery staple";
+
+    fn vault() -> Result<Vault> {
+        Vault::create(None, Seed::DEMO_PHRASE, "", UNLOCK_PASSWORD)
+    }
+
+    #[test]
+    fn api() -> Result<()> {
+        let mut vault = vault()?;
+        let params = Parameters::bip43(CipherSuite::Ed25519, BIP43_PURPOSE_MERCURY)?;
+        Plugin::init(&mut vault, UNLOCK_PASSWORD, &params)?;
+
+        let mercury = Plugin::get(&vault, &params)?;
+        assert_eq!(mercury.public()?.parameters().path()?.to_string(), "m/9791'");
+        assert_eq!(mercury.public()?.len()?, 1);
+
+        let mut private = mercury.private(UNLOCK_PASSWORD)?;
+        assert!(private.key(1).is_err());
+        let sk2 = private.key_mut(2)?;
+        let pk2 = sk2.public_key();
+
+        let public = mercury.public()?;
+        assert_eq!(public.len()?, 3);
+        assert_eq!(public.key(2)?, pk2);
+        assert_eq!(public.key_by_id(&pk2.key_id())?, pk2);
+        assert_eq!(private.key_by_pk(&pk2)?.public_key(), pk2);
+        assert_ne!(public.key(0)?, pk2);
+
+        let err = Plugin::init(&mut vault, UNLOCK_PASSWORD, &params).unwrap_err();
+        assert!(err.to_string().contains("was already added"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn matches_morpheus_personas() -> Result<()> {
+        let mut vault = vault()?;
+        let params = Parameters::new(CipherSuite::Ed25519, "m/128164'/0'")?;
+        Plugin::init(&mut vault, UNLOCK_PASSWORD, &params)?;
+
+        let key0 = Plugin::get(&vault, &params)?.public()?.key(0)?;
+        assert_eq!(key0.to_string(), "pez2CLkBUjHB8w8G87D3YkREjpRuiqPu6BrRsgHMQy2Pzt6");
+
+        Ok(())
+    }
+
+    #[test]
+    fn several_subtrees() -> Result<()> {
+        let mut vault = vault()?;
+        let devices = Parameters::new(CipherSuite::Ed25519, "m/9791'/1'")?;
+        let messaging = Parameters::new(CipherSuite::Secp256k1, "m/9791'/2'/0")?;
+        Plugin::create(&mut vault, &devices)?;
+        Plugin::init(&mut vault, UNLOCK_PASSWORD, &messaging)?;
+
+        assert!(Plugin::get(&vault, &devices)?.public()?.is_empty()?);
+        let messaging_pk = Plugin::get(&vault, &messaging)?.public()?.key(0)?;
+        assert_eq!(messaging_pk.suite(), CipherSuite::Secp256k1);
+
+        let serialized = serde_json::to_string(&vault)?;
+        let restored: Vault = serde_json::from_str(&serialized)?;
+        let private = Plugin::get(&restored, &messaging)?.private(UNLOCK_PASSWORD)?;
+        assert_eq!(private.key(0)?.public_key(), messaging_pk);
+
+        let unknown = Parameters::new(CipherSuite::Secp256k1, "m/9791'/1'")?;
+        assert!(Plugin::get(&restored, &unknown).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn invalid_parameters() {
+        assert!(Parameters::new(CipherSuite::Ed25519, "m").is_err());
+        assert!(Parameters::new(CipherSuite::Ed25519, "m/9791'/0").is_err());
+        assert!(Parameters::new(CipherSuite::Secp256k1, "9791'").is_err());
+        assert!(Parameters::bip43(CipherSuite::Secp256k1, -1).is_err());
+    }
+}
@@ -0,0 +1,78 @@
+use super::*;
+
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Inner {
+    parameters: Parameters,
+    public_state: PublicState,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Plugin {
+    inner: Arc<RwLock<Inner>>,
+}
+
+#[cfg_attr(target_arch = "wasm32", typetag::serialize(name = "Bip32Subtree"))]
+#[cfg_attr(not(target_arch = "wasm32"), typetag::serde(name = "Bip32Subtree"))]
+impl VaultPlugin for Plugin {
+    fn name(&self) -> &'static str {
+        "Bip32Subtree"
+    }
+
+    fn to_any(&self) -> Box<dyn Any> {
+        Box::new(self.clone())
+    }
+
+    fn eq(&self, other: &dyn VaultPlugin) -> bool {
+        let other: Result<Box<Plugin>, _> = other.to_any().downcast();
+        match other {
+            Ok(p) => self.inner.read().parameters == p.inner.read().parameters,
+            Err(_) => false,
+        }
+    }
+}
+
+impl Plugin {
+    pub fn new(parameters: Parameters, keys: Vec<String>) -> Self {
+        let imp = Inner { parameters, public_state: PublicState { keys } };
+        let inner = Arc::new(RwLock::new(imp));
+        Self { inner }
+    }
+
+    pub fn create(vault: &mut Vault, parameters: &Parameters) -> Result<()> {
+        let plugin = Self::new(parameters.to_owned(), vec![]);
+        vault.add(Box::new(plugin))
+    }
+
+    pub fn init(
+        vault: &mut Vault, unlock_password: impl AsRef<str>, parameters: &Parameters,
+    ) -> Result<()> {
+        let seed = vault.unlock(unlock_password.as_ref())?;
+        let key0 = SubtreeRoot::new(&seed, parameters)?.key(0)?.public_key();
+        let plugin = Self::new(parameters.to_owned(), vec![key0.to_string()]);
+        vault.add(Box::new(plugin))
+    }
+
+    pub fn get(
+        vault: &Vault, parameters: &Parameters,
+    ) -> Result<BoundPlugin<Plugin, Public, Private>> {
+        let subtree_plugins = vault.plugins_by_type::<Plugin>()?;
+        let plugin: &Plugin = subtree_plugins
+            .iter()
+            .by_ref()
+            .find(|p| p.inner.read().parameters == *parameters)
+            .with_context(|| {
+                format!("Could not find {} subtree on {}", parameters.suite(), parameters.path)
+            })?;
+        Ok(BoundPlugin::new(vault.to_owned(), plugin.to_owned()))
+    }
+
+    pub fn parameters(&self) -> Parameters {
+        self.inner.read().parameters.to_owned()
+    }
+
+    pub(super) fn to_state(&self) -> Box<dyn State<PublicState>> {
+        <dyn State<_>>::map(&self.inner, |s| &s.public_state, |s| &mut s.public_state)
+    }
+}
@@ -0,0 +1,58 @@
+use super::*;
+
+pub struct Private {
+    parameters: Parameters,
+    state: Box<dyn State<PublicState>>,
+    root: SubtreeRoot,
+    vault_dirty: Box<dyn State<bool>>,
+}
+
+impl PluginPrivate<Plugin> for Private {
+    fn create(plugin: &Plugin, seed: Seed, vault_dirty: Box<dyn State<bool>>) -> Result<Self> {
+        let parameters = plugin.parameters();
+        let root = SubtreeRoot::new(&seed, &parameters)?;
+        let state = plugin.to_state();
+        Ok(Private { parameters, state, root, vault_dirty })
+    }
+}
+
+impl Private {
+    pub fn public(&self) -> Public {
+        Public::new(self.parameters.to_owned(), self.state.clone())
+    }
+
+    pub fn key(&self, idx: i32) -> Result<MPrivateKey> {
+        ensure!(idx >= 0, "Key index cannot be negative");
+        let count = self.state.try_borrow()?.keys.len() as i32;
+        ensure!(idx < count, "Only existing keys can be queried");
+        self.root.key(idx)
+    }
+
+    /// Returns the key with the given index, issuing it and all keys before it if needed.
+    pub fn key_mut(&mut self, idx: i32) -> Result<MPrivateKey> {
+        ensure!(idx >= 0, "Key index cannot be negative");
+        let count = self.state.try_borrow()?.keys.len() as i32;
+        let required = idx + 1;
+        if count < required {
+            let mut state = self.state.try_borrow_mut()?;
+            for i in count..required {
+                let pk = self.root.key(i)?.public_key().to_string();
+                state.keys.push(pk)
+            }
+            let mut dirty = self.vault_dirty.try_borrow_mut()?;
+            *dirty = true;
+        }
+        self.root.key(idx)
+    }
+
+    pub fn key_by_pk(&self, pk: &MPublicKey) -> Result<MPrivateKey> {
+        let count = self.state.try_borrow()?.keys.len() as i32;
+        for idx in 0..count {
+            let sk = self.root.key(idx)?;
+            if sk.public_key() == *pk {
+                return Ok(sk);
+            }
+        }
+        bail!("Could not find {} among keys of {} subtree", pk, self.parameters.path)
+    }
+}
@@ -0,0 +1,53 @@
+use super::*;
+
+pub struct Public {
+    parameters: Parameters,
+    state: Box<dyn State<PublicState>>,
+}
+
+impl PluginPublic<Plugin> for Public {
+    fn create(plugin: &Plugin, _vault_dirty: Box<dyn State<bool>>) -> Result<Self> {
+        Ok(Self::new(plugin.parameters(), plugin.to_state()))
+    }
+}
+
+impl Public {
+    pub(super) fn new(parameters: Parameters, state: Box<dyn State<PublicState>>) -> Self {
+        Self { parameters, state }
+    }
+
+    pub fn parameters(&self) -> &Parameters {
+        &self.parameters
+    }
+
+    /// The number of keys already issued in this subtree.
+    pub fn len(&self) -> Result<u32> {
+        let state = self.state.try_borrow()?;
+        Ok(state.keys.len() as u32)
+    }
+
+    pub fn is_empty(&self) -> Result<bool> {
+        let state = self.state.try_borrow()?;
+        Ok(state.keys.is_empty())
+    }
+
+    pub fn key(&self, idx: i32) -> Result<MPublicKey> {
+        ensure!(idx >= 0, "Key index cannot be negative");
+        let idx = idx as usize;
+        let state = self.state.try_borrow()?;
+        ensure!(idx < state.keys.len(), "Only existing keys can be queried via Subtree Public");
+        let key: MPublicKey = state.keys[idx].parse()?;
+        Ok(key)
+    }
+
+    pub fn key_by_id(&self, id: &MKeyId) -> Result<MPublicKey> {
+        let state = self.state.try_borrow()?;
+        for key in &state.keys {
+            let key: MPublicKey = key.parse()?;
+            if key.validate_id(id) {
+                return Ok(key);
+            }
+        }
+        bail!("Could not find {} among keys of {} subtree", id, self.parameters.path)
+    }
+}
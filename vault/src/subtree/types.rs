@@ -0,0 +1,102 @@
+use super::*;
+
+/// Chooses the cipher suite and the BIP32 path of the node that is the parent of all keys in the subtree.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Parameters {
+    pub(super) suite: CipherSuite,
+    pub(super) path: String,
+}
+
+impl Parameters {
+    pub fn new(suite: CipherSuite, path: impl AsRef<str>) -> Result<Self> {
+        let path = path.as_ref();
+        let bip32_path: Bip32Path = path.parse()?;
+        ensure!(!bip32_path.children().is_empty(), "The master node cannot be used as a subtree");
+        if suite == CipherSuite::Ed25519 {
+            let all_hardened =
+                bip32_path.children().iter().all(|c| matches!(c, ChildIndex::Hardened(_)));
+            ensure!(all_hardened, "Ed25519 supports only hardened derivation, got {}", path);
+        }
+        Ok(Self { suite, path: bip32_path.to_string() })
+    }
+
+    /// The subtree `m/purpose'` reserved for an application based on BIP43, e.g. [`BIP43_PURPOSE_MERCURY`].
+    pub fn bip43(suite: CipherSuite, purpose: i32) -> Result<Self> {
+        ensure!(purpose >= 0, "BIP43 purpose cannot be negative");
+        Self::new(suite, Bip43Path::purpose(purpose).bip32_path().to_string())
+    }
+
+    pub fn suite(&self) -> CipherSuite {
+        self.suite
+    }
+
+    pub fn path(&self) -> Result<Bip32Path> {
+        self.path.parse()
+    }
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct PublicState {
+    #[serde(skip_serializing_if = "Vec::is_empty", default)]
+    pub(super) keys: Vec<String>,
+}
+
+struct EdSubtree;
+
+impl Subtree for EdSubtree {
+    type Suite = Ed25519;
+
+    fn name(&self) -> &'static str {
+        "ed25519 subtree"
+    }
+    fn master(&self, seed: &Seed) -> EdExtPrivateKey {
+        Ed25519::master(seed)
+    }
+    fn key_id(&self, pk: &EdPublicKey) -> EdKeyId {
+        pk.key_id()
+    }
+}
+
+struct SecpSubtree;
+
+impl Subtree for SecpSubtree {
+    type Suite = Secp256k1;
+
+    fn name(&self) -> &'static str {
+        "secp256k1 subtree"
+    }
+    fn master(&self, seed: &Seed) -> SecpExtPrivateKey {
+        Secp256k1::master(seed)
+    }
+    fn key_id(&self, pk: &SecpPublicKey) -> SecpKeyId {
+        pk.key_id()
+    }
+}
+
+/// The parent node of all keys in a subtree. Keys are always derived with hardened derivation,
+/// because Ed25519 does not support anything else.
+#[derive(Clone, Debug)]
+pub(super) enum SubtreeRoot {
+    Ed25519(Bip32Node<Ed25519>),
+    Secp256k1(Bip32Node<Secp256k1>),
+}
+
+impl SubtreeRoot {
+    pub(super) fn new(seed: &Seed, parameters: &Parameters) -> Result<Self> {
+        let path = parameters.path()?;
+        let root = match parameters.suite {
+            CipherSuite::Ed25519 => Self::Ed25519(Bip32.derive(seed, &EdSubtree, &path)?),
+            CipherSuite::Secp256k1 => Self::Secp256k1(Bip32.derive(seed, &SecpSubtree, &path)?),
+        };
+        Ok(root)
+    }
+
+    pub(super) fn key(&self, idx: i32) -> Result<MPrivateKey> {
+        let sk = match self {
+            Self::Ed25519(node) => node.derive_hardened(idx)?.private_key().into(),
+            Self::Secp256k1(node) => node.derive_hardened(idx)?.private_key().into(),
+        };
+        Ok(sk)
+    }
+}
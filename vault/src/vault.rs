@@ -18,14 +18,18 @@ impl fmt::Debug for dyn VaultPlugin {
 #[derive(Debug, Serialize)]
 #[serde(rename_all = "camelCase")]
 struct VaultImpl {
-    encrypted_seed: String,
+    /// Watch-only vaults have no seed, only plugins with public keys
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    encrypted_seed: Option<String>,
     plugins: Vec<Box<dyn VaultPlugin>>,
     #[serde(skip)]
     is_dirty: bool, // Plugins and language bindings are trusted to use this properly
 }
 
 impl VaultImpl {
-    fn new(encrypted_seed: String, plugins: Vec<Box<dyn VaultPlugin>>, is_dirty: bool) -> Self {
+    fn new(
+        encrypted_seed: Option<String>, plugins: Vec<Box<dyn VaultPlugin>>, is_dirty: bool,
+    ) -> Self {
         Self { encrypted_seed, plugins, is_dirty }
     }
 }
@@ -38,7 +42,9 @@ pub struct Vault {
 }
 
 impl Vault {
-    pub fn new(encrypted_seed: String, plugins: Vec<Box<dyn VaultPlugin>>, dirty: bool) -> Self {
+    pub fn new(
+        encrypted_seed: Option<String>, plugins: Vec<Box<dyn VaultPlugin>>, dirty: bool,
+    ) -> Self {
         let imp = VaultImpl::new(encrypted_seed, plugins, dirty);
         let inner = Arc::new(RwLock::new(imp));
         Self { inner }
@@ -54,13 +60,35 @@ impl Vault {
         };
         let seed = bip39.phrase(phrase)?.password(bip39_password);
         let encrypted_seed = Self::encrypt_seed(&seed, unlock_password.as_ref())?;
-        let vault = Self::new(encrypted_seed, Vec::new(), true);
+        let vault = Self::new(Some(encrypted_seed), Vec::new(), true);
         Ok(vault)
     }
 
+    /// Creates a vault without a seed. Only plugins that need no private keys, like watch-only
+    /// accounts imported from an xpub, can be added to it.
+    pub fn create_watch_only() -> Vault {
+        Self::new(None, Vec::new(), true)
+    }
+
+    pub fn is_watch_only(&self) -> Result<bool> {
+        let imp = self.inner.try_read().ok_or_else(|| format_err!("Read lock on Vault failed"))?;
+        Ok(imp.encrypted_seed.is_none())
+    }
+
     pub fn unlock(&self, unlock_password: &str) -> Result<Seed> {
         let imp = self.inner.try_read().ok_or_else(|| format_err!("Read lock on Vault failed"))?;
-        Self::decrypt_seed(&imp.encrypted_seed, unlock_password)
+        let encrypted_seed = imp
+            .encrypted_seed
+            .as_ref()
+            .ok_or_else(|| format_err!("Vault is watch-only, it has no seed to unlock"))?;
+        Self::decrypt_seed(encrypted_seed, unlock_password)
+    }
+
+    /// Derives child mnemonics, WIF keys and hex secrets from the seed of the vault, so they can be
+    /// imported into other wallets without sharing the vault itself.
+    pub fn bip85(&self, unlock_password: &str) -> Result<Bip85> {
+        let seed = self.unlock(unlock_password)?;
+        Ok(Bip85::from_seed(&seed))
     }
 
     pub fn plugins_by_type<T: VaultPlugin + 'static>(&self) -> Result<Vec<Box<T>>> {
@@ -121,6 +149,10 @@ impl<T: VaultPlugin, TPublic: PluginPublic<T>, TPriv: PluginPrivate<T>>
         Self { vault, plugin, _pub: Default::default(), _priv: Default::default() }
     }
 
+    pub fn plugin(&self) -> &T {
+        &self.plugin
+    }
+
     pub fn private(&self, unlock_password: impl AsRef<str>) -> Result<TPriv> {
         let seed = self.vault.unlock(unlock_password.as_ref())?;
         TPriv::create(&self.plugin, seed, self.vault.to_modifiable())